@@ -5,7 +5,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use scc::hash_map::Entry;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, watch};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(tag = "state", content = "hash", rename_all = "kebab-case")]
@@ -64,6 +64,12 @@ pub struct ActionContext {
     /// Targets to run after the initial locators have been resolved.
     pub primary_targets: FxHashSet<Target>,
 
+    /// Readiness of persistent tasks with probes, that dependents wait on.
+    /// A value of `None` means that the probes are still pending.
+    /// @mutable
+    #[serde(skip)]
+    pub target_readiness: scc::HashMap<Target, Arc<watch::Sender<Option<bool>>>>,
+
     /// The current state of running tasks (via their target).
     /// @mutable
     pub target_states: scc::HashMap<Target, TargetState>,
@@ -116,6 +122,24 @@ impl ActionContext {
             .is_some_and(|dependencies| dependencies.contains(dependency.as_ref()))
     }
 
+    /// Resolve the readiness of a tracked target. Once resolved,
+    /// subsequent calls are ignored.
+    pub fn set_target_readiness<T: AsRef<Target>>(&self, target: T, ready: bool) {
+        if let Some(sender) = self
+            .target_readiness
+            .read_sync(target.as_ref(), |_, sender| Arc::clone(sender))
+        {
+            sender.send_if_modified(|state| {
+                if state.is_some() {
+                    return false;
+                }
+
+                *state = Some(ready);
+                true
+            });
+        }
+    }
+
     pub fn set_target_state<T: AsRef<Target>>(&self, target: T, state: TargetState) {
         let _ = self
             .target_states
@@ -146,4 +170,30 @@ impl ActionContext {
 
         false
     }
+
+    /// Track the readiness of a target, so that dependents can wait on it.
+    pub fn track_target_readiness<T: AsRef<Target>>(&self, target: T) {
+        let _ = self.target_readiness.insert_sync(
+            target.as_ref().to_owned(),
+            Arc::new(watch::Sender::new(None)),
+        );
+    }
+
+    /// Wait for a target's readiness probes to resolve, and return whether
+    /// they passed. Targets that are not tracked are always ready.
+    pub async fn wait_for_target_readiness<T: AsRef<Target>>(&self, target: T) -> bool {
+        let Some(sender) = self
+            .target_readiness
+            .read_sync(target.as_ref(), |_, sender| Arc::clone(sender))
+        else {
+            return true;
+        };
+
+        let mut receiver = sender.subscribe();
+
+        receiver
+            .wait_for(Option::is_some)
+            .await
+            .is_ok_and(|state| state.unwrap_or_default())
+    }
 }
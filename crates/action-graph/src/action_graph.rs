@@ -170,6 +170,12 @@ impl GraphConnections<ActionNode, TaskDependencyType, String> for ActionGraph {
 
 impl GraphConversions<ActionNode, TaskDependencyType, String> for ActionGraph {}
 
+impl GraphNodeAttributes<ActionNode> for ActionGraph {}
+
 impl GraphToDot<ActionNode, TaskDependencyType, String> for ActionGraph {}
 
+impl GraphToGraphMl<ActionNode, TaskDependencyType, String> for ActionGraph {}
+
 impl GraphToJson<ActionNode, TaskDependencyType, String> for ActionGraph {}
+
+impl GraphToMermaid<ActionNode, TaskDependencyType, String> for ActionGraph {}
@@ -12,7 +12,7 @@ use moon_app_context::AppContext;
 use moon_common::path::{PathExt, WorkspaceRelativePathBuf};
 use moon_common::{Id, color, is_ci};
 use moon_config::{EnvMap, PipelineActionSwitch, TaskDependencyConfig, TaskDependencyType};
-use moon_exec_plan::{ExecutionPlan, TargetsBlock};
+use moon_exec_plan::{ExecutionPlan, JobStrategy, TargetsBlock};
 use moon_pdk_api::{DefineRequirementsInput, LocateDependenciesRootInput};
 use moon_project::{Project, ProjectError};
 use moon_query::{Criteria, build_query};
@@ -25,7 +25,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use std::fmt::Debug;
 use std::mem;
 use std::sync::Arc;
-use tracing::{debug, instrument, trace};
+use tracing::{debug, instrument, trace, warn};
 
 macro_rules! insert_node_if_missing {
     ($builder:ident, $node:expr) => {{
@@ -61,6 +61,7 @@ pub struct RunRequirements {
     pub interactive: bool,           // Entire pipeline is interactive
     pub job: Option<usize>,          // Current job index
     pub job_total: Option<usize>,    // Total amount of jobs
+    pub job_strategy: JobStrategy,   // How to partition jobs
     pub skip_affected: bool,         // Skip all affected checks
 }
 
@@ -75,6 +76,7 @@ impl Default for RunRequirements {
             interactive: false,
             job: None,
             job_total: None,
+            job_strategy: JobStrategy::Count,
             skip_affected: false,
         }
     }
@@ -136,6 +138,9 @@ pub struct ActionGraphBuilder<'query> {
     affected: Option<AffectedTracker>,
     changed_files: Option<FxHashSet<WorkspaceRelativePathBuf>>,
 
+    // Durations (in milliseconds) used for balanced job partitioning
+    job_timings: Option<FxHashMap<String, u64>>,
+
     // Target tracking
     ignored_dependencies: FxHashMap<Target, FxHashSet<Target>>,
     // Tasks whose dependents were out of scope when their node was created.
@@ -173,6 +178,7 @@ impl<'query> ActionGraphBuilder<'query> {
             primary_targets: FxHashSet::default(),
             serial_edges: FxHashSet::default(),
             changed_files: None,
+            job_timings: None,
             workspace_graph,
         })
     }
@@ -288,6 +294,10 @@ impl<'query> ActionGraphBuilder<'query> {
         Ok(())
     }
 
+    pub fn set_job_timings(&mut self, timings: FxHashMap<String, u64>) {
+        self.job_timings = Some(timings);
+    }
+
     pub async fn track_affected(
         &mut self,
         upstream: UpstreamScope,
@@ -670,31 +680,54 @@ impl<'query> ActionGraphBuilder<'query> {
                 tasks = new_tasks;
             }
 
-            // Then slice and partition the tasks based on the job index and total
-            let size = tasks.len().div_ceil(job_total);
-            let (start, stop) =
-                // beginning
-                if job_index == 0 {
-                    (0, size)
+            // Durations in the local state cache differ between machines, so
+            // each job may compute different partitions, duplicating or dropping
+            // tasks. Only balance when every job shares the same timings file.
+            let timings = match (&reqs.job_strategy, &self.job_timings) {
+                (JobStrategy::Balanced, Some(timings)) => Some(timings),
+                (JobStrategy::Balanced, None) => {
+                    warn!(
+                        "The balanced job strategy requires a timings file (--job-timings), falling back to the count strategy"
+                    );
+
+                    None
                 }
-                // end
-                else if job_index == job_total - 1 {
-                    ((size * job_index), tasks.len())
+                (JobStrategy::Count, _) => None,
+            };
+
+            match timings {
+                Some(timings) => {
+                    tasks = partition_tasks_by_duration(tasks, timings, job_index, job_total);
+                    partition.size = Some(tasks.len());
                 }
-                // middle
-                else {
-                    ((size * job_index), (size * (job_index + 1)))
-                };
+                None => {
+                    // Then slice and partition the tasks based on the job index and total
+                    let size = tasks.len().div_ceil(job_total);
+                    let (start, stop) =
+                        // beginning
+                        if job_index == 0 {
+                            (0, size)
+                        }
+                        // end
+                        else if job_index == job_total - 1 {
+                            ((size * job_index), tasks.len())
+                        }
+                        // middle
+                        else {
+                            ((size * job_index), (size * (job_index + 1)))
+                        };
+
+                    if tasks.get(start).is_some() {
+                        if tasks.get(stop).is_some() {
+                            tasks = tasks[start..stop].to_vec();
+                        } else {
+                            tasks = tasks[start..].to_vec();
+                        }
+                    }
 
-            if tasks.get(start).is_some() {
-                if tasks.get(stop).is_some() {
-                    tasks = tasks[start..stop].to_vec();
-                } else {
-                    tasks = tasks[start..].to_vec();
+                    partition.size = Some(size);
                 }
             }
-
-            partition.size = Some(size);
         }
 
         for task in tasks {
@@ -1711,6 +1744,67 @@ impl ActionGraphBuilder<'_> {
     }
 }
 
+// Bin-pack tasks into jobs by placing the longest tasks first, each into
+// the job with the least amount of work. Ties are broken by target and
+// job index, so that every job computes the same partitions.
+fn partition_tasks_by_duration(
+    tasks: Vec<Arc<Task>>,
+    timings: &FxHashMap<String, u64>,
+    job_index: usize,
+    job_total: usize,
+) -> Vec<Arc<Task>> {
+    let durations = tasks
+        .iter()
+        .map(|task| timings.get(task.target.as_str()).copied())
+        .collect::<Vec<_>>();
+
+    // Tasks without a duration are assumed to take an average amount of time
+    let known = durations.iter().flatten().copied().collect::<Vec<_>>();
+    let fallback = if known.is_empty() {
+        1
+    } else {
+        (known.iter().sum::<u64>() / known.len() as u64).max(1)
+    };
+
+    let durations = durations
+        .into_iter()
+        .map(|duration| duration.unwrap_or(fallback))
+        .collect::<Vec<_>>();
+
+    let mut order = (0..tasks.len()).collect::<Vec<_>>();
+
+    order.sort_by(|a, b| {
+        durations[*b]
+            .cmp(&durations[*a])
+            .then_with(|| tasks[*a].target.as_str().cmp(tasks[*b].target.as_str()))
+    });
+
+    let mut loads = vec![0; job_total];
+    let mut keep = vec![false; tasks.len()];
+
+    for index in order {
+        let job = (0..job_total)
+            .min_by_key(|job| (loads[*job], *job))
+            .unwrap_or_default();
+
+        loads[job] += durations[index];
+        keep[index] = job == job_index;
+    }
+
+    debug!(
+        job_index,
+        job_total,
+        estimated_duration = loads[job_index],
+        "Partitioned tasks based on recorded durations"
+    );
+
+    tasks
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(task, keep)| keep.then_some(task))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
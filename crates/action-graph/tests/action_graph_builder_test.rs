@@ -9,7 +9,7 @@ use moon_config::{
     EnvMap, PROTO_CLI_VERSION, PipelineActionSwitch, TaskDependencyConfig, TaskOptionRunInCI,
     UnresolvedVersionSpec, Version, VersionSpec,
 };
-use moon_exec_plan::{ExecutionPlan, GraphBlock, TargetsBlock};
+use moon_exec_plan::{ExecutionPlan, GraphBlock, JobStrategy, TargetsBlock};
 use moon_graph_utils::*;
 use moon_task::{Target, TargetLocator, Task, TaskFileInput};
 use moon_toolchain::ToolchainSpec;
@@ -3991,6 +3991,106 @@ mod action_graph_builder {
             assert_eq!(context.primary_targets.len(), 2);
             assert_snapshot!(graph.to_dot());
         }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn partitions_by_job_balanced() {
+            let sandbox = create_sandbox("tasks");
+            let mut container = ActionGraphContainer::new(sandbox.path());
+            let mut timings = FxHashMap::default();
+
+            for i in 1..=10 {
+                timings.insert(
+                    format!("partition:task-{i}"),
+                    if i == 1 { 1000 } else { 100 },
+                );
+            }
+
+            // 0
+            let mut builder = container
+                .create_builder(container.create_workspace_graph().await)
+                .await;
+
+            builder.set_job_timings(timings.clone());
+            builder
+                .run_tasks(
+                    vec![TargetLocator::parse("partition:task-*").unwrap()],
+                    RunRequirements {
+                        job: Some(0),
+                        job_total: Some(2),
+                        job_strategy: JobStrategy::Balanced,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            let (context, _) = builder.build();
+
+            assert_eq!(
+                context.primary_targets.into_iter().collect::<Vec<_>>(),
+                vec![Target::parse("partition:task-1").unwrap()]
+            );
+
+            // 1
+            let mut builder = container
+                .create_builder(container.create_workspace_graph().await)
+                .await;
+
+            builder.set_job_timings(timings);
+            builder
+                .run_tasks(
+                    vec![TargetLocator::parse("partition:task-*").unwrap()],
+                    RunRequirements {
+                        job: Some(1),
+                        job_total: Some(2),
+                        job_strategy: JobStrategy::Balanced,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            let (context, _) = builder.build();
+
+            assert_eq!(context.primary_targets.len(), 9);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn partitions_by_count_when_balanced_without_timings() {
+            let sandbox = create_sandbox("tasks");
+            let mut container = ActionGraphContainer::new(sandbox.path());
+            let mut partitions = vec![];
+
+            for job in 0..3 {
+                let mut builder = container
+                    .create_builder(container.create_workspace_graph().await)
+                    .await;
+
+                builder
+                    .run_tasks(
+                        vec![TargetLocator::parse("partition:task-*").unwrap()],
+                        RunRequirements {
+                            job: Some(job),
+                            job_total: Some(3),
+                            job_strategy: JobStrategy::Balanced,
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap();
+
+                let (context, _) = builder.build();
+
+                partitions.push(context.primary_targets);
+            }
+
+            assert_eq!(
+                partitions.iter().map(|p| p.len()).collect::<Vec<_>>(),
+                vec![4, 4, 2]
+            );
+            assert!(partitions[0].is_disjoint(&partitions[1]));
+            assert!(partitions[1].is_disjoint(&partitions[2]));
+        }
     }
 
     mod setup_env {
@@ -1,37 +1,46 @@
 use crate::event_emitter::{Event, EventEmitter};
 use crate::job::Job;
 use crate::job_context::JobContext;
-use crate::job_dispatcher::JobDispatcher;
+use crate::job_dispatcher::{JobDispatcher, calculate_critical_paths};
+use crate::resource_pools::ResourcePools;
 use crate::subscribers::cleanup_subscriber::CleanupSubscriber;
 use crate::subscribers::console_subscriber::ConsoleSubscriber;
+use crate::subscribers::daemon_subscriber::DaemonSubscriber;
 use crate::subscribers::notifications_subscriber::NotificationsSubscriber;
 use crate::subscribers::reports_subscriber::ReportsSubscriber;
 // use crate::subscribers::telemetry_subscriber::TelemetrySubscriber;
 use crate::subscribers::webhooks_subscriber::WebhooksSubscriber;
 use miette::IntoDiagnostic;
-use moon_action::{Action, ActionNode, ActionPipelineStatus};
+use moon_action::{Action, ActionNode, ActionPipelineStatus, ActionStatus};
 use moon_action_context::{ActionContext, TargetState};
 use moon_action_graph::ActionGraph;
 use moon_app_context::AppContext;
 use moon_common::{color, is_remote, is_test_env};
 use moon_console::Level;
 use moon_daemon_client::DaemonClient;
+use moon_exec_plan::ReportFormat;
 use moon_process::{ProcessRegistry, SignalType};
+use moon_task::Target;
+use moon_task_runner::{TaskRunCacheState, TaskRunnerError};
 use moon_workspace_graph::WorkspaceGraph;
+use petgraph::graph::NodeIndex;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeMap;
 use std::mem;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::{RwLock, Semaphore, mpsc};
+use tokio::sync::{RwLock, mpsc};
 use tokio::task::{JoinHandle, JoinSet};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, instrument, warn};
 
 pub struct ActionPipeline {
     pub bail: bool,
+    pub cancel_token: CancellationToken,
     pub concurrency: usize,
     pub quiet: bool,
     pub report_name: String,
+    pub reports: Vec<ReportFormat>,
     pub summary: Option<Level>,
 
     // State
@@ -60,12 +69,14 @@ impl ActionPipeline {
             actions: vec![],
             app_context,
             bail: false,
+            cancel_token: CancellationToken::new(),
             concurrency: num_cpus::get(),
             daemon_client,
             duration: None,
             emitter: Arc::new(EventEmitter::default()),
             quiet: false,
             report_name: "runReport.json".into(),
+            reports: vec![],
             status: ActionPipelineStatus::Pending,
             summary: None,
             workspace_graph,
@@ -84,7 +95,7 @@ impl ActionPipeline {
         action_context: ActionContext,
     ) -> miette::Result<Vec<Action>> {
         self.action_context = Arc::new(action_context);
-        self.setup_subscribers().await;
+        self.setup_subscribers(&action_graph).await;
 
         self.emitter
             .emit(Event::PipelineStarted {
@@ -98,6 +109,18 @@ impl ActionPipeline {
         let result = self.internal_run(action_graph).await;
         let actions = mem::take(&mut self.actions);
 
+        // Remote uploads run in the background, so wait for them before
+        // completing, otherwise reports would be missing their metrics
+        if let Err(error) = self
+            .app_context
+            .cache_engine
+            .storage
+            .wait_for_background_tasks()
+            .await
+        {
+            debug!("Failed to wait for background storage tasks: {error}");
+        }
+
         // Handle the result of the pipeline
         match result {
             Ok(_) => {
@@ -152,7 +175,7 @@ impl ActionPipeline {
 
         // Create job context
         let abort_token = CancellationToken::new();
-        let cancel_token = CancellationToken::new();
+        let cancel_token = self.cancel_token.child_token();
 
         let job_context = JobContext {
             abort_token: abort_token.clone(),
@@ -161,8 +184,15 @@ impl ActionPipeline {
             completed_jobs: Arc::new(RwLock::new(FxHashSet::default())),
             daemon_client: self.daemon_client.clone(),
             emitter: Arc::clone(&self.emitter),
+            resource_pools: Arc::new(ResourcePools::new(
+                self.concurrency,
+                self.app_context
+                    .workspace_config
+                    .pipeline
+                    .resource_pools
+                    .clone(),
+            )),
             result_sender: sender,
-            semaphore: Arc::new(Semaphore::new(self.concurrency)),
             running_jobs: Arc::new(RwLock::new(FxHashMap::default())),
             workspace_graph: self.workspace_graph.clone(),
         };
@@ -265,15 +295,21 @@ impl ActionPipeline {
     ) -> miette::Result<JoinHandle<JoinSet<()>>> {
         let node_indices = action_graph.sort_topological()?;
         let node_count = node_indices.len();
-        let priority_groups = action_graph.group_priorities(node_indices);
         let app_context = Arc::clone(&self.app_context);
         let action_context = Arc::clone(&self.action_context);
 
         debug!(total_jobs = node_count, "Dispatching jobs in the pipeline");
 
         Ok(tokio::spawn(Box::pin(async move {
+            let critical_paths = calculate_critical_paths(
+                &action_graph,
+                &node_indices,
+                &load_duration_estimates(Arc::clone(&app_context), &action_graph).await,
+            );
+            let priority_groups = action_graph.group_priorities(node_indices);
             let mut dispatcher =
-                JobDispatcher::new(&action_graph, job_context.clone(), priority_groups);
+                JobDispatcher::new(&action_graph, job_context.clone(), priority_groups)
+                    .with_critical_paths(critical_paths);
             let mut persistent_indices = vec![];
             let mut job_handles = JoinSet::new();
 
@@ -323,7 +359,7 @@ impl ActionPipeline {
                     continue;
                 }
 
-                // Otherwise run the action topologically
+                // Otherwise run the action in dispatch order
                 job_handles.spawn(dispatch_job_with_permit(
                     node.to_owned(),
                     node_index.index(),
@@ -356,10 +392,11 @@ impl ActionPipeline {
                 persistent_indices.len()
             );
 
-            persistent_indices
+            let persistent_nodes = persistent_indices
                 .into_iter()
                 .flat_map(|node_index| {
                     let node = action_graph.get_node_from_index(&node_index)?;
+                    let mut task = None;
 
                     // Since the task is persistent, set the state early since
                     // it "never finishes", otherwise the runner will error about
@@ -367,19 +404,47 @@ impl ActionPipeline {
                     if let ActionNode::RunTask(inner) = node {
                         action_context
                             .set_target_state(inner.target.clone(), TargetState::Passthrough);
+
+                        task = job_context.workspace_graph.get_task(&inner.target).ok();
                     }
 
-                    Some((node.to_owned(), node_index.index()))
+                    Some((node.to_owned(), node_index.index(), task))
                 })
-                .for_each(|(node, node_index)| {
-                    job_handles.spawn(dispatch_job(
-                        node,
-                        node_index,
-                        job_context.clone(),
-                        Arc::clone(&app_context),
-                        Arc::clone(&action_context),
-                    ));
-                });
+                .collect::<Vec<_>>();
+
+            // Track tasks with readiness probes before dispatching,
+            // so that their dependents are able to wait on them
+            let probed_targets = persistent_nodes
+                .iter()
+                .filter_map(|(_, _, task)| task.as_ref())
+                .filter(|task| task.readiness.is_some())
+                .map(|task| {
+                    action_context.track_target_readiness(&task.target);
+
+                    task.target.clone()
+                })
+                .collect::<FxHashSet<_>>();
+
+            for (node, node_index, task) in persistent_nodes {
+                let ready_targets = task
+                    .map(|task| {
+                        task.deps
+                            .iter()
+                            .filter(|dep| probed_targets.contains(&dep.target))
+                            .map(|dep| dep.target.clone())
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                job_handles.spawn(dispatch_job_when_ready(
+                    node,
+                    node_index,
+                    ready_targets,
+                    job_context.clone(),
+                    Arc::clone(&app_context),
+                    Arc::clone(&action_context),
+                ));
+            }
 
             job_handles
         })))
@@ -389,25 +454,36 @@ impl ActionPipeline {
         tokio::spawn(async move {
             let mut receiver = ProcessRegistry::instance().receive_signal();
 
-            if let Ok(signal) = receiver.recv().await {
-                cancel_token.cancel();
+            tokio::select! {
+                Ok(signal) = receiver.recv() => {
+                    cancel_token.cancel();
 
-                debug!("Received signal, shutting down pipeline");
+                    debug!("Received signal, shutting down pipeline");
 
-                return signal;
-            }
+                    return signal;
+                }
+                // Cancelled by the consumer (parent token), so terminate any
+                // running child processes, otherwise the pipeline would wait
+                // for them to complete
+                _ = cancel_token.cancelled() => {
+                    debug!("Pipeline was cancelled, shutting down");
+
+                    ProcessRegistry::instance().terminate_running();
+                }
+            };
 
             SignalType::Interrupt
         })
     }
 
-    async fn setup_subscribers(&mut self) {
+    async fn setup_subscribers(&mut self, action_graph: &ActionGraph) {
         debug!("Registering event subscribers");
 
         if !self.quiet {
             self.emitter
                 .subscribe(ConsoleSubscriber::new(
                     Arc::clone(&self.app_context.console),
+                    Arc::clone(&self.app_context.cache_engine),
                     self.summary,
                 ))
                 .await;
@@ -415,14 +491,37 @@ impl ActionPipeline {
 
         debug!("Subscribing run reports and estimates");
 
+        let edges = if self.reports.is_empty() {
+            vec![]
+        } else {
+            action_graph
+                .get_inner_graph()
+                .graph()
+                .raw_edges()
+                .iter()
+                .map(|edge| (edge.source().index(), edge.target().index()))
+                .collect()
+        };
+
         self.emitter
-            .subscribe(ReportsSubscriber::new(
-                Arc::clone(&self.app_context.cache_engine),
-                Arc::clone(&self.action_context),
-                &self.report_name,
-            ))
+            .subscribe(
+                ReportsSubscriber::new(
+                    Arc::clone(&self.app_context.cache_engine),
+                    Arc::clone(&self.action_context),
+                    &self.report_name,
+                )
+                .with_formats(self.reports.clone(), edges),
+            )
             .await;
 
+        if let Some(daemon_client) = &self.daemon_client {
+            debug!("Subscribing daemon event streaming");
+
+            self.emitter
+                .subscribe(DaemonSubscriber::new(daemon_client.clone()))
+                .await;
+        }
+
         // For security and privacy purposes, only send webhooks in a remote environment
         if (is_remote() || is_test_env())
             && let Some(webhook_url) = &self.app_context.workspace_config.notifier.webhook_url
@@ -514,6 +613,102 @@ async fn dispatch_job(
     job.dispatch().await;
 }
 
+// Durations are recorded by the task runner after each successful
+// run, and are used to prioritize the longest chains of tasks. States
+// are read from the file system, so load them in parallel off the runtime
+async fn load_duration_estimates(
+    app_context: Arc<AppContext>,
+    action_graph: &ActionGraph,
+) -> FxHashMap<NodeIndex, u64> {
+    let mut set = JoinSet::new();
+
+    for (index, node) in action_graph.get_inner_nodes() {
+        let ActionNode::RunTask(inner) = node else {
+            continue;
+        };
+
+        let index = *index;
+        let target = inner.target.clone();
+        let app_context = Arc::clone(&app_context);
+
+        set.spawn_blocking(move || {
+            app_context
+                .cache_engine
+                .state
+                .load_target_state::<TaskRunCacheState>(&target)
+                .ok()
+                .and_then(|state| state.data.get_estimated_duration())
+                .map(|duration| (index, duration.as_millis() as u64))
+        });
+    }
+
+    let mut durations = FxHashMap::default();
+
+    while let Some(result) = set.join_next().await {
+        if let Ok(Some((index, duration))) = result {
+            durations.insert(index, duration);
+        }
+    }
+
+    durations
+}
+
+async fn dispatch_job_when_ready(
+    node: ActionNode,
+    node_index: usize,
+    ready_targets: Vec<Target>,
+    job_context: JobContext,
+    app_context: Arc<AppContext>,
+    action_context: Arc<ActionContext>,
+) {
+    let target = match &node {
+        ActionNode::RunTask(inner) => Some(inner.target.clone()),
+        _ => None,
+    };
+
+    for dep_target in ready_targets {
+        debug!(
+            index = node_index,
+            target = dep_target.as_str(),
+            "Waiting for persistent dependency to be ready",
+        );
+
+        if !action_context.wait_for_target_readiness(&dep_target).await {
+            debug!(
+                index = node_index,
+                target = dep_target.as_str(),
+                "Persistent dependency did not become ready, skipping job",
+            );
+
+            let mut action = Action::new(node);
+            action.node_index = node_index;
+            action.finish(ActionStatus::Skipped);
+
+            if let Some(target) = target {
+                action.fail(TaskRunnerError::DependencyNotReady { dep_target, target }.into());
+            }
+
+            job_context.send_result(action).await;
+
+            return;
+        }
+    }
+
+    dispatch_job(
+        node,
+        node_index,
+        job_context,
+        app_context,
+        Arc::clone(&action_context),
+    )
+    .await;
+
+    // The job may have finished before its probes resolved
+    if let Some(target) = target {
+        action_context.set_target_readiness(target, false);
+    }
+}
+
 async fn dispatch_job_with_permit(
     node: ActionNode,
     node_index: usize,
@@ -521,16 +716,24 @@ async fn dispatch_job_with_permit(
     app_context: Arc<AppContext>,
     action_context: Arc<ActionContext>,
 ) {
-    let permit = job_context
-        .semaphore
-        .clone()
-        .acquire_owned()
+    let resources = match &node {
+        ActionNode::RunTask(inner) => job_context
+            .workspace_graph
+            .get_task(&inner.target)
+            .map(|task| task.options.resources.clone())
+            .unwrap_or_default(),
+        _ => BTreeMap::default(),
+    };
+
+    let permits = job_context
+        .resource_pools
+        .acquire(&resources)
         .await
         .expect("Failed to dispatch job!");
 
     dispatch_job(node, node_index, job_context, app_context, action_context).await;
 
-    drop(permit);
+    drop(permits);
 }
 
 #[instrument(skip_all)]
@@ -1,11 +1,12 @@
 use crate::event_emitter::EventEmitter;
+use crate::resource_pools::ResourcePools;
 use moon_action::Action;
 use moon_daemon_client::DaemonClient;
 use moon_workspace_graph::WorkspaceGraph;
 use petgraph::graph::NodeIndex;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::Arc;
-use tokio::sync::{RwLock, Semaphore, mpsc::Sender};
+use tokio::sync::{RwLock, mpsc::Sender};
 use tokio_util::sync::CancellationToken;
 
 #[derive(Clone)]
@@ -28,15 +29,15 @@ pub struct JobContext {
     /// Internal pipeline event emitter
     pub emitter: Arc<EventEmitter>,
 
+    /// Acquires weighted permits for concurrency and resources
+    pub resource_pools: Arc<ResourcePools>,
+
     /// Sends results to the parent pipeline
     pub result_sender: Sender<Action>,
 
     /// Currently running jobs (used by the dispatcher)
     pub running_jobs: Arc<RwLock<FxHashMap<NodeIndex, u64>>>,
 
-    /// Acquires a permit for concurrency
-    pub semaphore: Arc<Semaphore>,
-
     /// The project and task graphs, for use within actions
     pub workspace_graph: Arc<WorkspaceGraph>,
 }
@@ -10,7 +10,7 @@ pub struct JobDispatcher<'graph> {
     context: JobContext,
     graph: &'graph ActionGraphType,
     nodes: &'graph FxHashMap<NodeIndex, ActionNode>,
-    groups: BTreeMap<u8, Vec<NodeIndex>>, // topo
+    groups: BTreeMap<u8, Vec<NodeIndex>>, // critical path, then topo
     visited: FxHashSet<NodeIndex>,
 }
 
@@ -29,6 +29,21 @@ impl<'graph> JobDispatcher<'graph> {
         }
     }
 
+    /// Order the indices within each priority group by their estimated
+    /// critical path, so that the longest chains of work start first.
+    /// Indices with equal weights retain their topological order.
+    pub fn with_critical_paths(mut self, weights: FxHashMap<NodeIndex, u64>) -> Self {
+        if weights.values().any(|weight| *weight > 0) {
+            for indices in self.groups.values_mut() {
+                indices.sort_by_key(|index| {
+                    std::cmp::Reverse(weights.get(index).copied().unwrap_or_default())
+                });
+            }
+        }
+
+        self
+    }
+
     pub fn has_queued_jobs(&self) -> bool {
         self.visited.len() < self.graph.node_count()
     }
@@ -76,6 +91,36 @@ impl<'graph> JobDispatcher<'graph> {
     }
 }
 
+/// Calculate the remaining critical path for each node, which is the
+/// estimated duration of the node itself, plus the longest chain of
+/// estimated durations for the nodes that depend on it.
+pub fn calculate_critical_paths(
+    action_graph: &ActionGraph,
+    topo_indices: &[NodeIndex],
+    durations: &FxHashMap<NodeIndex, u64>,
+) -> FxHashMap<NodeIndex, u64> {
+    let graph = action_graph.get_inner_graph().graph();
+    let mut weights = FxHashMap::<NodeIndex, u64>::default();
+
+    // Dependencies are sorted before their dependents,
+    // so walk in reverse to resolve dependents first
+    for index in topo_indices.iter().rev() {
+        let chain = graph
+            .neighbors_directed(*index, Direction::Incoming)
+            .filter_map(|dependent| weights.get(&dependent))
+            .max()
+            .copied()
+            .unwrap_or_default();
+
+        weights.insert(
+            *index,
+            durations.get(index).copied().unwrap_or_default() + chain,
+        );
+    }
+
+    weights
+}
+
 // This is based on the `Topo` struct from petgraph!
 impl JobDispatcher<'_> {
     pub async fn next(&mut self) -> Option<NodeIndex> {
@@ -87,8 +132,8 @@ impl JobDispatcher<'_> {
         // Loop based on priority groups, from critical to low
         {
             for (group, indices) in &self.groups {
-                // Then loop through the indices within the group,
-                // which are topologically sorted
+                // Then loop through the indices within the group, which are
+                // sorted by critical path weight, then topologically
                 for maybe_index in indices {
                     let Some(index) = self.find_applicable_index(
                         *group,
@@ -155,6 +200,7 @@ impl JobDispatcher<'_> {
 mod tests {
     use super::*;
     use crate::event_emitter::EventEmitter;
+    use crate::resource_pools::ResourcePools;
     use moon_action::{Action, ActionNode, RunTaskNode, SyncProjectNode};
     use moon_action_graph::{ActionGraph, ActionGraphType};
     use moon_common::Id;
@@ -164,7 +210,7 @@ mod tests {
     use rustc_hash::FxHashMap;
     use std::sync::Arc;
     use std::time::{Duration, Instant};
-    use tokio::sync::{RwLock, Semaphore, mpsc};
+    use tokio::sync::{RwLock, mpsc};
     use tokio_util::sync::CancellationToken;
 
     async fn create_job_context() -> JobContext {
@@ -177,9 +223,9 @@ mod tests {
             completed_jobs: Arc::new(RwLock::new(FxHashSet::default())),
             daemon_client: None,
             emitter: Arc::new(EventEmitter::default()),
+            resource_pools: Arc::new(ResourcePools::new(1, FxHashMap::default())),
             result_sender: sender,
             running_jobs: Arc::new(RwLock::new(FxHashMap::default())),
-            semaphore: Arc::new(Semaphore::new(1)),
             workspace_graph: Arc::new(WorkspaceGraph::default()),
         }
     }
@@ -267,6 +313,101 @@ mod tests {
         }
     }
 
+    fn create_chain_graph() -> ActionGraph {
+        let mut graph = ActionGraphType::new();
+        let mut nodes = FxHashMap::default();
+
+        for id in ["short", "build", "test", "package"] {
+            let index = graph.add_node(NodeIndex::new(graph.node_count()));
+            nodes.insert(
+                index,
+                ActionNode::run_task(RunTaskNode::new(
+                    Target::parse(&format!("app:{id}")).unwrap(),
+                )),
+            );
+        }
+
+        // package -> test -> build
+        graph
+            .add_edge(
+                NodeIndex::new(3),
+                NodeIndex::new(2),
+                TaskDependencyType::Required,
+            )
+            .unwrap();
+        graph
+            .add_edge(
+                NodeIndex::new(2),
+                NodeIndex::new(1),
+                TaskDependencyType::Required,
+            )
+            .unwrap();
+
+        ActionGraph::new(graph, nodes)
+    }
+
+    #[test]
+    fn calculates_critical_paths() {
+        let action_graph = create_chain_graph();
+        let topo = action_graph.sort_topological().unwrap();
+        let weights = calculate_critical_paths(
+            &action_graph,
+            &topo,
+            &FxHashMap::from_iter([
+                (NodeIndex::new(0), 100),
+                (NodeIndex::new(1), 30),
+                (NodeIndex::new(2), 50),
+                (NodeIndex::new(3), 40),
+            ]),
+        );
+
+        assert_eq!(weights[&NodeIndex::new(0)], 100);
+        assert_eq!(weights[&NodeIndex::new(1)], 120);
+        assert_eq!(weights[&NodeIndex::new(2)], 90);
+        assert_eq!(weights[&NodeIndex::new(3)], 40);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dispatches_critical_path_first() {
+        let action_graph = create_chain_graph();
+        let topo = action_graph.sort_topological().unwrap();
+        let weights = calculate_critical_paths(
+            &action_graph,
+            &topo,
+            &FxHashMap::from_iter([
+                (NodeIndex::new(0), 100),
+                (NodeIndex::new(1), 60),
+                (NodeIndex::new(2), 60),
+                (NodeIndex::new(3), 60),
+            ]),
+        );
+        let groups = action_graph.group_priorities(topo);
+        let context = create_job_context().await;
+        let mut dispatcher =
+            JobDispatcher::new(&action_graph, context, groups).with_critical_paths(weights);
+
+        assert_eq!(dispatcher.next().await, Some(NodeIndex::new(1)));
+        assert_eq!(dispatcher.next().await, Some(NodeIndex::new(0)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn retains_topological_order_without_history() {
+        let action_graph = create_chain_graph();
+        let topo = action_graph.sort_topological().unwrap();
+        let first = topo
+            .iter()
+            .find(|index| index.index() < 2)
+            .copied()
+            .unwrap();
+        let weights = calculate_critical_paths(&action_graph, &topo, &FxHashMap::default());
+        let groups = action_graph.group_priorities(topo);
+        let context = create_job_context().await;
+        let mut dispatcher =
+            JobDispatcher::new(&action_graph, context, groups).with_critical_paths(weights);
+
+        assert_eq!(dispatcher.next().await, Some(first));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn avoids_rewalking_blocked_sync_subgraphs() {
         let action_graph = create_dense_sync_graph(12, 4);
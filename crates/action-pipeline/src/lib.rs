@@ -9,6 +9,7 @@ mod job;
 mod job_context;
 mod job_dispatcher;
 pub mod reports;
+mod resource_pools;
 mod subscribers;
 
 pub use action_pipeline::*;
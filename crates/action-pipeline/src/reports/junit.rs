@@ -0,0 +1,264 @@
+use moon_action::{Action, ActionNode, ActionStatus, Operation};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+use tracing::{debug, instrument};
+
+// https://github.com/testmoapp/junitxml
+
+#[derive(Debug, PartialEq)]
+pub enum TestCaseResult {
+    Passed,
+    Failed { kind: &'static str, message: String },
+    Skipped { message: String },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TestCase {
+    pub name: String,
+    pub classname: String,
+    pub duration: Duration,
+    pub result: TestCaseResult,
+    pub stdout: Option<String>,
+    pub stderr: Option<String>,
+}
+
+impl TestCase {
+    pub fn from_action(action: &Action) -> Option<Self> {
+        let ActionNode::RunTask(node) = &*action.node else {
+            return None;
+        };
+
+        // When a requirement check fails, the task itself never runs,
+        // so the failing check's process is the most relevant output
+        let requirement = find_failed_requirement(action);
+        let process = requirement.or_else(|| action.operations.get_last_process());
+        let output = process.and_then(|op| op.get_exec_output());
+
+        let result = match action.status {
+            ActionStatus::Passed | ActionStatus::Cached | ActionStatus::CachedFromRemote => {
+                TestCaseResult::Passed
+            }
+            ActionStatus::Failed
+            | ActionStatus::Invalid
+            | ActionStatus::TimedOut
+            | ActionStatus::Aborted => {
+                let message = action.error.clone().unwrap_or_else(|| {
+                    process
+                        .map(|op| op.get_exec_output_status())
+                        .unwrap_or_else(|| "unknown failure".into())
+                });
+
+                if action.quarantined {
+                    TestCaseResult::Skipped {
+                        message: format!("Failure quarantined: {message}"),
+                    }
+                } else if action.allow_failure {
+                    TestCaseResult::Skipped {
+                        message: format!("Failure allowed: {message}"),
+                    }
+                } else {
+                    TestCaseResult::Failed {
+                        kind: if requirement.is_some() {
+                            "requirement"
+                        } else if action.status == ActionStatus::TimedOut {
+                            "timeout"
+                        } else {
+                            "task"
+                        },
+                        message,
+                    }
+                }
+            }
+            ActionStatus::Skipped | ActionStatus::Running => TestCaseResult::Skipped {
+                message: "Task did not run".into(),
+            },
+        };
+
+        Some(TestCase {
+            name: node
+                .target
+                .get_task_id()
+                .unwrap_or(node.target.as_str())
+                .to_owned(),
+            classname: node.target.get_project_id().unwrap_or("~").to_owned(),
+            duration: action.duration.unwrap_or_default(),
+            result,
+            stdout: output.and_then(|out| out.stdout.as_ref().map(|s| s.to_string())),
+            stderr: output.and_then(|out| out.stderr.as_ref().map(|s| s.to_string())),
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct JunitReport {
+    pub suites: BTreeMap<String, Vec<TestCase>>,
+    pub duration: Duration,
+}
+
+impl JunitReport {
+    /// Generate a report where each task is a test case,
+    /// grouped into a test suite for each project.
+    #[instrument(name = "generate_junit", skip_all)]
+    pub fn generate(actions: &[Action], duration: &Duration) -> Self {
+        debug!("Generating a JUnit report of the pipeline");
+
+        let mut suites: BTreeMap<String, Vec<TestCase>> = BTreeMap::default();
+
+        for action in actions {
+            if let Some(case) = TestCase::from_action(action) {
+                suites.entry(case.classname.clone()).or_default().push(case);
+            }
+        }
+
+        JunitReport {
+            suites,
+            duration: *duration,
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        let cases = self.suites.values().flatten();
+        let mut xml = String::new();
+
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="moon" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+            cases.clone().count(),
+            count_failures(cases.clone()),
+            count_skipped(cases),
+            format_seconds(&self.duration),
+        );
+
+        for (name, cases) in &self.suites {
+            let _ = writeln!(
+                xml,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+                escape(name),
+                cases.len(),
+                count_failures(cases.iter()),
+                count_skipped(cases.iter()),
+                format_seconds(&cases.iter().map(|case| case.duration).sum()),
+            );
+
+            for case in cases {
+                write_test_case(&mut xml, case);
+            }
+
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+
+        let _ = writeln!(xml, "</testsuites>");
+
+        xml
+    }
+}
+
+fn find_failed_requirement(action: &Action) -> Option<&Operation> {
+    // Requirement checks run before the task, so a failed check
+    // can only be the last process when no task execution exists
+    if action.operations.get_last_execution().is_some() {
+        return None;
+    }
+
+    action
+        .operations
+        .0
+        .iter()
+        .rfind(|op| op.meta.is_process_execution() && op.has_failed())
+}
+
+fn write_test_case(xml: &mut String, case: &TestCase) {
+    let _ = write!(
+        xml,
+        r#"    <testcase name="{}" classname="{}" time="{}""#,
+        escape(&case.name),
+        escape(&case.classname),
+        format_seconds(&case.duration),
+    );
+
+    if case.result == TestCaseResult::Passed && case.stdout.is_none() && case.stderr.is_none() {
+        let _ = writeln!(xml, " />");
+
+        return;
+    }
+
+    let _ = writeln!(xml, ">");
+
+    match &case.result {
+        TestCaseResult::Passed => {}
+        TestCaseResult::Failed { kind, message } => {
+            let _ = writeln!(
+                xml,
+                r#"      <failure type="{kind}" message="{}">{}</failure>"#,
+                escape(first_line(message)),
+                escape(message),
+            );
+        }
+        TestCaseResult::Skipped { message } => {
+            let _ = writeln!(xml, r#"      <skipped message="{}" />"#, escape(message));
+        }
+    };
+
+    if let Some(stdout) = &case.stdout {
+        let _ = writeln!(xml, "      <system-out>{}</system-out>", escape(stdout));
+    }
+
+    if let Some(stderr) = &case.stderr {
+        let _ = writeln!(xml, "      <system-err>{}</system-err>", escape(stderr));
+    }
+
+    let _ = writeln!(xml, "    </testcase>");
+}
+
+fn count_failures<'a>(cases: impl Iterator<Item = &'a TestCase>) -> usize {
+    cases
+        .filter(|case| matches!(case.result, TestCaseResult::Failed { .. }))
+        .count()
+}
+
+fn count_skipped<'a>(cases: impl Iterator<Item = &'a TestCase>) -> usize {
+    cases
+        .filter(|case| matches!(case.result, TestCaseResult::Skipped { .. }))
+        .count()
+}
+
+fn format_seconds(duration: &Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64())
+}
+
+fn first_line(value: &str) -> &str {
+    value.lines().next().unwrap_or_default()
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Strip ANSI color codes from captured output
+            '\u{1b}' => {
+                if chars.next() == Some('[') {
+                    for next in chars.by_ref() {
+                        if ('@'..='~').contains(&next) {
+                            break;
+                        }
+                    }
+                }
+            }
+            // Other control characters are not allowed in XML 1.0
+            '\t' | '\n' | '\r' => escaped.push(ch),
+            ch if ch.is_control() => {}
+            ch => escaped.push(ch),
+        }
+    }
+
+    escaped
+}
@@ -1 +1,5 @@
 pub mod estimate;
+pub mod junit;
+pub mod sarif;
+pub mod timings;
+pub mod trace;
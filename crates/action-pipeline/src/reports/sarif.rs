@@ -0,0 +1,91 @@
+use super::junit::{TestCase, TestCaseResult};
+use moon_action::{Action, ActionNode};
+use serde::Serialize;
+use serde_json::{Value, json};
+use tracing::{debug, instrument};
+
+// https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+const RULES: [(&str, &str, &str); 3] = [
+    ("task", "task-failed", "Task failed to run successfully."),
+    (
+        "requirement",
+        "requirement-check-failed",
+        "Task was unable to run as a requirement check failed.",
+    ),
+    ("timeout", "task-timed-out", "Task exceeded its timeout."),
+];
+
+#[derive(Debug, Serialize)]
+pub struct SarifReport {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+
+    pub version: &'static str,
+
+    pub runs: Vec<Value>,
+}
+
+impl SarifReport {
+    /// Generate a report where each failed task is a result.
+    #[instrument(name = "generate_sarif", skip_all)]
+    pub fn generate(actions: &[Action]) -> Self {
+        debug!("Generating a SARIF report of the pipeline");
+
+        let mut results = vec![];
+
+        for action in actions {
+            let ActionNode::RunTask(node) = &*action.node else {
+                continue;
+            };
+
+            let Some(TestCase {
+                result: TestCaseResult::Failed { kind, message },
+                ..
+            }) = TestCase::from_action(action)
+            else {
+                continue;
+            };
+
+            let rule_index = RULES
+                .iter()
+                .position(|(rule_kind, _, _)| *rule_kind == kind)
+                .unwrap_or_default();
+
+            results.push(json!({
+                "ruleId": RULES[rule_index].1,
+                "ruleIndex": rule_index,
+                "level": "error",
+                "message": {
+                    "text": message,
+                },
+                "locations": [{
+                    "logicalLocations": [{
+                        "fullyQualifiedName": node.target.as_str(),
+                        "kind": "module",
+                    }],
+                }],
+            }));
+        }
+
+        SarifReport {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![json!({
+                "tool": {
+                    "driver": {
+                        "name": "moon",
+                        "informationUri": "https://moonrepo.dev/moon",
+                        "rules": RULES.iter().map(|(_, id, description)| json!({
+                            "id": id,
+                            "shortDescription": {
+                                "text": description,
+                            },
+                        })).collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            })],
+        }
+    }
+}
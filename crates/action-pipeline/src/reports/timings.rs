@@ -0,0 +1,49 @@
+use moon_action::{Action, ActionNode, ActionStatus};
+use moon_task::Target;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::{debug, instrument};
+
+/// A JSON object of task targets to their durations in milliseconds,
+/// that can be passed to `--job-timings` for the balanced job strategy.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct TimingsReport {
+    pub timings: BTreeMap<String, u64>,
+}
+
+impl TimingsReport {
+    /// Generate timings for every task in the pipeline. Durations recorded
+    /// across previous runs are preferred, as they're not affected by cache
+    /// hits, otherwise the duration of the action is used if it executed.
+    #[instrument(name = "generate_timings", skip_all)]
+    pub fn generate(
+        actions: &[Action],
+        load_recorded_duration: impl Fn(&Target) -> Option<Duration>,
+    ) -> Self {
+        debug!("Generating timings of tasks");
+
+        let mut timings = BTreeMap::new();
+
+        for action in actions {
+            let ActionNode::RunTask(node) = &*action.node else {
+                continue;
+            };
+
+            let duration = load_recorded_duration(&node.target).or_else(|| {
+                if action.status == ActionStatus::Passed {
+                    action.duration
+                } else {
+                    None
+                }
+            });
+
+            if let Some(duration) = duration {
+                timings.insert(node.target.to_string(), duration.as_millis() as u64);
+            }
+        }
+
+        Self { timings }
+    }
+}
@@ -0,0 +1,227 @@
+use moon_action::Action;
+use moon_time::chrono::NaiveDateTime;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tracing::{debug, instrument};
+
+// https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+
+const PROCESS_ID: u32 = 1;
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct TraceEvent {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cat: Option<String>,
+
+    pub ph: &'static str,
+
+    /// Timestamp in microseconds, relative to the first action.
+    pub ts: u64,
+
+    /// Duration in microseconds, for complete events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dur: Option<u64>,
+
+    pub pid: u32,
+
+    pub tid: usize,
+
+    /// Links the start and end of a flow event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<usize>,
+
+    /// Binding point of a flow event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bp: Option<&'static str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Value>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceReport {
+    pub trace_events: Vec<TraceEvent>,
+
+    pub display_time_unit: &'static str,
+}
+
+struct TracedAction<'data> {
+    action: &'data Action,
+    start: u64,
+    end: u64,
+    slot: usize,
+}
+
+impl TraceReport {
+    /// Generate a trace from the actions that ran in the pipeline, where
+    /// each action is placed on the track of the concurrency slot it occupied,
+    /// and edges are a list of dependent to dependency node indices.
+    #[instrument(name = "generate_trace", skip_all)]
+    pub fn generate(actions: &[Action], edges: &[(usize, usize)]) -> Self {
+        debug!("Generating a trace of the pipeline");
+
+        let mut ran = actions
+            .iter()
+            .filter(|action| action.started_at.is_some())
+            .collect::<Vec<_>>();
+
+        ran.sort_by_key(|action| (action.started_at, action.node_index));
+
+        let Some(origin) = ran.first().and_then(|action| action.started_at) else {
+            return Self::default();
+        };
+
+        // Actions don't record which worker ran them, so derive the slots
+        // by placing each action in the first slot that was idle when it
+        // started, which is equivalent to how the semaphore hands out permits
+        let mut slots: Vec<u64> = vec![];
+        let mut traced: FxHashMap<usize, TracedAction> = FxHashMap::default();
+
+        for action in ran {
+            let start = micros_between(origin, action.started_at.unwrap());
+            let end = start
+                + action
+                    .duration
+                    .map(|duration| duration.as_micros() as u64)
+                    .unwrap_or_else(|| {
+                        action
+                            .finished_at
+                            .map(|finished_at| {
+                                micros_between(origin, finished_at).saturating_sub(start)
+                            })
+                            .unwrap_or_default()
+                    });
+
+            let slot = match slots.iter().position(|slot_end| *slot_end <= start) {
+                Some(index) => {
+                    slots[index] = end;
+                    index
+                }
+                None => {
+                    slots.push(end);
+                    slots.len() - 1
+                }
+            };
+
+            traced.insert(
+                action.node_index,
+                TracedAction {
+                    action,
+                    start,
+                    end,
+                    slot,
+                },
+            );
+        }
+
+        let mut events = vec![TraceEvent {
+            name: "process_name".into(),
+            cat: None,
+            ph: "M",
+            ts: 0,
+            dur: None,
+            pid: PROCESS_ID,
+            tid: 0,
+            id: None,
+            bp: None,
+            args: Some(json!({ "name": "moon pipeline" })),
+        }];
+
+        for slot in 0..slots.len() {
+            events.push(TraceEvent {
+                name: "thread_name".into(),
+                cat: None,
+                ph: "M",
+                ts: 0,
+                dur: None,
+                pid: PROCESS_ID,
+                tid: slot + 1,
+                id: None,
+                bp: None,
+                args: Some(json!({ "name": format!("Slot {}", slot + 1) })),
+            });
+        }
+
+        let mut ordered = traced.values().collect::<Vec<_>>();
+
+        ordered.sort_by_key(|item| (item.start, item.slot));
+
+        for item in ordered {
+            let mut args = json!({
+                "index": item.action.node_index,
+                "status": item.action.status,
+            });
+
+            if let Some(error) = &item.action.error {
+                args["error"] = json!(error);
+            }
+
+            events.push(TraceEvent {
+                name: item.action.label.clone(),
+                cat: Some(item.action.get_prefix().into()),
+                ph: "X",
+                ts: item.start,
+                dur: Some(item.end - item.start),
+                pid: PROCESS_ID,
+                tid: item.slot + 1,
+                id: None,
+                bp: None,
+                args: Some(args),
+            });
+        }
+
+        // Draw an arrow from the end of each dependency to the start of its
+        // dependent, so it's clear which actions were blocking others
+        let mut flow_id = 0;
+
+        for (dependent, dependency) in edges {
+            let (Some(from), Some(to)) = (traced.get(dependency), traced.get(dependent)) else {
+                continue;
+            };
+
+            flow_id += 1;
+
+            events.push(TraceEvent {
+                name: "dependency".into(),
+                cat: Some("dependency".into()),
+                ph: "s",
+                ts: from.end.saturating_sub(1).max(from.start),
+                dur: None,
+                pid: PROCESS_ID,
+                tid: from.slot + 1,
+                id: Some(flow_id),
+                bp: None,
+                args: None,
+            });
+
+            events.push(TraceEvent {
+                name: "dependency".into(),
+                cat: Some("dependency".into()),
+                ph: "f",
+                ts: to.start,
+                dur: None,
+                pid: PROCESS_ID,
+                tid: to.slot + 1,
+                id: Some(flow_id),
+                bp: Some("e"),
+                args: None,
+            });
+        }
+
+        TraceReport {
+            trace_events: events,
+            display_time_unit: "ms",
+        }
+    }
+}
+
+fn micros_between(origin: NaiveDateTime, timestamp: NaiveDateTime) -> u64 {
+    (timestamp - origin)
+        .num_microseconds()
+        .unwrap_or_default()
+        .max(0) as u64
+}
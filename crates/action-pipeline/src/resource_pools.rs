@@ -0,0 +1,172 @@
+use moon_common::Id;
+use rustc_hash::FxHashMap;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{AcquireError, OwnedSemaphorePermit, Semaphore};
+use tracing::trace;
+
+/// The reserved pool that maps to the pipeline's concurrency.
+pub const CPU_POOL: &str = "cpu";
+
+/// Named pools of weighted permits that jobs must acquire before
+/// being dispatched. The `cpu` pool is sized by the pipeline's
+/// concurrency, while other pools are sized by configuration.
+pub struct ResourcePools {
+    capacities: FxHashMap<Id, u32>,
+    concurrency: u32,
+    cpu: Arc<Semaphore>,
+    pools: Mutex<FxHashMap<Id, Arc<Semaphore>>>,
+}
+
+impl ResourcePools {
+    pub fn new(concurrency: usize, capacities: FxHashMap<Id, u32>) -> Self {
+        let concurrency = u32::try_from(concurrency)
+            .unwrap_or(u32::MAX)
+            .clamp(1, Semaphore::MAX_PERMITS as u32);
+
+        Self {
+            capacities,
+            concurrency,
+            cpu: Arc::new(Semaphore::new(concurrency as usize)),
+            pools: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// Acquire weighted permits from all requested pools, waiting until
+    /// each pool has enough capacity. Every job acquires at least 1 `cpu`
+    /// permit. Pools are always acquired in the same order (by name, then
+    /// `cpu` last so that waiting jobs don't hold a concurrency slot) to
+    /// avoid deadlocks between jobs.
+    pub async fn acquire(
+        &self,
+        resources: &BTreeMap<Id, u32>,
+    ) -> Result<Vec<OwnedSemaphorePermit>, AcquireError> {
+        let mut permits = vec![];
+
+        for (pool, weight) in resources {
+            if pool.as_str() == CPU_POOL || *weight == 0 {
+                continue;
+            }
+
+            let (semaphore, capacity) = self.get_pool(pool);
+
+            // Never request more than the pool can hold, otherwise
+            // the job would wait forever
+            let weight = (*weight).min(capacity);
+
+            trace!(
+                pool = pool.as_str(),
+                weight, capacity, "Acquiring resource permits"
+            );
+
+            permits.push(semaphore.acquire_many_owned(weight).await?);
+        }
+
+        let cpu_weight = resources
+            .get(CPU_POOL)
+            .copied()
+            .unwrap_or(1)
+            .clamp(1, self.concurrency);
+
+        permits.push(self.cpu.clone().acquire_many_owned(cpu_weight).await?);
+
+        Ok(permits)
+    }
+
+    fn get_pool(&self, pool: &Id) -> (Arc<Semaphore>, u32) {
+        let capacity = self
+            .capacities
+            .get(pool)
+            .copied()
+            .unwrap_or(1)
+            .clamp(1, Semaphore::MAX_PERMITS as u32);
+
+        let mut pools = self
+            .pools
+            .lock()
+            .expect("Failed to acquire resource pools!");
+
+        let semaphore = pools
+            .entry(pool.to_owned())
+            .or_insert_with(|| Arc::new(Semaphore::new(capacity as usize)));
+
+        (Arc::clone(semaphore), capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    fn create_resources(list: &[(&str, u32)]) -> BTreeMap<Id, u32> {
+        list.iter()
+            .map(|(pool, weight)| (Id::raw(pool), *weight))
+            .collect()
+    }
+
+    async fn can_acquire(pools: &ResourcePools, resources: &BTreeMap<Id, u32>) -> bool {
+        timeout(Duration::from_millis(50), pools.acquire(resources))
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn acquires_a_cpu_permit_by_default() {
+        let pools = ResourcePools::new(2, FxHashMap::default());
+        let resources = BTreeMap::default();
+
+        let _a = pools.acquire(&resources).await.unwrap();
+        let _b = pools.acquire(&resources).await.unwrap();
+
+        assert!(!can_acquire(&pools, &resources).await);
+    }
+
+    #[tokio::test]
+    async fn acquires_weighted_cpu_permits() {
+        let pools = ResourcePools::new(4, FxHashMap::default());
+
+        let _heavy = pools
+            .acquire(&create_resources(&[("cpu", 3)]))
+            .await
+            .unwrap();
+
+        assert!(can_acquire(&pools, &BTreeMap::default()).await);
+        assert!(!can_acquire(&pools, &create_resources(&[("cpu", 2)])).await);
+    }
+
+    #[tokio::test]
+    async fn clamps_weights_to_capacity() {
+        let pools = ResourcePools::new(2, FxHashMap::from_iter([(Id::raw("db"), 2)]));
+
+        assert!(can_acquire(&pools, &create_resources(&[("cpu", 10), ("db", 10)])).await);
+    }
+
+    #[tokio::test]
+    async fn waits_for_named_pools() {
+        let pools = ResourcePools::new(8, FxHashMap::from_iter([(Id::raw("memory"), 4)]));
+
+        let _a = pools
+            .acquire(&create_resources(&[("memory", 3)]))
+            .await
+            .unwrap();
+
+        assert!(can_acquire(&pools, &create_resources(&[("memory", 1)])).await);
+        assert!(!can_acquire(&pools, &create_resources(&[("memory", 2)])).await);
+    }
+
+    #[tokio::test]
+    async fn unconfigured_pools_are_exclusive() {
+        let pools = ResourcePools::new(8, FxHashMap::default());
+        let resources = create_resources(&[("gpu", 1)]);
+
+        let permits = pools.acquire(&resources).await.unwrap();
+
+        assert!(!can_acquire(&pools, &resources).await);
+
+        drop(permits);
+
+        assert!(can_acquire(&pools, &resources).await);
+    }
+}
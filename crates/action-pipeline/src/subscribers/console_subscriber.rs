@@ -1,16 +1,44 @@
 use crate::event_emitter::{Event, Subscriber};
 use async_trait::async_trait;
-use moon_console::{Console, Level, PipelineReportItem};
+use moon_cache::CacheEngine;
+use moon_console::{CacheReportItem, Console, Level, PipelineReportItem};
 use std::sync::Arc;
 
 pub struct ConsoleSubscriber {
+    cache_engine: Arc<CacheEngine>,
     console: Arc<Console>,
     summary: Option<Level>,
 }
 
 impl ConsoleSubscriber {
-    pub fn new(console: Arc<Console>, summary: Option<Level>) -> Self {
-        Self { console, summary }
+    pub fn new(
+        console: Arc<Console>,
+        cache_engine: Arc<CacheEngine>,
+        summary: Option<Level>,
+    ) -> Self {
+        Self {
+            cache_engine,
+            console,
+            summary,
+        }
+    }
+
+    fn get_cache_items(&self) -> Vec<CacheReportItem> {
+        self.cache_engine
+            .storage
+            .get_metrics()
+            .into_iter()
+            .filter(|(_, metrics)| metrics.remote && !metrics.is_empty())
+            .map(|(id, metrics)| CacheReportItem {
+                backend: id.to_string(),
+                hits: metrics.hits,
+                misses: metrics.misses,
+                bytes_read: metrics.bytes_read,
+                bytes_written: metrics.bytes_written,
+                errors: metrics.errors,
+                latency: metrics.get_average_latency(),
+            })
+            .collect()
     }
 }
 
@@ -29,6 +57,7 @@ impl Subscriber for ConsoleSubscriber {
                 ..
             } => {
                 let item = PipelineReportItem {
+                    cache: self.get_cache_items(),
                     duration: *duration,
                     summary: self.summary,
                     status: **status,
@@ -0,0 +1,91 @@
+use crate::event_emitter::{Event, Subscriber};
+use async_trait::async_trait;
+use moon_common::format_error_chain;
+use moon_daemon_client::{DaemonClient, PipelineEvent};
+use moon_time::now_millis;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
+use tracing::debug;
+use uuid::Uuid;
+
+// Events are dropped when the daemon can't keep up,
+// instead of slowing down the pipeline
+const EVENT_BUFFER_SIZE: usize = 256;
+
+pub struct DaemonSubscriber {
+    pipeline_id: String,
+    publisher: Option<JoinHandle<()>>,
+    sender: Option<mpsc::Sender<PipelineEvent>>,
+}
+
+impl DaemonSubscriber {
+    pub fn new(daemon_client: DaemonClient) -> Self {
+        let (sender, receiver) = mpsc::channel(EVENT_BUFFER_SIZE);
+
+        DaemonSubscriber {
+            pipeline_id: Uuid::new_v4().to_string(),
+            publisher: Some(tokio::spawn(publish_events(daemon_client, receiver))),
+            sender: Some(sender),
+        }
+    }
+
+    async fn wait_for_publisher(&mut self) {
+        // Dropping the sender closes the channel once drained
+        self.sender.take();
+
+        if let Some(handle) = self.publisher.take() {
+            debug!("Waiting for pipeline events to be published to the daemon");
+
+            let _ = handle.await;
+        }
+    }
+}
+
+async fn publish_events(
+    mut daemon_client: DaemonClient,
+    mut receiver: mpsc::Receiver<PipelineEvent>,
+) {
+    while let Some(event) = receiver.recv().await {
+        // Streaming events is best effort, so a failing daemon
+        // should never fail the pipeline itself
+        if let Err(error) = daemon_client.publish_event(event).await {
+            debug!(
+                error = format_error_chain(&error),
+                "Failed to publish pipeline event to the daemon, disabling event streaming"
+            );
+
+            break;
+        }
+    }
+}
+
+#[async_trait]
+impl Subscriber for DaemonSubscriber {
+    async fn on_emit<'data>(&mut self, event: &Event<'data>) -> miette::Result<()> {
+        if let Some(sender) = &self.sender {
+            let result = sender.try_send(PipelineEvent {
+                pipeline_id: self.pipeline_id.clone(),
+                r#type: event.get_type().to_owned(),
+                payload: serde_json::to_string(event).unwrap_or_default(),
+                timestamp: now_millis() as u64,
+            });
+
+            match result {
+                Ok(_) => {}
+                Err(TrySendError::Full(_)) => {
+                    debug!("Daemon event buffer is full, dropping pipeline event");
+                }
+                // The publisher has stopped because the daemon failed
+                Err(TrySendError::Closed(_)) => {
+                    self.sender = None;
+                }
+            }
+        }
+
+        if matches!(event, Event::PipelineCompleted { .. }) {
+            self.wait_for_publisher().await;
+        }
+
+        Ok(())
+    }
+}
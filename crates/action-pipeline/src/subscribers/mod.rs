@@ -1,5 +1,6 @@
 pub mod cleanup_subscriber;
 pub mod console_subscriber;
+pub mod daemon_subscriber;
 pub mod notifications_subscriber;
 pub mod reports_subscriber;
 // pub mod telemetry_subscriber;
@@ -1,10 +1,19 @@
 use crate::event_emitter::{Event, Subscriber};
 use crate::reports::estimate::Estimate;
+use crate::reports::junit::JunitReport;
+use crate::reports::sarif::SarifReport;
+use crate::reports::timings::TimingsReport;
+use crate::reports::trace::TraceReport;
 use async_trait::async_trait;
 use moon_action::{Action, ActionPipelineStatus};
 use moon_action_context::ActionContext;
-use moon_cache::CacheEngine;
+use moon_cache::{CacheEngine, StorageBackendMetrics};
+use moon_common::Id;
+use moon_exec_plan::ReportFormat;
+use moon_task_runner::TaskRunCacheState;
 use serde::Serialize;
+use starbase_utils::fs;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::debug;
@@ -14,6 +23,9 @@ use tracing::debug;
 pub struct RunReport<'data> {
     pub actions: &'data [Action],
 
+    /// Read and write activity of each cache storage backend.
+    pub cache: BTreeMap<Id, StorageBackendMetrics>,
+
     pub context: &'data ActionContext,
 
     /// How long the pipeline took to execute all actions.
@@ -30,6 +42,12 @@ pub struct ReportsSubscriber {
     cache_engine: Arc<CacheEngine>,
     action_context: Arc<ActionContext>,
     report_name: String,
+
+    /// Additional formats to write, alongside the JSON report.
+    formats: Vec<ReportFormat>,
+
+    /// Dependent to dependency node indices of the action graph.
+    edges: Vec<(usize, usize)>,
 }
 
 impl ReportsSubscriber {
@@ -42,8 +60,26 @@ impl ReportsSubscriber {
             cache_engine,
             action_context,
             report_name: report_name.to_owned(),
+            formats: vec![],
+            edges: vec![],
         }
     }
+
+    pub fn with_formats(mut self, formats: Vec<ReportFormat>, edges: Vec<(usize, usize)>) -> Self {
+        self.formats = formats;
+        self.edges = edges;
+        self
+    }
+
+    // runReport.json -> runTrace.json, runReport.xml, etc
+    fn get_file_name(&self, suffix: &str) -> String {
+        let prefix = self
+            .report_name
+            .strip_suffix("Report.json")
+            .unwrap_or("run");
+
+        format!("{prefix}{suffix}")
+    }
 }
 
 #[async_trait]
@@ -62,6 +98,7 @@ impl Subscriber for ReportsSubscriber {
 
             let report = RunReport {
                 actions,
+                cache: self.cache_engine.storage.get_metrics(),
                 context: &self.action_context,
                 duration,
                 comparison_estimate: estimate,
@@ -69,6 +106,50 @@ impl Subscriber for ReportsSubscriber {
             };
 
             self.cache_engine.write(&self.report_name, &report)?;
+
+            for format in &self.formats {
+                match format {
+                    ReportFormat::Junit => {
+                        debug!("Creating JUnit report");
+
+                        fs::write_file(
+                            self.cache_engine
+                                .resolve_path(self.get_file_name("Report.xml")),
+                            JunitReport::generate(actions, duration).to_xml(),
+                        )?;
+                    }
+                    ReportFormat::Sarif => {
+                        debug!("Creating SARIF report");
+
+                        self.cache_engine.write(
+                            self.get_file_name("Report.sarif"),
+                            &SarifReport::generate(actions),
+                        )?;
+                    }
+                    ReportFormat::Timings => {
+                        debug!("Creating timings report");
+
+                        let timings = TimingsReport::generate(actions, |target| {
+                            self.cache_engine
+                                .state
+                                .load_target_state::<TaskRunCacheState>(target)
+                                .ok()
+                                .and_then(|state| state.data.get_estimated_duration())
+                        });
+
+                        self.cache_engine
+                            .write(self.get_file_name("Timings.json"), &timings)?;
+                    }
+                    ReportFormat::Trace => {
+                        debug!("Creating trace report");
+
+                        self.cache_engine.write(
+                            self.get_file_name("Trace.json"),
+                            &TraceReport::generate(actions, &self.edges),
+                        )?;
+                    }
+                }
+            }
         }
 
         Ok(())
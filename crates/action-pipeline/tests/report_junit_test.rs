@@ -0,0 +1,191 @@
+use moon_action::*;
+use moon_action_pipeline::reports::junit::{JunitReport, TestCase, TestCaseResult};
+use moon_action_pipeline::reports::sarif::SarifReport;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_action(target: &str, status: ActionStatus) -> Action {
+    Action {
+        duration: Some(Duration::from_millis(1500)),
+        node: Arc::new(ActionNode::run_task(RunTaskNode::new(target.into()))),
+        status,
+        ..Action::default()
+    }
+}
+
+fn create_process_op(meta: OperationMeta, status: ActionStatus) -> Operation {
+    Operation::new_finished(meta, status)
+}
+
+fn create_output(stdout: &str, stderr: &str) -> Box<OperationMetaProcessOutput> {
+    let mut output = OperationMetaProcessOutput {
+        exit_code: Some(1),
+        ..Default::default()
+    };
+    output.set_stdout(stdout.into());
+    output.set_stderr(stderr.into());
+
+    Box::new(output)
+}
+
+mod junit {
+    use super::*;
+
+    #[test]
+    fn ignores_non_task_actions() {
+        let action = Action {
+            node: Arc::new(ActionNode::sync_workspace()),
+            ..Action::default()
+        };
+
+        assert!(TestCase::from_action(&action).is_none());
+    }
+
+    #[test]
+    fn maps_statuses() {
+        let passed = TestCase::from_action(&create_action("a:build", ActionStatus::Cached));
+        let skipped = TestCase::from_action(&create_action("a:test", ActionStatus::Skipped));
+
+        assert_eq!(passed.unwrap().result, TestCaseResult::Passed);
+        assert!(matches!(
+            skipped.unwrap().result,
+            TestCaseResult::Skipped { .. }
+        ));
+    }
+
+    #[test]
+    fn marks_allowed_failures_as_skipped() {
+        let mut action = create_action("a:lint", ActionStatus::Failed);
+        action.allow_failure = true;
+        action.error = Some("Oops".into());
+
+        assert_eq!(
+            TestCase::from_action(&action).unwrap().result,
+            TestCaseResult::Skipped {
+                message: "Failure allowed: Oops".into()
+            }
+        );
+    }
+
+    #[test]
+    fn marks_quarantined_failures_as_skipped() {
+        let mut action = create_action("a:lint", ActionStatus::Failed);
+        action.allow_failure = true;
+        action.quarantined = true;
+        action.error = Some("Oops".into());
+
+        assert_eq!(
+            TestCase::from_action(&action).unwrap().result,
+            TestCaseResult::Skipped {
+                message: "Failure quarantined: Oops".into()
+            }
+        );
+    }
+
+    #[test]
+    fn captures_task_output_on_failure() {
+        let mut action = create_action("a:test", ActionStatus::Failed);
+        action.error = Some("Task a:test failed to run.\nMore details".into());
+        action.operations.0.push(create_process_op(
+            OperationMeta::TaskExecution(create_output("out", "err")),
+            ActionStatus::Failed,
+        ));
+
+        let case = TestCase::from_action(&action).unwrap();
+
+        assert_eq!(case.name, "test");
+        assert_eq!(case.classname, "a");
+        assert_eq!(case.stdout.as_deref(), Some("out"));
+        assert_eq!(case.stderr.as_deref(), Some("err"));
+        assert_eq!(
+            case.result,
+            TestCaseResult::Failed {
+                kind: "task",
+                message: "Task a:test failed to run.\nMore details".into()
+            }
+        );
+    }
+
+    #[test]
+    fn detects_requirement_check_failures() {
+        let mut action = create_action("a:deploy", ActionStatus::Failed);
+        action.error = Some("Requirement failed".into());
+        action.operations.0.push(create_process_op(
+            OperationMeta::ProcessExecution(create_output("", "not logged in")),
+            ActionStatus::Failed,
+        ));
+
+        let case = TestCase::from_action(&action).unwrap();
+
+        assert_eq!(case.stderr.as_deref(), Some("not logged in"));
+        assert_eq!(
+            case.result,
+            TestCaseResult::Failed {
+                kind: "requirement",
+                message: "Requirement failed".into()
+            }
+        );
+    }
+
+    #[test]
+    fn renders_xml() {
+        let mut failed = create_action("b:test", ActionStatus::Failed);
+        failed.error = Some("Expected <true> & got \"false\"".into());
+        failed.operations.0.push(create_process_op(
+            OperationMeta::TaskExecution(create_output("\u{1b}[31mred\u{1b}[0m", "")),
+            ActionStatus::Failed,
+        ));
+
+        let report = JunitReport::generate(
+            &[
+                create_action("a:build", ActionStatus::Passed),
+                failed,
+                create_action("b:lint", ActionStatus::Skipped),
+            ],
+            &Duration::from_secs(3),
+        );
+
+        assert_eq!(
+            report.to_xml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="moon" tests="3" failures="1" skipped="1" time="3.000">
+  <testsuite name="a" tests="1" failures="0" skipped="0" time="1.500">
+    <testcase name="build" classname="a" time="1.500" />
+  </testsuite>
+  <testsuite name="b" tests="2" failures="1" skipped="1" time="3.000">
+    <testcase name="test" classname="b" time="1.500">
+      <failure type="task" message="Expected &lt;true&gt; &amp; got &quot;false&quot;">Expected &lt;true&gt; &amp; got &quot;false&quot;</failure>
+      <system-out>red</system-out>
+    </testcase>
+    <testcase name="lint" classname="b" time="1.500">
+      <skipped message="Task did not run" />
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
+
+mod sarif {
+    use super::*;
+
+    #[test]
+    fn includes_only_failures() {
+        let mut failed = create_action("b:test", ActionStatus::TimedOut);
+        failed.error = Some("Timed out".into());
+
+        let report =
+            SarifReport::generate(&[create_action("a:build", ActionStatus::Passed), failed]);
+        let results = report.runs[0]["results"].as_array().unwrap();
+
+        assert_eq!(report.version, "2.1.0");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "task-timed-out");
+        assert_eq!(results[0]["message"]["text"], "Timed out");
+        assert_eq!(
+            results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+            "b:test"
+        );
+    }
+}
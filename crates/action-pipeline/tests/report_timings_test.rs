@@ -0,0 +1,64 @@
+use moon_action::*;
+use moon_action_pipeline::reports::timings::TimingsReport;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_action(target: &str, status: ActionStatus, duration_ms: u64) -> Action {
+    Action {
+        duration: Some(Duration::from_millis(duration_ms)),
+        node: Arc::new(ActionNode::run_task(RunTaskNode::new(target.into()))),
+        status,
+        ..Action::default()
+    }
+}
+
+mod timings {
+    use super::*;
+
+    #[test]
+    fn prefers_recorded_durations() {
+        let report = TimingsReport::generate(
+            &[
+                create_action("a:build", ActionStatus::Cached, 5),
+                create_action("b:build", ActionStatus::Passed, 100),
+            ],
+            |target| (target.as_str() == "a:build").then_some(Duration::from_millis(2000)),
+        );
+
+        assert_eq!(
+            report.timings,
+            BTreeMap::from_iter([("a:build".into(), 2000), ("b:build".into(), 100)])
+        );
+    }
+
+    #[test]
+    fn skips_tasks_that_didnt_execute_without_recorded_durations() {
+        let report = TimingsReport::generate(
+            &[
+                create_action("a:build", ActionStatus::Cached, 5),
+                create_action("b:build", ActionStatus::Failed, 100),
+                Action {
+                    node: Arc::new(ActionNode::sync_workspace()),
+                    ..Action::default()
+                },
+            ],
+            |_| None,
+        );
+
+        assert!(report.timings.is_empty());
+    }
+
+    #[test]
+    fn serializes_as_an_object_of_targets() {
+        let report = TimingsReport::generate(
+            &[create_action("a:build", ActionStatus::Passed, 1500)],
+            |_| None,
+        );
+
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"a:build":1500}"#
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use moon_action::*;
+use moon_action_pipeline::reports::trace::{TraceEvent, TraceReport};
+use moon_time::chrono::{NaiveDateTime, TimeDelta};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_action(index: usize, target: &str, start_ms: u64, duration_ms: u64) -> Action {
+    let origin = NaiveDateTime::default();
+
+    Action {
+        duration: Some(Duration::from_millis(duration_ms)),
+        label: format!("RunTask({target})"),
+        node: Arc::new(ActionNode::run_task(RunTaskNode::new(target.into()))),
+        node_index: index,
+        started_at: Some(origin + TimeDelta::milliseconds(start_ms as i64)),
+        status: ActionStatus::Passed,
+        ..Action::default()
+    }
+}
+
+fn get_slices(report: &TraceReport) -> Vec<(&str, u64, u64, usize)> {
+    report
+        .trace_events
+        .iter()
+        .filter(|event| event.ph == "X")
+        .map(|event| (event.name.as_str(), event.ts, event.dur.unwrap(), event.tid))
+        .collect()
+}
+
+fn get_flows(report: &TraceReport) -> Vec<&TraceEvent> {
+    report
+        .trace_events
+        .iter()
+        .filter(|event| event.ph == "s" || event.ph == "f")
+        .collect()
+}
+
+mod trace {
+    use super::*;
+
+    #[test]
+    fn returns_empty_when_nothing_ran() {
+        let report = TraceReport::generate(
+            &[Action {
+                node: Arc::new(ActionNode::run_task(RunTaskNode::new("a:build".into()))),
+                ..Action::default()
+            }],
+            &[],
+        );
+
+        assert!(report.trace_events.is_empty());
+    }
+
+    #[test]
+    fn places_actions_in_concurrency_slots() {
+        let report = TraceReport::generate(
+            &[
+                create_action(0, "a:build", 0, 100),
+                create_action(1, "b:build", 0, 50),
+                create_action(2, "c:build", 100, 50),
+                create_action(3, "d:build", 60, 20),
+            ],
+            &[],
+        );
+
+        assert_eq!(
+            get_slices(&report),
+            vec![
+                ("RunTask(a:build)", 0, 100_000, 1),
+                ("RunTask(b:build)", 0, 50_000, 2),
+                ("RunTask(d:build)", 60_000, 20_000, 2),
+                ("RunTask(c:build)", 100_000, 50_000, 1),
+            ]
+        );
+
+        let threads = report
+            .trace_events
+            .iter()
+            .filter(|event| event.name == "thread_name")
+            .count();
+
+        assert_eq!(threads, 2);
+    }
+
+    #[test]
+    fn links_dependencies_with_flows() {
+        let report = TraceReport::generate(
+            &[
+                create_action(0, "a:build", 0, 100),
+                create_action(1, "b:build", 0, 50),
+                create_action(2, "c:build", 100, 50),
+            ],
+            // c -> a, and c -> missing
+            &[(2, 0), (2, 5)],
+        );
+
+        let flows = get_flows(&report);
+
+        assert_eq!(flows.len(), 2);
+
+        assert_eq!(flows[0].ph, "s");
+        assert_eq!(flows[0].ts, 99_999);
+        assert_eq!(flows[0].tid, 1);
+        assert_eq!(flows[0].id, Some(1));
+
+        assert_eq!(flows[1].ph, "f");
+        assert_eq!(flows[1].ts, 100_000);
+        assert_eq!(flows[1].tid, 1);
+        assert_eq!(flows[1].id, Some(1));
+        assert_eq!(flows[1].bp, Some("e"));
+    }
+}
@@ -64,6 +64,8 @@ pub struct Action {
 
     pub operations: OperationList,
 
+    pub quarantined: bool,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub started_at: Option<NaiveDateTime>,
 
@@ -87,6 +89,7 @@ impl Action {
             node: Arc::new(node),
             node_index: 0,
             operations: OperationList::default(),
+            quarantined: false,
             started_at: None,
             start_time: None,
             status: ActionStatus::Running,
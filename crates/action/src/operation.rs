@@ -272,6 +272,10 @@ impl Operation {
         Self::new(OperationMeta::ArchiveCreation)
     }
 
+    pub fn file_audit() -> Self {
+        Self::new(OperationMeta::FileAudit(Default::default()))
+    }
+
     pub fn hash_generation() -> Self {
         Self::new(OperationMeta::HashGeneration(Default::default()))
     }
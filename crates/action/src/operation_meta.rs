@@ -17,6 +17,16 @@ pub struct OperationMetaFileChange {
     pub changed_files: Vec<PathBuf>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OperationMetaFileAudit {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub undeclared_inputs: Vec<PathBuf>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub undeclared_outputs: Vec<PathBuf>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct OperationMetaProcessOutput {
@@ -68,6 +78,7 @@ pub enum OperationMeta {
 
     // Metrics
     ArchiveCreation,
+    FileAudit(Box<OperationMetaFileAudit>),
     HashGeneration(Box<OperationMetaHash>),
     MutexAcquisition,
 }
@@ -77,6 +88,10 @@ impl OperationMeta {
         matches!(self, Self::ArchiveCreation)
     }
 
+    pub fn is_file_audit(&self) -> bool {
+        matches!(self, Self::FileAudit(_))
+    }
+
     pub fn is_hash_generation(&self) -> bool {
         matches!(self, Self::HashGeneration(_))
     }
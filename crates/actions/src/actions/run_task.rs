@@ -23,17 +23,26 @@ pub async fn run_task(
 
     // Must be set before running the task in case it fails and
     // and error is bubbled up the stack
-    action.allow_failure = task.options.allow_failure;
+    action.quarantined = app_context
+        .workspace_config
+        .pipeline
+        .is_quarantined(&task.target);
+    action.allow_failure = task.options.allow_failure || action.quarantined;
 
     let result = TaskRunner::new(&app_context, &project, &task, daemon_client)?
         .run(&action_context, &action.node)
         .await?;
 
-    action.flaky = result.operations.is_flaky();
+    action.flaky = result.flaky || result.operations.is_flaky();
     action.status = result.operations.get_final_status();
     action.operations = result.operations;
 
-    if action.has_failed() && action.allow_failure {
+    if action.has_failed() && action.quarantined {
+        warn!(
+            "Task {} has failed, but is quarantined, continuing pipeline",
+            color::label(&task.target),
+        );
+    } else if action.has_failed() && action.allow_failure {
         warn!(
             "Task {} has failed, but is marked to allow failures, continuing pipeline",
             color::label(&task.target),
@@ -48,6 +48,15 @@ pub fn with_shared_exec_args(attr: TokenStream, item: TokenStream) -> TokenStrea
             )]
             pub plan: Option<std::path::PathBuf>
         },
+        quote! {
+            #[arg(
+                long,
+                env = "MOON_REPORT",
+                value_delimiter = ',',
+                help = "Additional report formats to write to the cache when the pipeline completes"
+            )]
+            pub report: Vec<moon_exec_plan::ReportFormat>
+        },
         quote! {
             #[arg(
                 long,
@@ -95,6 +104,24 @@ pub fn with_shared_exec_args(attr: TokenStream, item: TokenStream) -> TokenStrea
             )]
             pub job_total: Option<usize>
         },
+        quote! {
+            #[arg(
+                long,
+                env = "MOON_JOB_STRATEGY",
+                help = "Strategy to partition targets across jobs",
+                help_heading = super::HEADING_PARALLELISM,
+            )]
+            pub job_strategy: Option<moon_exec_plan::JobStrategy>
+        },
+        quote! {
+            #[arg(
+                long,
+                env = "MOON_JOB_TIMINGS",
+                help = "Relative path to a timings file (JSON) of target durations, used by the balanced strategy",
+                help_heading = super::HEADING_PARALLELISM,
+            )]
+            pub job_timings: Option<std::path::PathBuf>
+        },
     ];
 
     if params.passthrough {
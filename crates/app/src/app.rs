@@ -1,6 +1,7 @@
 use crate::app_options::*;
 use crate::commands::action_graph::ActionGraphArgs;
 use crate::commands::bin::BinArgs;
+use crate::commands::cache::CacheCommands;
 use crate::commands::check::CheckArgs;
 use crate::commands::ci::CiArgs;
 use crate::commands::clean::CleanArgs;
@@ -11,7 +12,9 @@ use crate::commands::docker::DockerCommands;
 use crate::commands::exec::ExecArgs;
 use crate::commands::ext::ExtArgs;
 use crate::commands::extension::ExtensionCommands;
+use crate::commands::flaky::FlakyArgs;
 use crate::commands::generate::GenerateArgs;
+use crate::commands::graph::GraphCommands;
 use crate::commands::hash::HashArgs;
 use crate::commands::init::InitArgs;
 use crate::commands::mcp::McpArgs;
@@ -29,6 +32,7 @@ use crate::commands::template::TemplateArgs;
 use crate::commands::templates::TemplatesArgs;
 use crate::commands::toolchain::ToolchainCommands;
 use crate::commands::upgrade::UpgradeArgs;
+use crate::commands::watch::WatchArgs;
 use crate::systems::bootstrap;
 use clap::builder::styling::{Color, Style, Styles};
 use clap::{Parser, Subcommand};
@@ -60,6 +64,12 @@ pub enum Commands {
     )]
     Bin(BinArgs),
 
+    #[command(name = "cache", about = "Manage and serve cached outputs.")]
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
     #[command(
         alias = "c",
         name = "check",
@@ -119,6 +129,12 @@ pub enum Commands {
         command: ExtensionCommands,
     },
 
+    #[command(
+        name = "flaky",
+        about = "Display a report of flaky tasks based on recent runs."
+    )]
+    Flaky(FlakyArgs),
+
     #[command(
         alias = "g",
         name = "generate",
@@ -126,6 +142,12 @@ pub enum Commands {
     )]
     Generate(GenerateArgs),
 
+    #[command(name = "graph", about = "Analyze the project and task graphs.")]
+    Graph {
+        #[command(subcommand)]
+        command: GraphCommands,
+    },
+
     #[command(
         alias = "h",
         name = "hash",
@@ -247,6 +269,13 @@ pub enum Commands {
         about = "Upgrade to the latest version of moon."
     )]
     Upgrade(UpgradeArgs),
+
+    #[command(
+        alias = "w",
+        name = "watch",
+        about = "Watch for file changes and re-run affected tasks."
+    )]
+    Watch(WatchArgs),
 }
 
 fn fg(ty: ColorType) -> Style {
@@ -39,6 +39,13 @@ pub enum AppError {
     )]
     MissingHashManifest(String),
 
+    #[diagnostic(code(app::missing_task_run))]
+    #[error(
+        "Task {} has not been run, so there are no cached outputs to export.",
+        .0.style(Style::Label),
+    )]
+    MissingTaskRun(String),
+
     #[diagnostic(code(app::missing_home_dir))]
     #[error("Unable to determine your home directory.")]
     MissingHomeDir,
@@ -74,6 +81,20 @@ pub enum AppError {
     )]
     InvalidMoonVersion { actual: String, expected: String },
 
+    #[diagnostic(code(app::invalid_lifetime))]
+    #[error(
+        "Invalid lifetime {}, expected a duration with a unit, like 12 hours or 7 days.",
+        .0.style(Style::Symbol),
+    )]
+    InvalidLifetime(String),
+
+    #[diagnostic(code(app::invalid_size))]
+    #[error(
+        "Invalid size {}, expected a number of bytes with an optional unit, like 512mb or 10gib.",
+        .0.style(Style::Symbol),
+    )]
+    InvalidSize(String),
+
     #[diagnostic(code(app::tty::required_id))]
     #[error(
         "An identifier is required and must be explicitly provided as a positional argument in non-TTY environments."
@@ -1,7 +1,7 @@
 use crate::commands::graph::run_server;
 use crate::session::{MoonSession, SessionResult};
 use clap::Args;
-use moon_action_graph::{GraphToDot, GraphToJson, RunRequirements};
+use moon_action_graph::{GraphToDot, GraphToGraphMl, GraphToJson, GraphToMermaid, RunRequirements};
 use moon_affected::DownstreamScope;
 use moon_task::Target;
 use tracing::instrument;
@@ -33,8 +33,14 @@ pub struct ActionGraphArgs {
     #[arg(long, help = "Print the graph in DOT format")]
     dot: bool,
 
+    #[arg(long, help = "Print the graph in GraphML format")]
+    graphml: bool,
+
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(long, help = "Print the graph in Mermaid format")]
+    mermaid: bool,
 }
 
 #[instrument(skip(session))]
@@ -74,6 +80,18 @@ pub async fn action_graph(session: MoonSession, args: ActionGraphArgs) -> Sessio
         return Ok(None);
     }
 
+    if args.graphml {
+        session.console.out.write_line(action_graph.to_graphml())?;
+
+        return Ok(None);
+    }
+
+    if args.mermaid {
+        session.console.out.write_line(action_graph.to_mermaid())?;
+
+        return Ok(None);
+    }
+
     if args.json {
         session
             .console
@@ -86,6 +104,7 @@ pub async fn action_graph(session: MoonSession, args: ActionGraphArgs) -> Sessio
     run_server(
         "Action graph",
         action_graph.to_json(false)?,
+        None,
         args.host,
         args.port,
     )
@@ -0,0 +1,104 @@
+use crate::app_error::AppError;
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::element;
+use moon_cache::{CacheBundle, ContentHash, Digest};
+use moon_console::ui::{Container, Notice, StyledText, Variant};
+use moon_task::Target;
+use moon_task_runner::TaskRunCacheState;
+use starbase_utils::fs;
+use std::path::PathBuf;
+use tracing::instrument;
+
+#[derive(Args, Clone, Debug)]
+pub struct CacheExportArgs {
+    #[arg(
+        required = true,
+        help = "Task targets (using their last run) or hashes to export"
+    )]
+    entries: Vec<String>,
+
+    #[arg(
+        long,
+        short = 'o',
+        default_value = "moon-cache.tar.gz",
+        help = "Archive file to write, with the format inferred from its extension"
+    )]
+    output: PathBuf,
+}
+
+#[instrument(skip(session))]
+pub async fn export(session: MoonSession, args: CacheExportArgs) -> SessionResult {
+    let cache_engine = session.get_cache_engine().await?;
+    let mut digests = vec![];
+
+    for entry in &args.entries {
+        let hash = if entry.contains(':') {
+            let target = Target::parse(entry)?;
+
+            if target.get_project_id().is_err() {
+                return Err(AppError::ProjectIdRequired.into());
+            }
+
+            let state = cache_engine
+                .state
+                .load_target_state::<TaskRunCacheState>(&target)?;
+
+            if state.data.hash.is_empty() {
+                return Err(AppError::MissingTaskRun(target.to_string()).into());
+            }
+
+            state.data.hash
+        } else {
+            match cache_engine.hash.find_manifest_path(entry)? {
+                Some(path) => fs::file_name(&path).replace(".json", ""),
+                None => return Err(AppError::MissingHashManifest(entry.to_owned()).into()),
+            }
+        };
+
+        // The manifest size is only relevant to remote backends,
+        // but keep it consistent with the digest the task runner creates
+        let size = fs::metadata(cache_engine.hash.get_manifest_path(&hash))
+            .map(|meta| meta.len() as i64)
+            .unwrap_or_default();
+
+        digests.push(Digest {
+            hash: ContentHash::from_hex(&hash)?,
+            size,
+        });
+    }
+
+    let output = session.working_dir.join(&args.output);
+    let stats = CacheBundle::new(&cache_engine.storage, &cache_engine.temp_dir)?
+        .export(&digests, &output)
+        .await?;
+
+    session.console.render(element! {
+        Container {
+            Notice(variant: if stats.skipped > 0 { Variant::Caution } else { Variant::Success }) {
+                StyledText(
+                    content: format!(
+                        "Exported {} manifests and {} blobs to <path>{}</path>",
+                        stats.manifests,
+                        stats.blobs,
+                        output.display(),
+                    )
+                )
+                #(if stats.skipped > 0 {
+                    Some(element! {
+                        StyledText(
+                            content: format!(
+                                "Skipped {} entries that are not in the local cache",
+                                stats.skipped
+                            )
+                        )
+                    })
+                } else {
+                    None
+                })
+            }
+        }
+    })?;
+
+    Ok(None)
+}
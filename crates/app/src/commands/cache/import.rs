@@ -0,0 +1,52 @@
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::element;
+use moon_cache::CacheBundle;
+use moon_console::ui::{Container, Notice, StyledText, Variant};
+use std::path::PathBuf;
+use tracing::instrument;
+
+#[derive(Args, Clone, Debug)]
+pub struct CacheImportArgs {
+    #[arg(required = true, help = "Archive file created by moon cache export")]
+    file: PathBuf,
+}
+
+#[instrument(skip(session))]
+pub async fn import(session: MoonSession, args: CacheImportArgs) -> SessionResult {
+    let cache_engine = session.get_cache_engine().await?;
+    let file = session.working_dir.join(&args.file);
+
+    let stats = CacheBundle::new(&cache_engine.storage, &cache_engine.temp_dir)?
+        .import(&file)
+        .await?;
+
+    session.console.render(element! {
+        Container {
+            Notice(variant: if stats.skipped > 0 { Variant::Caution } else { Variant::Success }) {
+                StyledText(
+                    content: format!(
+                        "Imported {} manifests and {} blobs from <path>{}</path>",
+                        stats.manifests,
+                        stats.blobs,
+                        file.display(),
+                    )
+                )
+                #(if stats.skipped > 0 {
+                    Some(element! {
+                        StyledText(
+                            content: format!(
+                                "Skipped {} manifests with missing or corrupted blobs",
+                                stats.skipped
+                            )
+                        )
+                    })
+                } else {
+                    None
+                })
+            }
+        }
+    })?;
+
+    Ok(None)
+}
@@ -0,0 +1,32 @@
+pub mod export;
+pub mod import;
+pub mod serve;
+
+use clap::Subcommand;
+use export::CacheExportArgs;
+use import::CacheImportArgs;
+use serve::CacheServeArgs;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum CacheCommands {
+    #[command(
+        name = "export",
+        about = "Export cached task outputs to an archive.",
+        long_about = "Export the cache manifests and blobs for the provided task targets or hashes from the local cache into a self-contained archive, which can be imported on another machine with `moon cache import`."
+    )]
+    Export(CacheExportArgs),
+
+    #[command(
+        name = "import",
+        about = "Import cached task outputs from an archive.",
+        long_about = "Import the cache manifests and blobs from an archive created by `moon cache export` into the local cache. Blobs are verified against their digests before they're stored."
+    )]
+    Import(CacheImportArgs),
+
+    #[command(
+        name = "serve",
+        about = "Start a remote cache server.",
+        long_about = "Start a remote cache server that implements the Bazel remote execution API over gRPC, and the bazel-remote API over HTTP, on the same address. Can be used as a self-hosted remote cache for moon."
+    )]
+    Serve(CacheServeArgs),
+}
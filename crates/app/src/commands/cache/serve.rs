@@ -0,0 +1,107 @@
+use crate::app_error::AppError;
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::element;
+use miette::IntoDiagnostic;
+use moon_cache_server::{CacheServer, CacheStore, CacheStoreOptions};
+use moon_cas::parse_byte_size;
+use moon_console::ui::{Container, Notice, StyledText, Variant};
+use moon_process::ProcessRegistry;
+use moon_time::parse_duration;
+use std::path::PathBuf;
+use tracing::instrument;
+
+#[derive(Args, Clone, Debug)]
+pub struct CacheServeArgs {
+    #[arg(long, default_value = "127.0.0.1", help = "Host to bind the server to")]
+    host: String,
+
+    #[arg(long, default_value_t = 9092, help = "Port to bind the server to")]
+    port: u16,
+
+    #[arg(
+        long,
+        help = "Directory to store cache entries in (defaults to .moon/cache/server)"
+    )]
+    dir: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Maximum total size of cached outputs, like 10gb or 512mib (unlimited when not set)"
+    )]
+    max_size: Option<String>,
+
+    #[arg(
+        long,
+        default_value = "1gib",
+        help = "Maximum size of a single uploaded blob, like 1gib or 512mb"
+    )]
+    max_upload_size: String,
+
+    #[arg(
+        long,
+        default_value = "7 days",
+        help = "Lifetime of cache entries since they were last used"
+    )]
+    lifetime: String,
+}
+
+#[instrument(skip(session))]
+pub async fn serve(session: MoonSession, args: CacheServeArgs) -> SessionResult {
+    let max_size = match &args.max_size {
+        Some(size) => {
+            Some(parse_byte_size(size).ok_or_else(|| AppError::InvalidSize(size.into()))?)
+        }
+        None => None,
+    };
+
+    let max_upload_size = parse_byte_size(&args.max_upload_size)
+        .ok_or_else(|| AppError::InvalidSize(args.max_upload_size.clone()))?;
+
+    let lifetime = parse_duration(&args.lifetime)
+        .map_err(|_| AppError::InvalidLifetime(args.lifetime.clone()))?;
+
+    let dir = match args.dir {
+        Some(dir) => session.working_dir.join(dir),
+        None => session.get_cache_engine().await?.cache_dir.join("server"),
+    };
+
+    let mut server = CacheServer::new(CacheStore::new(
+        &dir,
+        CacheStoreOptions {
+            max_size,
+            lifetime,
+            verify_integrity: session.workspace_config.cache.cas.verify_integrity,
+        },
+    )?);
+    server.max_upload_size = max_upload_size;
+
+    let listener = CacheServer::bind(&format!("{}:{}", args.host, args.port)).await?;
+    let address = listener.local_addr().into_diagnostic()?;
+
+    session.console.render(element! {
+        Container {
+            Notice(variant: Variant::Info) {
+                StyledText(
+                    content: format!(
+                        "Cache server listening on <url>grpc://{address}</url> and <url>http://{address}</url>, storing entries in <path>{}</path>",
+                        dir.display(),
+                    )
+                )
+                StyledText(
+                    content: "Press <shell>Ctrl+C</shell> to stop the server"
+                )
+            }
+        }
+    })?;
+
+    let mut signal_rx = ProcessRegistry::instance().receive_signal();
+
+    server
+        .serve(listener, async move {
+            let _ = signal_rx.recv().await;
+        })
+        .await?;
+
+    Ok(None)
+}
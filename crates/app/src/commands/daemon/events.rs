@@ -0,0 +1,69 @@
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::element;
+use moon_console::ui::{Container, Notice, StyledText, Variant};
+use moon_process::ProcessRegistry;
+use serde::Serialize;
+use starbase_utils::json::{self, JsonValue};
+
+#[derive(Args, Clone, Debug)]
+pub struct DaemonEventsArgs {
+    #[arg(
+        long = "type",
+        help = "Only stream events of this type or category (e.g. task.ran or task)"
+    )]
+    types: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamedEvent<'data> {
+    event: JsonValue,
+    pipeline_id: &'data str,
+    timestamp: u64,
+    #[serde(rename = "type")]
+    type_of: &'data str,
+}
+
+pub async fn events(session: MoonSession, args: DaemonEventsArgs) -> SessionResult {
+    let Some(mut client) = session.get_daemon_connector()?.acquire().await? else {
+        session.console.render_err(element! {
+            Container {
+                Notice(variant: Variant::Failure) {
+                    StyledText(content: "Unable to connect to the daemon")
+                }
+            }
+        })?;
+
+        return Ok(Some(1));
+    };
+
+    let mut stream = client.subscribe_events(args.types).await?;
+    let mut signal_rx = ProcessRegistry::instance().receive_signal();
+
+    loop {
+        let message = tokio::select! {
+            message = stream.message() => message,
+            _ = signal_rx.recv() => break,
+        };
+
+        // The stream ends when the daemon shuts down
+        let Ok(Some(event)) = message else {
+            break;
+        };
+
+        let line = json::format(
+            &StreamedEvent {
+                event: json::parse(&event.payload).unwrap_or_default(),
+                pipeline_id: &event.pipeline_id,
+                timestamp: event.timestamp,
+                type_of: &event.r#type,
+            },
+            false,
+        )?;
+
+        session.console.out.write_line(line)?;
+    }
+
+    Ok(None)
+}
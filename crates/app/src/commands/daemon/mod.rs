@@ -1,3 +1,4 @@
+pub mod events;
 pub mod logs;
 pub mod restart;
 pub mod server;
@@ -6,9 +7,17 @@ pub mod status;
 pub mod stop;
 
 use clap::Subcommand;
+use events::DaemonEventsArgs;
 
 #[derive(Clone, Debug, Subcommand)]
 pub enum DaemonCommands {
+    #[command(
+        name = "events",
+        about = "Stream pipeline events from the daemon.",
+        long_about = "Stream pipeline events published by moon processes connected to the daemon, as JSON lines. Will start the daemon if it's not running."
+    )]
+    Events(DaemonEventsArgs),
+
     #[command(
         name = "logs",
         about = "Tail the daemon's logs.",
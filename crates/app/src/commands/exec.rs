@@ -16,7 +16,7 @@ use moon_cache::CacheMode;
 use moon_common::{apply_style_tags, is_ci, is_test_env, path::WorkspaceRelativePathBuf};
 use moon_console::ui::{Container, Notice, SelectOption, SelectProps, StyledText, Variant};
 use moon_console::{Console, Level};
-use moon_exec_plan::{ExecutionPlan, TargetsBlock};
+use moon_exec_plan::{ExecutionPlan, JobStrategy, ReportFormat, TargetsBlock};
 use moon_task::{Target, TargetLocator};
 use moon_vcs::ChangedStatus;
 use petgraph::graph::NodeIndex;
@@ -215,7 +215,8 @@ impl ExecWorkflow {
         // so unaffected tasks still run while affected files are still passed.
         if self.args.force {
             self.session
-                .get_cache_engine()?
+                .get_cache_engine()
+                .await?
                 .force_mode(CacheMode::Write);
         }
 
@@ -328,7 +329,7 @@ impl ExecWorkflow {
     async fn load_changed_files(&mut self) -> miette::Result<FxHashSet<WorkspaceRelativePathBuf>> {
         self.print_step("Loading changed files")?;
 
-        let vcs = self.session.get_vcs_adapter()?;
+        let vcs = self.session.get_vcs_adapter().await?;
 
         if !vcs.is_enabled() {
             self.affected = false;
@@ -478,6 +479,17 @@ impl ExecWorkflow {
         // Insert targets into the graph
         let job = self.get_job();
         let job_total = self.get_job_total();
+        let job_strategy = self.get_job_strategy();
+
+        if job_strategy == JobStrategy::Balanced
+            && let Some(rel_timings_path) = self.get_job_timings()
+        {
+            let timings_path = self.session.working_dir.join(rel_timings_path);
+
+            debug!(timings = ?timings_path, "Loading job timings for balanced partitioning");
+
+            action_graph_builder.set_job_timings(json::read_file(timings_path)?);
+        }
 
         let partition = action_graph_builder
             .run_tasks_with_plan(
@@ -491,6 +503,7 @@ impl ExecWorkflow {
                     interactive: self.args.interactive,
                     job,
                     job_total,
+                    job_strategy,
                     // Forcing runs tasks even when not affected, but still
                     // tracks affected files for the `affectedFiles` option.
                     skip_affected: !self.affected || self.args.force,
@@ -604,8 +617,14 @@ impl ExecWorkflow {
 
         action_context.passthrough_args = self.args.passthrough.clone();
 
-        let results =
-            run_action_pipeline(&self.session, action_context, action_graph, self.summary).await?;
+        let results = run_action_pipeline(
+            &self.session,
+            action_context,
+            action_graph,
+            self.summary,
+            self.get_reports(),
+        )
+        .await?;
 
         Ok(results)
     }
@@ -705,6 +724,30 @@ impl ExecWorkflow {
         self.plan.pipeline.job_total.or(self.args.job_total)
     }
 
+    fn get_job_strategy(&self) -> JobStrategy {
+        self.plan
+            .pipeline
+            .job_strategy
+            .or(self.args.job_strategy)
+            .unwrap_or_default()
+    }
+
+    fn get_job_timings(&self) -> Option<&std::path::PathBuf> {
+        self.plan
+            .pipeline
+            .job_timings
+            .as_ref()
+            .or(self.args.job_timings.as_ref())
+    }
+
+    fn get_reports(&self) -> Vec<ReportFormat> {
+        self.plan
+            .pipeline
+            .reports
+            .clone()
+            .unwrap_or_else(|| self.args.report.clone())
+    }
+
     fn get_no_actions(&self) -> bool {
         self.plan
             .pipeline
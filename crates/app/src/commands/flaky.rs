@@ -0,0 +1,166 @@
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::{Size, element};
+use moon_common::Id;
+use moon_console::ui::*;
+use moon_task::Target;
+use moon_task_runner::{RUN_HISTORY_LIMIT, TaskRunCacheState, TaskRunStats};
+use serde::Serialize;
+use starbase_utils::json;
+use tracing::instrument;
+
+#[derive(Args, Clone, Debug)]
+pub struct FlakyArgs {
+    #[arg(help = "Filter tasks to a specific project")]
+    project: Option<Id>,
+
+    #[arg(long, help = "Include tasks that have not been flaky")]
+    all: bool,
+
+    #[arg(long, help = "Print in JSON format")]
+    json: bool,
+
+    #[arg(
+        long,
+        help = "Number of recent runs to calculate flake rates from",
+        default_value_t = RUN_HISTORY_LIMIT
+    )]
+    limit: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FlakyTask {
+    target: Target,
+    quarantined: bool,
+    #[serde(flatten)]
+    stats: TaskRunStats,
+}
+
+#[instrument(skip(session))]
+pub async fn flaky(session: MoonSession, args: FlakyArgs) -> SessionResult {
+    let cache_engine = session.get_cache_engine().await?;
+    let workspace_graph = session.get_workspace_graph().await?;
+
+    let tasks = if let Some(project_id) = &args.project {
+        workspace_graph.get_tasks_from_project(project_id)?
+    } else {
+        workspace_graph.get_tasks()?
+    };
+
+    let mut report = vec![];
+
+    for task in tasks {
+        let state = cache_engine
+            .state
+            .load_target_state::<TaskRunCacheState>(&task.target)?;
+        let stats = state.data.get_run_stats(args.limit);
+        let quarantined = session
+            .workspace_config
+            .pipeline
+            .is_quarantined(&task.target);
+
+        if stats.runs == 0 || (!args.all && stats.flaky_runs == 0 && !quarantined) {
+            continue;
+        }
+
+        report.push(FlakyTask {
+            target: task.target.clone(),
+            quarantined,
+            stats,
+        });
+    }
+
+    report.sort_by(|a, d| {
+        d.stats
+            .flake_rate
+            .total_cmp(&a.stats.flake_rate)
+            .then_with(|| a.target.cmp(&d.target))
+    });
+
+    if args.json {
+        session
+            .console
+            .out
+            .write_line(json::format(&report, true)?)?;
+
+        return Ok(None);
+    }
+
+    if report.is_empty() {
+        session.console.render(element! {
+            Container {
+                Notice(variant: Variant::Success) {
+                    StyledText(content: "No flaky tasks have been detected in recent runs.")
+                }
+            }
+        })?;
+
+        return Ok(None);
+    }
+
+    let id_width = report
+        .iter()
+        .fold(0, |acc, item| acc.max(item.target.as_str().len()))
+        .max(4);
+
+    session.console.render(element! {
+        Container {
+            Table(
+                headers: vec![
+                    TableHeader::new("Task", Size::Length((id_width + 5).max(10) as u32)),
+                    TableHeader::new("Runs", Size::Length(8)),
+                    TableHeader::new("Failures", Size::Length(12)),
+                    TableHeader::new("Flaky", Size::Length(8)),
+                    TableHeader::new("Flake rate", Size::Length(14)),
+                    TableHeader::new("Quarantined", Size::Auto),
+                ]
+            ) {
+                #(report.into_iter().enumerate().map(|(i, item)| {
+                    element! {
+                        TableRow(row: i as i32) {
+                            TableCol(col: 0) {
+                                StyledText(
+                                    content: item.target.to_string(),
+                                    style: Style::Id
+                                )
+                            }
+                            TableCol(col: 1) {
+                                StyledText(
+                                    content: item.stats.runs.to_string(),
+                                )
+                            }
+                            TableCol(col: 2) {
+                                StyledText(
+                                    content: item.stats.failures.to_string(),
+                                )
+                            }
+                            TableCol(col: 3) {
+                                StyledText(
+                                    content: item.stats.flaky_runs.to_string(),
+                                )
+                            }
+                            TableCol(col: 4) {
+                                StyledText(
+                                    content: format!("{:.1}%", item.stats.flake_rate * 100.0),
+                                    style: if item.stats.flake_rate > 0.0 {
+                                        Some(Style::Caution)
+                                    } else {
+                                        None
+                                    }
+                                )
+                            }
+                            TableCol(col: 5) {
+                                StyledText(
+                                    content: if item.quarantined { "Yes" } else { "No" },
+                                )
+                            }
+                        }
+                    }
+                }))
+            }
+        }
+    })?;
+
+    Ok(None)
+}
@@ -9,14 +9,19 @@ use clap::{
     parser::ValueSource,
 };
 use iocraft::prelude::{View, Weight, element};
-use moon_codegen::{CodegenError, FileState, Template, TemplateContext};
+use moon_codegen::{
+    CodegenError, FileState, PostGenerateState, Template, TemplateBaseline, TemplateBaselines,
+    TemplateContext,
+};
 use moon_common::*;
 use moon_config::{TemplateVariable, TemplateVariableEnumDefault};
 use moon_console::{Console, ui::*};
 use rustc_hash::FxHashMap;
+use starbase_utils::fs;
 use starbase_utils::json::{self, JsonValue, serde_json};
 use std::path::PathBuf;
-use tracing::{debug, info, instrument};
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
 
 #[derive(Args, Clone, Debug)]
 pub struct GenerateArgs {
@@ -41,9 +46,19 @@ pub struct GenerateArgs {
     #[arg(long, help = "Force overwrite any existing files at the destination")]
     force: bool,
 
+    #[arg(
+        long,
+        help = "Update previously generated files by merging template changes with local changes",
+        conflicts_with = "force"
+    )]
+    update: bool,
+
     #[arg(long, help = "Create a new template")]
     template: bool,
 
+    #[arg(long, help = "Skip running the template's post-generate steps")]
+    no_post_generate: bool,
+
     // Variable args (after --)
     #[arg(last = true, help = "Arguments to define as variable values")]
     vars: Vec<String>,
@@ -174,6 +189,15 @@ pub async fn generate(session: MoonSession, args: GenerateArgs) -> SessionResult
     // Load template files and determine when to overwrite
     template.load_files(&dest, &context)?;
 
+    let post_generate_steps = template.load_post_generate_steps(&context)?;
+
+    let mut baselines = TemplateBaselines::load(&dest)?;
+    let prev_baseline = if args.update {
+        load_baseline(&baselines, &template.id)
+    } else {
+        None
+    };
+
     for file in template.files.values_mut() {
         if file.is_skipped() {
             file.state = FileState::Skip;
@@ -181,6 +205,15 @@ pub async fn generate(session: MoonSession, args: GenerateArgs) -> SessionResult
         }
 
         if file.dest_path.exists() {
+            // Merge template changes with local changes when updating
+            if let Some(baseline) = prev_baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get_content(&dest, &file.dest_path))
+            {
+                file.apply_update(baseline, &fs::read_file(&file.dest_path)?);
+                continue;
+            }
+
             if args.force || file.is_forced() {
                 file.state = FileState::Replace;
                 continue;
@@ -243,6 +276,14 @@ pub async fn generate(session: MoonSession, args: GenerateArgs) -> SessionResult
     // Generate the files in the destination and print the results
     if !args.dry_run {
         generator.generate(&template)?;
+
+        // Only files that were written are recorded, as skipped files
+        // still reflect the previous generation
+        let next_baseline =
+            TemplateBaseline::from_template(&template, &dest, baselines.get(&template.id))?;
+
+        baselines.insert(template.id.clone(), next_baseline);
+        baselines.save(&dest)?;
     }
 
     console.render(element! {
@@ -257,10 +298,12 @@ pub async fn generate(session: MoonSession, args: GenerateArgs) -> SessionResult
             List {
                 #(template.files.values().map(|file| {
                     let (label, arrow, style) = match &file.state {
+                        FileState::Conflict => ("conflict", "-➤", "failure"),
                         FileState::Create => ("created", "--➤", "success"),
                         FileState::Merge => ("merged", "---➤", "success"),
                         FileState::Replace => ("replaced", "-➤", "failure"),
                         FileState::Skip => ("skipped", "--➤", "invalid"),
+                        FileState::Update => ("updated", "--➤", "success"),
                     };
 
                     element! {
@@ -278,6 +321,18 @@ pub async fn generate(session: MoonSession, args: GenerateArgs) -> SessionResult
                     }
                 }))
 
+                #(template.excluded.iter().map(|name| {
+                    element! {
+                        ListItem {
+                            StyledText(
+                                content: format!(
+                                    "<invalid>excluded</invalid> <muted>-➤</muted> <mutedlight>{name}</mutedlight>"
+                                ),
+                            )
+                        }
+                    }
+                }))
+
                 #(template.assets.values().map(|asset| {
                     element! {
                         ListItem {
@@ -297,9 +352,84 @@ pub async fn generate(session: MoonSession, args: GenerateArgs) -> SessionResult
         }
     })?;
 
+    // Run post-generate steps now that the files exist
+    if !post_generate_steps.is_empty() {
+        let mut skip = args.dry_run || args.no_post_generate;
+
+        // Steps from remote templates run arbitrary commands that
+        // the workspace has not vetted, so confirm them first
+        if !skip && generator.is_remote_template(&template) {
+            let mut confirmed = false;
+
+            console
+                .render_prompt(element! {
+                    Confirm(
+                        label: format!(
+                            "Template <id>{}</id> was downloaded from a remote location, run its post-generate steps?",
+                            template.id
+                        ),
+                        on_confirm: &mut confirmed
+                    )
+                })
+                .await?;
+
+            skip = !confirmed;
+        }
+
+        let results = generator
+            .run_post_generate(post_generate_steps, &dest, skip, Arc::new(console.clone()))
+            .await?;
+
+        console.render(element! {
+            View(margin_bottom: 1) {
+                List {
+                    #(results.iter().map(|result| {
+                        let (label, arrow, style) = match &result.state {
+                            PostGenerateState::Failed => ("failed", "---➤", "failure"),
+                            PostGenerateState::Passed => ("passed", "---➤", "success"),
+                            PostGenerateState::Skipped => ("skipped", "--➤", "invalid"),
+                        };
+
+                        element! {
+                            ListItem {
+                                StyledText(
+                                    content: format!(
+                                        "<{style}>{label}</{style}> <muted>{arrow}</muted> <shell>{}</shell>",
+                                        result.step.get_label()
+                                    ),
+                                )
+                            }
+                        }
+                    }))
+                }
+            }
+        })?;
+
+        if let Some(failed) = results.iter().find(|result| result.is_fatal()) {
+            return Err(CodegenError::PostGenerateFailed {
+                command: failed.step.get_label(),
+                code: failed.exit_code.unwrap_or(-1),
+                stderr: failed.stderr.clone(),
+            }
+            .into());
+        }
+    }
+
     Ok(None)
 }
 
+fn load_baseline(baselines: &TemplateBaselines, template_id: &Id) -> Option<TemplateBaseline> {
+    let baseline = baselines.get(template_id).cloned();
+
+    if baseline.is_none() {
+        warn!(
+            "No baseline found for template {template_id} in the destination, unable to merge changes; has the template been generated there before?"
+        );
+    }
+
+    baseline
+}
+
 fn is_numeric(value: &str) -> bool {
     value
         .as_bytes()
@@ -0,0 +1,179 @@
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::{Size, element};
+use moon_console::ui::*;
+use moon_project_graph::{GraphAnalysis, GraphAnalyzer};
+use serde::Serialize;
+use starbase_utils::json;
+use std::fmt::Display;
+use tracing::instrument;
+
+#[derive(Args, Clone, Debug)]
+pub struct GraphAnalyzeArgs {
+    #[arg(long, help = "Print the analysis in JSON format")]
+    json: bool,
+
+    #[arg(
+        long,
+        help = "Maximum number of nodes and chains to display",
+        default_value_t = 10
+    )]
+    limit: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceGraphAnalysis<P, T> {
+    projects: GraphAnalysis<P>,
+    tasks: GraphAnalysis<T>,
+}
+
+fn join_keys<K: Display>(keys: &[K], separator: &str) -> String {
+    keys.iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn render_analysis<K: Display>(
+    session: &MoonSession,
+    title: &str,
+    analysis: &GraphAnalysis<K>,
+    limit: usize,
+) -> miette::Result<()> {
+    let node_width = analysis
+        .nodes
+        .iter()
+        .fold(0, |acc, metrics| acc.max(metrics.node.to_string().len()))
+        .max(4);
+
+    session.console.render(element! {
+        Container {
+            Section(title) {
+                Entry(
+                    name: "Nodes",
+                    content: analysis.nodes.len().to_string(),
+                )
+                Entry(
+                    name: "Without dependents",
+                    no_children: analysis.without_dependents.is_empty()
+                ) {
+                    StyledText(
+                        content: join_keys(&analysis.without_dependents, ", "),
+                        style: Style::Id
+                    )
+                }
+                Entry(
+                    name: "Articulation points",
+                    no_children: analysis.articulation_points.is_empty()
+                ) {
+                    StyledText(
+                        content: join_keys(&analysis.articulation_points, ", "),
+                        style: Style::Id
+                    )
+                }
+                Entry(
+                    name: "Longest chains",
+                    no_children: analysis.longest_chains.is_empty()
+                ) {
+                    List {
+                        #(analysis.longest_chains.iter().take(limit).map(|chain| {
+                            element! {
+                                ListItem {
+                                    StyledText(
+                                        content: format!(
+                                            "{} <mutedlight>({})</mutedlight>",
+                                            join_keys(chain, " → "),
+                                            chain.len()
+                                        ),
+                                    )
+                                }
+                            }
+                        }))
+                    }
+                }
+                Entry(
+                    name: "Cycles",
+                    no_children: analysis.cycles.is_empty()
+                ) {
+                    List {
+                        #(analysis.cycles.iter().map(|cycle| {
+                            element! {
+                                ListItem {
+                                    StyledText(
+                                        content: join_keys(cycle, " ↔ "),
+                                        style: Style::Failure
+                                    )
+                                }
+                            }
+                        }))
+                    }
+                }
+            }
+            #(if analysis.nodes.is_empty() {
+                None
+            } else {
+                Some(element! {
+                    Table(
+                        headers: vec![
+                            TableHeader::new("Node", Size::Length((node_width + 5) as u32)),
+                            TableHeader::new("Fan in", Size::Length(10)),
+                            TableHeader::new("Fan out", Size::Length(10)),
+                            TableHeader::new("Deep dependents", Size::Length(18)),
+                            TableHeader::new("Deep dependencies", Size::Length(20)),
+                        ]
+                    ) {
+                        #(analysis.nodes.iter().take(limit).enumerate().map(|(i, metrics)| {
+                            element! {
+                                TableRow(row: i as i32) {
+                                    TableCol(col: 0) {
+                                        StyledText(
+                                            content: metrics.node.to_string(),
+                                            style: Style::Id
+                                        )
+                                    }
+                                    TableCol(col: 1) {
+                                        StyledText(content: metrics.fan_in.to_string())
+                                    }
+                                    TableCol(col: 2) {
+                                        StyledText(content: metrics.fan_out.to_string())
+                                    }
+                                    TableCol(col: 3) {
+                                        StyledText(content: metrics.deep_dependents.to_string())
+                                    }
+                                    TableCol(col: 4) {
+                                        StyledText(content: metrics.deep_dependencies.to_string())
+                                    }
+                                }
+                            }
+                        }))
+                    }
+                })
+            })
+        }
+    })?;
+
+    Ok(())
+}
+
+#[instrument(skip(session))]
+pub async fn analyze(session: MoonSession, args: GraphAnalyzeArgs) -> SessionResult {
+    let analysis = WorkspaceGraphAnalysis {
+        projects: session.get_project_graph().await?.analyze(),
+        tasks: session.get_task_graph().await?.analyze(),
+    };
+
+    if args.json {
+        session
+            .console
+            .out
+            .write_line(json::format(&analysis, true)?)?;
+
+        return Ok(None);
+    }
+
+    render_analysis(&session, "Project graph", &analysis.projects, args.limit)?;
+    render_analysis(&session, "Task graph", &analysis.tasks, args.limit)?;
+
+    Ok(None)
+}
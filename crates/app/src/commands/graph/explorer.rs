@@ -0,0 +1,432 @@
+use crate::queries::changed_files::*;
+use moon_affected::AffectedTracker;
+use moon_common::Id;
+use moon_project_graph::Project;
+use moon_task::{Target, Task};
+use moon_vcs::BoxedVcs;
+use moon_workspace_graph::WorkspaceGraph;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use starbase_utils::json;
+use std::sync::Arc;
+use url::Url;
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplorerNode {
+    pub id: String,
+    pub kind: &'static str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<String>,
+
+    pub tags: Vec<String>,
+    pub toolchains: Vec<String>,
+
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_of: Option<String>,
+}
+
+/// A lightweight representation of a graph, with only the fields required
+/// to render, search, and filter nodes. Everything else is fetched lazily.
+#[derive(Debug, Default, Serialize)]
+pub struct ExplorerGraph {
+    pub nodes: Vec<ExplorerNode>,
+    pub edges: Vec<(String, String, String)>,
+}
+
+impl ExplorerNode {
+    pub fn from_task(task: &Task) -> Self {
+        Self {
+            id: task.target.to_string(),
+            kind: "task",
+            project: task.target.get_project_id().ok().map(|id| id.to_owned()),
+            tags: to_strings(&task.tags),
+            toolchains: to_strings(&task.toolchains),
+            type_of: Some(task.type_of.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+fn to_strings(ids: &[Id]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+impl ExplorerGraph {
+    pub fn from_projects(projects: &[Arc<Project>]) -> Self {
+        let ids = projects
+            .iter()
+            .map(|project| &project.id)
+            .collect::<FxHashSet<_>>();
+        let mut graph = Self::default();
+
+        for project in projects {
+            graph.nodes.push(ExplorerNode {
+                id: project.id.to_string(),
+                kind: "project",
+                language: Some(project.language.to_string()),
+                layer: Some(project.layer.to_string()),
+                stack: Some(project.stack.to_string()),
+                tags: to_strings(&project.config.tags),
+                toolchains: to_strings(&project.toolchains),
+                ..Default::default()
+            });
+
+            // Focused graphs may not contain every dependency
+            for dep in &project.dependencies {
+                if ids.contains(&dep.id) {
+                    graph.edges.push((
+                        project.id.to_string(),
+                        dep.id.to_string(),
+                        dep.scope.to_string(),
+                    ));
+                }
+            }
+        }
+
+        graph
+    }
+
+    pub fn from_tasks(tasks: &[Arc<Task>]) -> Self {
+        let targets = tasks
+            .iter()
+            .map(|task| &task.target)
+            .collect::<FxHashSet<_>>();
+        let mut graph = Self::default();
+
+        for task in tasks {
+            graph.nodes.push(ExplorerNode::from_task(task));
+
+            for dep in &task.deps {
+                if targets.contains(&dep.target) {
+                    graph.edges.push((
+                        task.target.to_string(),
+                        dep.target.to_string(),
+                        if dep.optional.unwrap_or_default() {
+                            "optional".into()
+                        } else {
+                            "required".into()
+                        },
+                    ));
+                }
+            }
+        }
+
+        graph
+    }
+}
+
+#[derive(Serialize)]
+struct ExplorerProject {
+    project: Arc<Project>,
+    tasks: Vec<ExplorerNode>,
+}
+
+#[derive(Serialize)]
+struct ExplorerAffected {
+    files: usize,
+    projects: Vec<Id>,
+    tasks: Vec<Target>,
+}
+
+/// Serves JSON endpoints for the graph explorer, so that the page
+/// can load details of a project or task only when it's selected.
+pub struct GraphExplorer {
+    pub graph: ExplorerGraph,
+    pub vcs: Arc<BoxedVcs>,
+    pub workspace_graph: Arc<WorkspaceGraph>,
+}
+
+impl GraphExplorer {
+    /// Handle a request to an `/api/*` endpoint, and return the JSON
+    /// response, or `None` if the endpoint or node does not exist.
+    pub async fn handle_request(&self, url: &Url) -> miette::Result<Option<String>> {
+        let get_param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+                .filter(|value| !value.is_empty())
+        };
+
+        match url.path() {
+            "/api/graph" => Ok(Some(json::format(&self.graph, false)?)),
+            "/api/project" => {
+                let Some(id) = get_param("id") else {
+                    return Ok(None);
+                };
+
+                let Ok(project) = self.workspace_graph.get_project(&id) else {
+                    return Ok(None);
+                };
+
+                let mut tasks = self
+                    .workspace_graph
+                    .get_tasks_from_project(&id)?
+                    .into_iter()
+                    .map(|task| ExplorerNode::from_task(&task))
+                    .collect::<Vec<_>>();
+
+                tasks.sort_by(|a, d| a.id.cmp(&d.id));
+
+                Ok(Some(json::format(
+                    &ExplorerProject { project, tasks },
+                    false,
+                )?))
+            }
+            "/api/task" => {
+                let Some(target) = get_param("target") else {
+                    return Ok(None);
+                };
+
+                let Ok(task) = Target::parse(&target)
+                    .and_then(|target| self.workspace_graph.get_task(&target))
+                else {
+                    return Ok(None);
+                };
+
+                Ok(Some(json::format(&task, false)?))
+            }
+            "/api/affected" => {
+                let base = get_param("base");
+                let head = get_param("head");
+                let local = base.is_none() && head.is_none();
+
+                let changed_files = query_changed_files(
+                    &self.vcs,
+                    QueryChangedFilesOptions {
+                        base,
+                        default_branch: !local,
+                        head,
+                        local,
+                        ..Default::default()
+                    },
+                )
+                .await?
+                .files;
+
+                let file_count = changed_files.len();
+                let mut tracker =
+                    AffectedTracker::new(Arc::clone(&self.workspace_graph), changed_files);
+                tracker.track_projects()?;
+                tracker.track_tasks()?;
+
+                let affected = tracker.build();
+                let mut projects = affected.projects.into_keys().collect::<Vec<_>>();
+                let mut tasks = affected.tasks.into_keys().collect::<Vec<_>>();
+
+                projects.sort();
+                tasks.sort();
+
+                Ok(Some(json::format(
+                    &ExplorerAffected {
+                        files: file_count,
+                        projects,
+                        tasks,
+                    },
+                    false,
+                )?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moon_config::{DependencyScope, ProjectDependencyConfig, TaskDependencyConfig};
+    use moon_test_utils::WorkspaceMocker;
+    use starbase_sandbox::create_empty_sandbox;
+
+    fn create_project(id: &str, deps: &[(&str, DependencyScope)]) -> Arc<Project> {
+        Arc::new(Project {
+            id: Id::raw(id),
+            dependencies: deps
+                .iter()
+                .map(|(dep_id, scope)| ProjectDependencyConfig {
+                    scope: *scope,
+                    ..ProjectDependencyConfig::new(Id::raw(dep_id))
+                })
+                .collect(),
+            ..Project::default()
+        })
+    }
+
+    fn create_task(target: &str, deps: Vec<TaskDependencyConfig>) -> Arc<Task> {
+        let (project_id, task_id) = target.split_once(':').unwrap();
+
+        Arc::new(Task {
+            id: Id::raw(task_id),
+            target: Target::new(project_id, task_id).unwrap(),
+            deps,
+            ..Task::default()
+        })
+    }
+
+    fn create_edge(from: &str, to: &str, label: &str) -> (String, String, String) {
+        (from.into(), to.into(), label.into())
+    }
+
+    fn create_dep(target: &str) -> TaskDependencyConfig {
+        TaskDependencyConfig::new(Target::parse(target).unwrap())
+    }
+
+    mod from_projects {
+        use super::*;
+
+        #[test]
+        fn creates_nodes_and_edges() {
+            let graph = ExplorerGraph::from_projects(&[
+                create_project("app", &[("lib", DependencyScope::Production)]),
+                create_project("lib", &[("types", DependencyScope::Development)]),
+                create_project("types", &[]),
+            ]);
+
+            assert_eq!(
+                graph
+                    .nodes
+                    .iter()
+                    .map(|node| (node.id.as_str(), node.kind))
+                    .collect::<Vec<_>>(),
+                [("app", "project"), ("lib", "project"), ("types", "project")]
+            );
+            assert_eq!(
+                graph.edges,
+                [
+                    create_edge("app", "lib", "production"),
+                    create_edge("lib", "types", "development"),
+                ]
+            );
+        }
+
+        #[test]
+        fn filters_edges_to_missing_projects() {
+            let graph = ExplorerGraph::from_projects(&[
+                create_project(
+                    "app",
+                    &[
+                        ("lib", DependencyScope::Production),
+                        ("unknown", DependencyScope::Production),
+                    ],
+                ),
+                create_project("lib", &[]),
+            ]);
+
+            assert_eq!(graph.edges, [create_edge("app", "lib", "production")]);
+        }
+    }
+
+    mod from_tasks {
+        use super::*;
+
+        #[test]
+        fn creates_nodes_and_edges() {
+            let graph = ExplorerGraph::from_tasks(&[
+                create_task(
+                    "app:build",
+                    vec![
+                        create_dep("lib:build"),
+                        create_dep("lib:codegen").optional(),
+                    ],
+                ),
+                create_task("lib:build", vec![]),
+                create_task("lib:codegen", vec![]),
+            ]);
+
+            assert_eq!(graph.nodes[0].id, "app:build");
+            assert_eq!(graph.nodes[0].kind, "task");
+            assert_eq!(graph.nodes[0].project.as_deref(), Some("app"));
+            assert_eq!(
+                graph.edges,
+                [
+                    create_edge("app:build", "lib:build", "required"),
+                    create_edge("app:build", "lib:codegen", "optional"),
+                ]
+            );
+        }
+
+        #[test]
+        fn filters_edges_to_missing_tasks() {
+            let graph = ExplorerGraph::from_tasks(&[
+                create_task(
+                    "app:build",
+                    vec![create_dep("lib:build"), create_dep("other:build")],
+                ),
+                create_task("lib:build", vec![]),
+            ]);
+
+            assert_eq!(
+                graph.edges,
+                [create_edge("app:build", "lib:build", "required")]
+            );
+        }
+    }
+
+    mod handle_request {
+        use super::*;
+
+        async fn request(path: &str) -> Option<String> {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(
+                "app/moon.yml",
+                r#"
+tasks:
+  build:
+    command: noop
+"#,
+            );
+
+            let mocker = WorkspaceMocker::new(sandbox.path())
+                .load_default_configs()
+                .with_default_projects();
+
+            let explorer = GraphExplorer {
+                graph: ExplorerGraph::default(),
+                vcs: Arc::new(mocker.mock_vcs_adapter()),
+                workspace_graph: Arc::new(mocker.mock_workspace_graph().await),
+            };
+
+            explorer
+                .handle_request(&Url::parse(&format!("http://localhost{path}")).unwrap())
+                .await
+                .unwrap()
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn returns_known_nodes() {
+            assert!(request("/api/graph").await.is_some());
+            assert!(request("/api/project?id=app").await.is_some());
+            assert!(request("/api/task?target=app:build").await.is_some());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn returns_none_for_unknown_projects() {
+            assert!(request("/api/project").await.is_none());
+            assert!(request("/api/project?id=").await.is_none());
+            assert!(request("/api/project?id=unknown").await.is_none());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn returns_none_for_unknown_tasks() {
+            assert!(request("/api/task").await.is_none());
+            assert!(request("/api/task?target=app:unknown").await.is_none());
+            assert!(request("/api/task?target=unknown:build").await.is_none());
+            assert!(request("/api/task?target=invalid").await.is_none());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn returns_none_for_unknown_endpoints() {
+            assert!(request("/api/unknown").await.is_none());
+        }
+    }
+}
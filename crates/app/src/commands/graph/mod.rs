@@ -1,3 +1,19 @@
+pub mod analyze;
+mod explorer;
 mod utils;
 
+pub use explorer::*;
 pub use utils::*;
+
+use analyze::GraphAnalyzeArgs;
+use clap::Subcommand;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum GraphCommands {
+    #[command(
+        name = "analyze",
+        about = "Analyze the health of the project and task graphs.",
+        long_about = "Analyze the project and task graphs, and report metrics like fan-in and fan-out per node, the longest dependency chains, articulation points that split the graph, nodes without dependents, and cycles."
+    )]
+    Analyze(GraphAnalyzeArgs),
+}
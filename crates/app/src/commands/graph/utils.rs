@@ -1,12 +1,17 @@
+use super::explorer::GraphExplorer;
 use miette::IntoDiagnostic;
 use moon_common::color;
 use moon_env_var::GlobalEnvBag;
 use moon_process::ProcessRegistry;
 use serde::Serialize;
+use starbase_utils::json;
+use std::io::Cursor;
 use std::sync::Arc;
 use tera::{Context, Tera};
 use tiny_http::{Header, Request, Response, Server};
-use tokio::task::{JoinHandle, spawn};
+use tokio::task::{JoinHandle, spawn, spawn_blocking};
+use tracing::warn;
+use url::Url;
 
 const INDEX_HTML: &str = include_str!("html.tera");
 
@@ -14,9 +19,16 @@ const INDEX_HTML: &str = include_str!("html.tera");
 pub struct RenderContext<'a> {
     pub page_title: &'a str,
     pub graph_data: &'a str, // JSON
+    pub graph_url: &'a str,
+    pub explorer: bool,
     pub js_url: String,
 }
 
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
 pub async fn setup_server(host: String, port: u16) -> miette::Result<(Arc<Server>, Tera)> {
     let address = format!("{host}:{port}");
     let server = Server::http(address).unwrap();
@@ -25,24 +37,56 @@ pub async fn setup_server(host: String, port: u16) -> miette::Result<(Arc<Server
     Ok((Arc::new(server), tera))
 }
 
-pub fn respond_to_request(
+fn create_json_response(data: impl Into<Vec<u8>>) -> Response<Cursor<Vec<u8>>> {
+    let mut response = Response::from_data(data);
+    response
+        .add_header(Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap());
+    response
+}
+
+pub async fn respond_to_request(
     req: Request,
     tera: &mut Tera,
     graph_data: &str,
+    explorer: Option<&GraphExplorer>,
     page_title: &str,
 ) -> miette::Result<()> {
-    let response = match req.url() {
-        "/graph-data" => {
-            let mut response = Response::from_data(graph_data);
-            response.add_header(
-                Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap(),
-            );
-            response
+    let url = Url::parse(&format!("http://localhost{}", req.url())).into_diagnostic()?;
+
+    let response = match url.path() {
+        "/graph-data" => create_json_response(graph_data),
+        path if path.starts_with("/api/") => {
+            let result = match explorer {
+                Some(explorer) => explorer.handle_request(&url).await,
+                None => Ok(None),
+            };
+
+            match result {
+                Ok(Some(data)) => create_json_response(data),
+                Ok(None) => create_json_response("null").with_status_code(404),
+                Err(error) => {
+                    warn!("Failed to respond to {}: {error}", url.path());
+
+                    create_json_response(json::format(
+                        &ErrorResponse {
+                            error: error.to_string(),
+                        },
+                        false,
+                    )?)
+                    .with_status_code(500)
+                }
+            }
         }
         _ => {
             let context = RenderContext {
                 page_title,
                 graph_data,
+                graph_url: if explorer.is_some() {
+                    "/api/graph"
+                } else {
+                    "/graph-data"
+                },
+                explorer: explorer.is_some(),
                 js_url: get_js_url(),
             };
 
@@ -79,6 +123,7 @@ pub fn get_js_url() -> String {
 pub async fn run_server(
     title: &str,
     graph_data: String,
+    explorer: Option<GraphExplorer>,
     host: String,
     port: u16,
 ) -> miette::Result<()> {
@@ -101,8 +146,19 @@ pub async fn run_server(
 
     let title = title.to_owned();
     let handle2: JoinHandle<miette::Result<()>> = spawn(async move {
-        for req in server.incoming_requests() {
-            respond_to_request(req, &mut tera, &graph_data, &title)?;
+        // Requests are received on a blocking thread, so that the
+        // explorer endpoints can await while responding
+        loop {
+            let server = Arc::clone(&server);
+
+            let Some(req) = spawn_blocking(move || server.recv().ok())
+                .await
+                .into_diagnostic()?
+            else {
+                break;
+            };
+
+            respond_to_request(req, &mut tera, &graph_data, explorer.as_ref(), &title).await?;
         }
 
         Ok(())
@@ -1,8 +1,12 @@
 use crate::session::{MoonSession, SessionResult};
+use crate::watchers::McpWatcher;
 use clap::Args;
 use miette::IntoDiagnostic;
+use moon_daemon::{start_file_listener, start_file_watcher};
 use moon_mcp::{SdkResult, run_mcp};
 use moon_process::ProcessRegistry;
+use std::sync::Arc;
+use tokio::sync::{RwLock, broadcast, mpsc};
 use tokio::task::JoinHandle;
 use tracing::instrument;
 
@@ -12,15 +16,42 @@ pub struct McpArgs {}
 #[instrument(skip(session))]
 pub async fn mcp(session: MoonSession, _args: McpArgs) -> SessionResult {
     let app_context = session.get_app_context().await?;
-    let workspace_graph = session.get_workspace_graph().await?;
+    let workspace_graph = Arc::new(RwLock::new(session.get_workspace_graph().await?));
 
-    let handle_server: JoinHandle<SdkResult<()>> =
-        tokio::spawn(run_mcp(app_context, workspace_graph));
+    // Watch for config changes, so that clients are notified of stale resources
+    let (change_tx, change_rx) = mpsc::channel(100);
+    let (event_tx, event_rx) = broadcast::channel(1000);
+    let (shutdown_tx, _) = broadcast::channel::<()>(1);
+
+    tokio::spawn(start_file_watcher(
+        session.workspace_root.clone(),
+        event_tx,
+        shutdown_tx.subscribe(),
+    ));
+    tokio::spawn(start_file_listener(
+        (),
+        vec![Box::new(McpWatcher::new(
+            session.clone(),
+            Arc::clone(&workspace_graph),
+            change_tx,
+        ))],
+        event_rx,
+        shutdown_tx.subscribe(),
+    ));
+
+    let handle_server: JoinHandle<SdkResult<()>> = tokio::spawn(run_mcp(
+        app_context,
+        workspace_graph,
+        session.config_loader.clone(),
+        Some(change_rx),
+    ));
 
     let handle: JoinHandle<miette::Result<()>> = tokio::spawn(async move {
         let mut listener = ProcessRegistry::instance().receive_signal();
 
         if listener.recv().await.is_ok() {
+            let _ = shutdown_tx.send(());
+
             handle_server.abort();
 
             // Need to await it so the abort registers!
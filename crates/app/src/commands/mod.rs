@@ -1,5 +1,6 @@
 pub mod action_graph;
 pub mod bin;
+pub mod cache;
 pub mod check;
 pub mod ci;
 pub mod clean;
@@ -10,6 +11,7 @@ pub mod docker;
 pub mod exec;
 pub mod ext;
 pub mod extension;
+pub mod flaky;
 pub mod generate;
 pub mod graph;
 pub mod hash;
@@ -32,6 +34,7 @@ pub mod template;
 pub mod templates;
 pub mod toolchain;
 pub mod upgrade;
+pub mod watch;
 
 pub const HEADING_AFFECTED: &str = "Affected options";
 pub const HEADING_GRAPH: &str = "Graph options";
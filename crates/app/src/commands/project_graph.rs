@@ -1,8 +1,8 @@
-use crate::commands::graph::run_server;
+use crate::commands::graph::{ExplorerGraph, GraphExplorer, run_server};
 use crate::session::{MoonSession, SessionResult};
 use clap::Args;
 use moon_common::Id;
-use moon_project_graph::{GraphToDot, GraphToJson};
+use moon_project_graph::{GraphToDot, GraphToGraphMl, GraphToJson, GraphToMermaid};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -33,8 +33,14 @@ pub struct ProjectGraphArgs {
     #[arg(long, help = "Print the graph in DOT format")]
     dot: bool,
 
+    #[arg(long, help = "Print the graph in GraphML format")]
+    graphml: bool,
+
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(long, help = "Print the graph in Mermaid format")]
+    mermaid: bool,
 }
 
 #[instrument(skip(session))]
@@ -46,7 +52,7 @@ pub async fn project_graph(session: MoonSession, args: ProjectGraphArgs) -> Sess
     }
 
     // Force expand all projects
-    project_graph.get_all()?;
+    let projects = project_graph.get_all()?;
 
     if args.dot {
         session.console.out.write_line(project_graph.to_dot())?;
@@ -54,6 +60,18 @@ pub async fn project_graph(session: MoonSession, args: ProjectGraphArgs) -> Sess
         return Ok(None);
     }
 
+    if args.graphml {
+        session.console.out.write_line(project_graph.to_graphml())?;
+
+        return Ok(None);
+    }
+
+    if args.mermaid {
+        session.console.out.write_line(project_graph.to_mermaid())?;
+
+        return Ok(None);
+    }
+
     if args.json {
         session
             .console
@@ -66,6 +84,11 @@ pub async fn project_graph(session: MoonSession, args: ProjectGraphArgs) -> Sess
     run_server(
         "Project graph",
         project_graph.to_json(false)?,
+        Some(GraphExplorer {
+            graph: ExplorerGraph::from_projects(&projects),
+            vcs: session.get_vcs_adapter().await?,
+            workspace_graph: session.get_workspace_graph().await?,
+        }),
         args.host,
         args.port,
     )
@@ -87,7 +87,7 @@ pub async fn projects(session: MoonSession, args: QueryProjectsArgs) -> SessionR
 
     // Filter down to affected projects only
     if let Some(by) = &args.affected {
-        let vcs = session.get_vcs_adapter()?;
+        let vcs = session.get_vcs_adapter().await?;
         let changed_files = query_changed_files_for_affected(&vcs, by.as_ref()).await?;
 
         let mut affected_tracker = AffectedTracker::new(workspace_graph.clone(), changed_files);
@@ -1,8 +1,8 @@
-use crate::commands::graph::run_server;
+use crate::commands::graph::{ExplorerGraph, GraphExplorer, run_server};
 use crate::session::{MoonSession, SessionResult};
 use clap::Args;
 use moon_task::Target;
-use moon_task_graph::{GraphToDot, GraphToJson};
+use moon_task_graph::{GraphToDot, GraphToGraphMl, GraphToJson, GraphToMermaid};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -33,8 +33,14 @@ pub struct TaskGraphArgs {
     #[arg(long, help = "Print the graph in DOT format")]
     dot: bool,
 
+    #[arg(long, help = "Print the graph in GraphML format")]
+    graphml: bool,
+
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(long, help = "Print the graph in Mermaid format")]
+    mermaid: bool,
 }
 
 #[instrument(skip(session))]
@@ -46,7 +52,7 @@ pub async fn task_graph(session: MoonSession, args: TaskGraphArgs) -> SessionRes
     }
 
     // Force expand all tasks
-    task_graph.get_all()?;
+    let tasks = task_graph.get_all()?;
 
     if args.dot {
         session.console.out.write_line(task_graph.to_dot())?;
@@ -54,6 +60,18 @@ pub async fn task_graph(session: MoonSession, args: TaskGraphArgs) -> SessionRes
         return Ok(None);
     }
 
+    if args.graphml {
+        session.console.out.write_line(task_graph.to_graphml())?;
+
+        return Ok(None);
+    }
+
+    if args.mermaid {
+        session.console.out.write_line(task_graph.to_mermaid())?;
+
+        return Ok(None);
+    }
+
     if args.json {
         session.console.out.write_line(task_graph.to_json(true)?)?;
 
@@ -63,6 +81,11 @@ pub async fn task_graph(session: MoonSession, args: TaskGraphArgs) -> SessionRes
     run_server(
         "Task graph",
         task_graph.to_json(false)?,
+        Some(GraphExplorer {
+            graph: ExplorerGraph::from_tasks(&tasks),
+            vcs: session.get_vcs_adapter().await?,
+            workspace_graph: session.get_workspace_graph().await?,
+        }),
         args.host,
         args.port,
     )
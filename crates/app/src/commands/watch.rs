@@ -0,0 +1,604 @@
+use crate::session::{MoonSession, SessionResult};
+use clap::Args;
+use iocraft::prelude::element;
+use moon_action_context::ActionContext;
+use moon_action_graph::{ActionGraph, ActionGraphBuilder, RunRequirements};
+use moon_action_pipeline::ActionPipeline;
+use moon_affected::{DownstreamScope, UpstreamScope};
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_console::ui::{Container, Notice, StyledText, Variant};
+use moon_console::{Console, Level};
+use moon_daemon::start_file_watcher;
+use moon_file_watcher::FileEvent;
+use moon_process::ProcessRegistry;
+use moon_task::{TargetLocator, Task};
+use moon_vcs::BoxedVcs;
+use rustc_hash::FxHashSet;
+use starbase_utils::glob::GlobSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, instrument, warn};
+
+#[derive(Args, Clone, Debug)]
+pub struct WatchArgs {
+    #[arg(
+        required = true,
+        help = "List of task targets to run when their inputs change"
+    )]
+    targets: Vec<TargetLocator>,
+
+    #[arg(
+        long,
+        default_value_t = 100,
+        help = "Time in milliseconds to wait for file changes to settle before running tasks"
+    )]
+    debounce: u64,
+
+    #[arg(long, help = "Don't run the tasks when the watcher starts")]
+    no_initial_run: bool,
+}
+
+struct WatchRun {
+    cancel_token: CancellationToken,
+    handle: JoinHandle<miette::Result<()>>,
+}
+
+impl WatchRun {
+    async fn cancel(self) {
+        if !self.handle.is_finished() {
+            debug!("Cancelling in-flight pipeline run");
+
+            self.cancel_token.cancel();
+        }
+
+        let _ = self.handle.await;
+    }
+}
+
+// Changes to ignored files, or files written by tasks, must not trigger
+// a run, otherwise a task that writes its outputs would loop forever
+struct WatchFilter {
+    output_files: Vec<WorkspaceRelativePathBuf>,
+    output_globs: GlobSet<'static>,
+    vcs: Arc<BoxedVcs>,
+    workspace_root: PathBuf,
+}
+
+impl WatchFilter {
+    fn new(
+        tasks: &[Arc<Task>],
+        vcs: Arc<BoxedVcs>,
+        workspace_root: PathBuf,
+    ) -> miette::Result<Self> {
+        Ok(Self {
+            output_files: tasks
+                .iter()
+                .flat_map(|task| task.output_files.keys().cloned())
+                .collect(),
+            output_globs: GlobSet::new_owned(
+                tasks.iter().flat_map(|task| task.output_globs.keys()),
+            )?,
+            vcs,
+            workspace_root,
+        })
+    }
+
+    fn filter(&self, changed_files: &mut FxHashSet<WorkspaceRelativePathBuf>) {
+        changed_files.retain(|file| {
+            !self
+                .vcs
+                .is_ignored(&file.to_logical_path(&self.workspace_root))
+                && !self
+                    .output_files
+                    .iter()
+                    .any(|output| file == output || file.starts_with(output))
+                && !self.output_globs.matches(file.as_str())
+        });
+    }
+}
+
+#[instrument(skip(session))]
+pub async fn watch(session: MoonSession, args: WatchArgs) -> SessionResult {
+    let (event_tx, mut event_rx) = broadcast::channel::<FileEvent>(1024);
+    let (shutdown_tx, shutdown_rx) = broadcast::channel::<()>(1);
+    let mut signal_rx = ProcessRegistry::instance().receive_signal();
+
+    // Subscribe before running anything, so that we don't
+    // miss changes made while the initial run is in progress
+    let watcher_handle = tokio::spawn(start_file_watcher(
+        session.workspace_root.clone(),
+        event_tx,
+        shutdown_rx,
+    ));
+
+    let filter = WatchFilter::new(
+        &session
+            .get_workspace_graph()
+            .await?
+            .get_tasks_with_internal()?,
+        session.get_vcs_adapter().await?,
+        session.workspace_root.clone(),
+    )?;
+
+    let mut run = if args.no_initial_run {
+        None
+    } else {
+        start_run(&session, &args).await?
+    };
+
+    if run.is_none() {
+        print_waiting(&session.console)?;
+    }
+
+    loop {
+        tokio::select! {
+            result = event_rx.recv() => {
+                let mut changed_files = FxHashSet::default();
+                let mut lagged = false;
+
+                match result {
+                    Ok(event) => {
+                        changed_files.insert(event.path);
+                    }
+                    Err(broadcast::error::RecvError::Lagged(count)) => {
+                        warn!("File change event receiver lagged by {count} events");
+
+                        lagged = true;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        break;
+                    }
+                };
+
+                lagged |= collect_changed_files(&mut event_rx, &mut changed_files, args.debounce).await;
+
+                // Events were dropped, so we can't know what changed,
+                // and must run all watched tasks instead
+                let changed_files = if lagged {
+                    None
+                } else {
+                    filter.filter(&mut changed_files);
+
+                    if changed_files.is_empty() {
+                        continue;
+                    }
+
+                    debug!(
+                        files = ?changed_files.iter().map(|file| file.as_str()).collect::<Vec<_>>(),
+                        "Detected file changes",
+                    );
+
+                    Some(changed_files)
+                };
+
+                // Only cancel the in-flight run if the changes affect
+                // a watched task, otherwise let it continue
+                let (context, graph) = match build_graph(&session, &args, changed_files).await {
+                    Ok(Some(result)) => result,
+                    Ok(None) => continue,
+                    Err(error) => {
+                        // The graph may be broken mid-edit, so keep waiting
+                        // for the next change instead of exiting
+                        render_failure(&session.console, error)?;
+
+                        if run.is_none() {
+                            print_waiting(&session.console)?;
+                        }
+
+                        continue;
+                    }
+                };
+
+                if let Some(previous) = run.take() {
+                    previous.cancel().await;
+                }
+
+                run = Some(spawn_run(&session, context, graph).await?);
+            }
+            _ = signal_rx.recv() => {
+                break;
+            }
+        }
+    }
+
+    if let Some(previous) = run.take() {
+        previous.cancel().await;
+    }
+
+    let _ = shutdown_tx.send(());
+    let _ = watcher_handle.await;
+
+    Ok(None)
+}
+
+// Events arrive in bursts (saving multiple files, switching branches, etc),
+// so wait until they settle before running anything. Returns true if
+// the receiver lagged and events were dropped.
+async fn collect_changed_files(
+    event_rx: &mut broadcast::Receiver<FileEvent>,
+    changed_files: &mut FxHashSet<WorkspaceRelativePathBuf>,
+    debounce: u64,
+) -> bool {
+    let timeout = Duration::from_millis(debounce);
+    let mut lagged = false;
+
+    loop {
+        match tokio::time::timeout(timeout, event_rx.recv()).await {
+            Ok(Ok(event)) => {
+                changed_files.insert(event.path);
+            }
+            Ok(Err(broadcast::error::RecvError::Lagged(count))) => {
+                warn!("File change event receiver lagged by {count} events");
+
+                lagged = true;
+            }
+            Ok(Err(broadcast::error::RecvError::Closed)) | Err(_) => {
+                break;
+            }
+        };
+    }
+
+    lagged
+}
+
+async fn build_graph(
+    session: &MoonSession,
+    args: &WatchArgs,
+    changed_files: Option<FxHashSet<WorkspaceRelativePathBuf>>,
+) -> miette::Result<Option<(ActionContext, ActionGraph)>> {
+    let mut action_graph_builder = session.build_action_graph().await?;
+
+    if !track_watched_tasks(&mut action_graph_builder, &args.targets, changed_files).await? {
+        debug!("No watched tasks affected by changed files, skipping run");
+
+        return Ok(None);
+    }
+
+    Ok(Some(action_graph_builder.build()))
+}
+
+// Map the changed files to affected tasks, using the same input
+// matching that powers `--affected`, and return whether any
+// of the watched tasks need to run
+async fn track_watched_tasks(
+    action_graph_builder: &mut ActionGraphBuilder<'_>,
+    targets: &[TargetLocator],
+    changed_files: Option<FxHashSet<WorkspaceRelativePathBuf>>,
+) -> miette::Result<bool> {
+    let skip_affected = changed_files.is_none();
+
+    if let Some(files) = changed_files {
+        action_graph_builder.set_changed_files(files)?;
+        action_graph_builder
+            .track_affected(UpstreamScope::Deep, DownstreamScope::None, false)
+            .await?;
+    }
+
+    let partition = action_graph_builder
+        .run_tasks(
+            targets,
+            RunRequirements {
+                ci: false,
+                skip_affected,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    Ok(!partition.targets.is_empty())
+}
+
+async fn start_run(session: &MoonSession, args: &WatchArgs) -> miette::Result<Option<WatchRun>> {
+    match build_graph(session, args, None).await {
+        Ok(Some((context, graph))) => Ok(Some(spawn_run(session, context, graph).await?)),
+        Ok(None) => Ok(None),
+        Err(error) => {
+            render_failure(&session.console, error)?;
+
+            Ok(None)
+        }
+    }
+}
+
+async fn spawn_run(
+    session: &MoonSession,
+    action_context: ActionContext,
+    action_graph: ActionGraph,
+) -> miette::Result<WatchRun> {
+    let mut pipeline = ActionPipeline::new(
+        session.get_app_context().await?,
+        session.get_workspace_graph().await?,
+        session.connect_to_daemon().await?,
+    );
+
+    if let Some(concurrency) = &session.cli.concurrency {
+        pipeline.concurrency = *concurrency;
+    }
+
+    pipeline.bail = true;
+    pipeline.summary = Some(Level::Zero);
+
+    let cancel_token = pipeline.cancel_token.clone();
+    let run_cancel_token = cancel_token.clone();
+    let console = session.console.clone();
+
+    let handle = tokio::spawn(async move {
+        // Render the failure but continue watching for changes,
+        // as the next change will most likely fix it
+        if let Err(error) = pipeline
+            .run_with_context(action_graph, action_context)
+            .await
+            && !run_cancel_token.is_cancelled()
+        {
+            render_failure(&console, error)?;
+        }
+
+        // A new run is about to start, so avoid the noise
+        if !run_cancel_token.is_cancelled() {
+            print_waiting(&console)?;
+        }
+
+        Ok(())
+    });
+
+    Ok(WatchRun {
+        cancel_token,
+        handle,
+    })
+}
+
+fn render_failure(console: &Console, error: miette::Report) -> miette::Result<()> {
+    console.render_err(element! {
+        Container {
+            Notice(variant: Variant::Failure) {
+                StyledText(content: error.to_string())
+            }
+        }
+    })?;
+
+    Ok(())
+}
+
+fn print_waiting(console: &Console) -> miette::Result<()> {
+    console.render(element! {
+        Container {
+            Notice(variant: Variant::Info) {
+                StyledText(content: "Waiting for file changes, press <shell>ctrl+c</shell> to exit")
+            }
+        }
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moon_file_watcher::{EventKind, ModifyKind};
+    use moon_test_utils::WorkspaceMocker;
+    use starbase_sandbox::create_empty_sandbox;
+    use std::path::PathBuf;
+
+    fn create_event(path: &str) -> FileEvent {
+        FileEvent {
+            path_original: PathBuf::from(path),
+            path: WorkspaceRelativePathBuf::from(path),
+            kind: EventKind::Modify(ModifyKind::Any),
+        }
+    }
+
+    fn create_changed_files(paths: &[&str]) -> FxHashSet<WorkspaceRelativePathBuf> {
+        paths
+            .iter()
+            .map(|path| WorkspaceRelativePathBuf::from(*path))
+            .collect()
+    }
+
+    mod debounce {
+        use super::*;
+
+        #[tokio::test]
+        async fn collects_events_until_they_settle() {
+            let (event_tx, mut event_rx) = broadcast::channel(10);
+            let mut changed_files = FxHashSet::default();
+
+            let sender = tokio::spawn(async move {
+                for path in ["a.js", "b.js", "c.js"] {
+                    event_tx.send(create_event(path)).unwrap();
+
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+
+                event_tx
+            });
+
+            collect_changed_files(&mut event_rx, &mut changed_files, 200).await;
+
+            assert_eq!(
+                changed_files,
+                create_changed_files(&["a.js", "b.js", "c.js"])
+            );
+
+            let _ = sender.await;
+        }
+
+        #[tokio::test]
+        async fn dedupes_events_for_the_same_file() {
+            let (event_tx, mut event_rx) = broadcast::channel(10);
+            let mut changed_files = FxHashSet::default();
+
+            event_tx.send(create_event("a.js")).unwrap();
+            event_tx.send(create_event("a.js")).unwrap();
+
+            collect_changed_files(&mut event_rx, &mut changed_files, 50).await;
+
+            assert_eq!(changed_files, create_changed_files(&["a.js"]));
+        }
+
+        #[tokio::test]
+        async fn stops_when_the_channel_closes() {
+            let (event_tx, mut event_rx) = broadcast::channel(10);
+            let mut changed_files = FxHashSet::default();
+
+            event_tx.send(create_event("a.js")).unwrap();
+            drop(event_tx);
+
+            // Would wait for the entire debounce if the closed channel wasn't handled
+            collect_changed_files(&mut event_rx, &mut changed_files, 60_000).await;
+
+            assert_eq!(changed_files, create_changed_files(&["a.js"]));
+        }
+    }
+
+    mod filter {
+        use super::*;
+
+        async fn filter(paths: &[&str]) -> FxHashSet<WorkspaceRelativePathBuf> {
+            let sandbox = create_empty_sandbox();
+            sandbox.enable_git();
+            sandbox.create_file(".gitignore", "*.log");
+            sandbox.create_file(
+                "app/moon.yml",
+                r#"
+tasks:
+  build:
+    command: noop
+    outputs:
+      - 'dist'
+      - 'types/**/*.d.ts'
+"#,
+            );
+
+            let mocker = WorkspaceMocker::new(sandbox.path())
+                .load_default_configs()
+                .with_default_projects();
+            let graph = mocker.mock_workspace_graph().await;
+            let filter = WatchFilter::new(
+                &graph.get_tasks_with_internal().unwrap(),
+                Arc::new(mocker.mock_vcs_adapter()),
+                sandbox.path().to_path_buf(),
+            )
+            .unwrap();
+
+            let mut changed_files = create_changed_files(paths);
+            filter.filter(&mut changed_files);
+            changed_files
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn keeps_source_files() {
+            assert_eq!(
+                filter(&["app/src/index.js"]).await,
+                create_changed_files(&["app/src/index.js"])
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn removes_ignored_files() {
+            assert!(filter(&["app/debug.log"]).await.is_empty());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn removes_task_outputs() {
+            assert!(
+                filter(&["app/dist/index.js", "app/types/nested/index.d.ts"])
+                    .await
+                    .is_empty()
+            );
+        }
+    }
+
+    mod cancel {
+        use super::*;
+
+        #[tokio::test]
+        async fn cancels_in_flight_runs() {
+            let cancel_token = CancellationToken::new();
+            let run_cancel_token = cancel_token.clone();
+
+            let run = WatchRun {
+                cancel_token: cancel_token.clone(),
+                handle: tokio::spawn(async move {
+                    run_cancel_token.cancelled().await;
+
+                    Ok(())
+                }),
+            };
+
+            run.cancel().await;
+
+            assert!(cancel_token.is_cancelled());
+        }
+
+        #[tokio::test]
+        async fn doesnt_cancel_finished_runs() {
+            let cancel_token = CancellationToken::new();
+
+            let run = WatchRun {
+                cancel_token: cancel_token.clone(),
+                handle: tokio::spawn(async { Ok(()) }),
+            };
+
+            while !run.handle.is_finished() {
+                tokio::task::yield_now().await;
+            }
+
+            run.cancel().await;
+
+            assert!(!cancel_token.is_cancelled());
+        }
+    }
+
+    mod affected {
+        use super::*;
+
+        async fn track(changed_files: Option<&[&str]>) -> bool {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(
+                "app/moon.yml",
+                r#"
+tasks:
+  build:
+    command: noop
+    inputs:
+      - 'src/**/*'
+"#,
+            );
+            sandbox.create_file("app/src/index.js", "");
+            sandbox.create_file("app/README.md", "");
+
+            let mocker = WorkspaceMocker::new(sandbox.path())
+                .load_default_configs()
+                .with_default_projects();
+            let mut builder = mocker.create_action_graph().await;
+
+            track_watched_tasks(
+                &mut builder,
+                &[TargetLocator::parse("app:build").unwrap()],
+                changed_files.map(create_changed_files),
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn runs_all_tasks_without_changed_files() {
+            assert!(track(None).await);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn runs_tasks_when_inputs_change() {
+            assert!(track(Some(&["app/src/index.js"])).await);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn skips_tasks_when_other_files_change() {
+            assert!(!track(Some(&["app/README.md"])).await);
+            assert!(!track(Some(&["other/src/index.js"])).await);
+        }
+    }
+}
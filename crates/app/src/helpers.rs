@@ -10,6 +10,7 @@ use moon_action_pipeline::ActionPipeline;
 use moon_common::Id;
 use moon_console::ui::{OwnedOrShared, Progress, ProgressDisplay, ProgressReporter};
 use moon_console::{Console, ConsoleError, Level};
+use moon_exec_plan::ReportFormat;
 use serde::Serialize;
 use starbase_utils::{fs, json, toml, yaml};
 use std::ops::Deref;
@@ -75,6 +76,7 @@ pub async fn run_action_pipeline(
     action_context: ActionContext,
     action_graph: ActionGraph,
     summary: Option<Level>,
+    reports: Vec<ReportFormat>,
 ) -> miette::Result<Vec<Action>> {
     let mut pipeline = ActionPipeline::new(
         session.get_app_context().await?,
@@ -86,6 +88,7 @@ pub async fn run_action_pipeline(
         pipeline.concurrency = *concurrency;
     }
 
+    pipeline.reports = reports;
     pipeline.summary = summary;
 
     match &session.cli.command {
@@ -26,6 +26,7 @@ use moon_project_graph::ProjectGraph;
 use moon_task_graph::TaskGraph;
 use moon_toolchain_plugin::*;
 use moon_vcs::{BoxedVcs, git::Git};
+use moon_vcs_plugin::{PluginVcs, VcsRegistry};
 use moon_workspace::{WorkspaceBuilder, WorkspaceBuilderAsync, WorkspaceBuilderContext};
 use moon_workspace_graph::WorkspaceGraph;
 use proto_core::ProtoEnvironment;
@@ -54,13 +55,13 @@ pub struct MoonSession {
     pub proto_env: Arc<ProtoEnvironment>,
 
     // Lazy components
-    pub(crate) cache_engine: OnceLock<Arc<CacheEngine>>,
+    pub(crate) cache_engine: OnceCell<Arc<CacheEngine>>,
     pub(crate) daemon_client: OnceLock<DaemonClient>,
     pub(crate) extension_registry: OnceCell<Arc<ExtensionRegistry>>,
     pub(crate) project_graph: OnceLock<Arc<ProjectGraph>>,
     pub(crate) task_graph: OnceLock<Arc<TaskGraph>>,
     pub(crate) toolchain_registry: OnceCell<Arc<ToolchainRegistry>>,
-    pub(crate) vcs_adapter: OnceLock<Arc<BoxedVcs>>,
+    pub(crate) vcs_adapter: OnceCell<Arc<BoxedVcs>>,
     pub(crate) workspace_graph: OnceCell<Arc<WorkspaceGraph>>,
 
     // Configs
@@ -81,7 +82,7 @@ impl MoonSession {
 
         Self {
             exit_code: AppExitCode::default(),
-            cache_engine: OnceLock::new(),
+            cache_engine: OnceCell::new(),
             cli_version: Version::parse(&cli_version).unwrap(),
             config_dir: PathBuf::new(),
             config_loader: ConfigLoader::default(),
@@ -100,7 +101,7 @@ impl MoonSession {
             workspace_config: Arc::new(WorkspaceConfig::default()),
             workspace_graph: OnceCell::new(),
             workspace_root: PathBuf::new(),
-            vcs_adapter: OnceLock::new(),
+            vcs_adapter: OnceCell::new(),
             cli,
         }
     }
@@ -159,7 +160,7 @@ impl MoonSession {
 
     pub async fn create_workspace_graph_context(&self) -> miette::Result<WorkspaceBuilderContext> {
         Ok(WorkspaceBuilderContext {
-            cache_engine: self.get_cache_engine()?,
+            cache_engine: self.get_cache_engine().await?,
             config_loader: self.config_loader.clone(),
             enabled_toolchains: self.toolchains_config.get_enabled(),
             extensions_config: Arc::clone(&self.extensions_config),
@@ -167,7 +168,7 @@ impl MoonSession {
             inherited_tasks: Arc::clone(&self.tasks_config),
             toolchains_config: Arc::clone(&self.toolchains_config),
             toolchain_registry: self.get_toolchain_registry().await?,
-            vcs: Some(self.get_vcs_adapter()?),
+            vcs: Some(self.get_vcs_adapter().await?),
             working_dir: self.working_dir.clone(),
             workspace_config: Arc::clone(&self.workspace_config),
             workspace_root: self.workspace_root.clone(),
@@ -177,7 +178,7 @@ impl MoonSession {
     pub async fn get_app_context(&self) -> miette::Result<Arc<AppContext>> {
         Ok(Arc::new(AppContext {
             cli_version: self.cli_version.clone(),
-            cache_engine: self.get_cache_engine()?,
+            cache_engine: self.get_cache_engine().await?,
             config_dir: self.config_dir.clone(),
             config_exts: self.config_loader.extensions.clone(),
             console: self.get_console()?,
@@ -188,77 +189,78 @@ impl MoonSession {
             extension_registry: self.get_extension_registry().await?,
             toolchains_config: Arc::clone(&self.toolchains_config),
             toolchain_registry: self.get_toolchain_registry().await?,
-            vcs: self.get_vcs_adapter()?,
+            vcs: self.get_vcs_adapter().await?,
             working_dir: self.working_dir.clone(),
             workspace_config: Arc::clone(&self.workspace_config),
             workspace_root: self.workspace_root.clone(),
         }))
     }
 
-    pub fn get_cache_engine(&self) -> miette::Result<Arc<CacheEngine>> {
-        if self.cache_engine.get().is_none() {
-            let mut context = CacheContext {
-                cache_dir: self.config_dir.join("cache"),
-                cache_shared_dir: None,
-                cache_config: Arc::new(self.workspace_config.cache.clone()),
-                config_dir: self.config_dir.clone(),
-                remote_config: Arc::new(self.workspace_config.remote.clone()),
-                remote_debug: GlobalEnvBag::instance().should_debug_remote(),
-                workspace_root: self.workspace_root.clone(),
-            };
-
-            if context.cache_config.shared_worktree_cache {
-                let vcs = self.get_vcs_adapter()?;
-
-                if vcs.is_worktree() {
-                    let repo_root = vcs.get_repository_root()?;
-                    let worktree_root = vcs.get_working_root()?;
-                    let common_moon_dir = repo_root.join(&self.config_loader.dir_prefix);
-
-                    if common_moon_dir.exists() && repo_root != worktree_root {
-                        context.cache_shared_dir = Some(common_moon_dir.join("cache"));
-                    } else {
-                        context.cache_shared_dir = Some(self.moon_env.cache_dir.join("shared"));
-                    }
+    pub async fn get_cache_engine(&self) -> miette::Result<Arc<CacheEngine>> {
+        self.cache_engine
+            .get_or_try_init(async || {
+                let mut context = CacheContext {
+                    cache_dir: self.config_dir.join("cache"),
+                    cache_shared_dir: None,
+                    cache_config: Arc::new(self.workspace_config.cache.clone()),
+                    config_dir: self.config_dir.clone(),
+                    remote_config: Arc::new(self.workspace_config.remote.clone()),
+                    remote_debug: GlobalEnvBag::instance().should_debug_remote(),
+                    workspace_root: self.workspace_root.clone(),
+                };
 
-                    debug!(
-                        dir = ?context.cache_shared_dir,
-                        "In a VCS worktree, using a shared cache directory for blobs and manifests",
-                    );
-                }
-            }
+                if context.cache_config.shared_worktree_cache {
+                    let vcs = self.get_vcs_adapter().await?;
 
-            let mut engine = CacheEngine::new(context.clone())?;
+                    if vcs.is_worktree() {
+                        let repo_root = vcs.get_repository_root()?;
+                        let worktree_root = vcs.get_working_root()?;
+                        let common_moon_dir = repo_root.join(&self.config_loader.dir_prefix);
 
-            if self.workspace_config.experiments.cas_outputs_cache {
-                engine.storage.add_local_backend(LocalStorage::new(
-                    context.clone(),
-                    context
-                        .cache_shared_dir
-                        .as_deref()
-                        .unwrap_or(&context.cache_dir),
-                )?);
-            }
+                        if common_moon_dir.exists() && repo_root != worktree_root {
+                            context.cache_shared_dir = Some(common_moon_dir.join("cache"));
+                        } else {
+                            context.cache_shared_dir = Some(self.moon_env.cache_dir.join("shared"));
+                        }
 
-            if context.remote_config.is_enabled() {
-                match context.remote_config.api {
-                    RemoteApi::Grpc => {
-                        engine
-                            .storage
-                            .add_remote_backend(GrpcRemoteStorage::new(context.clone())?);
-                    }
-                    RemoteApi::Http => {
-                        engine
-                            .storage
-                            .add_remote_backend(HttpRemoteStorage::new(context.clone())?);
+                        debug!(
+                            dir = ?context.cache_shared_dir,
+                            "In a VCS worktree, using a shared cache directory for blobs and manifests",
+                        );
                     }
-                };
-            }
+                }
 
-            let _ = self.cache_engine.set(Arc::new(engine));
-        }
+                let mut engine = CacheEngine::new(context.clone())?;
+
+                if self.workspace_config.experiments.cas_outputs_cache {
+                    engine.storage.add_local_backend(LocalStorage::new(
+                        context.clone(),
+                        context
+                            .cache_shared_dir
+                            .as_deref()
+                            .unwrap_or(&context.cache_dir),
+                    )?);
+                }
+
+                if context.remote_config.is_enabled() {
+                    match context.remote_config.api {
+                        RemoteApi::Grpc => {
+                            engine
+                                .storage
+                                .add_remote_backend(GrpcRemoteStorage::new(context.clone())?);
+                        }
+                        RemoteApi::Http => {
+                            engine
+                                .storage
+                                .add_remote_backend(HttpRemoteStorage::new(context.clone())?);
+                        }
+                    };
+                }
 
-        Ok(self.cache_engine.get().map(Arc::clone).unwrap())
+                Ok(Arc::new(engine))
+            })
+            .await
+            .map(Arc::clone)
     }
 
     pub fn get_console(&self) -> miette::Result<Arc<Console>> {
@@ -327,20 +329,37 @@ impl MoonSession {
             .map(Arc::clone)
     }
 
-    pub fn get_vcs_adapter(&self) -> miette::Result<Arc<BoxedVcs>> {
-        if self.vcs_adapter.get().is_none() {
-            let config = &self.workspace_config.vcs;
-
-            let git: BoxedVcs = Box::new(Git::load(
-                &self.workspace_root,
-                &config.default_branch,
-                &config.remote_candidates,
-            )?);
-
-            let _ = self.vcs_adapter.set(Arc::new(git));
-        }
+    pub async fn get_vcs_adapter(&self) -> miette::Result<Arc<BoxedVcs>> {
+        self.vcs_adapter
+            .get_or_try_init(async || {
+                let config = &self.workspace_config.vcs;
+
+                let vcs: BoxedVcs = if config.plugin.is_some() {
+                    let registry = VcsRegistry::new(
+                        MoonHostData {
+                            moon_env: Arc::clone(&self.moon_env),
+                            proto_env: Arc::clone(&self.proto_env),
+                            extensions_config: Arc::clone(&self.extensions_config),
+                            toolchains_config: Arc::clone(&self.toolchains_config),
+                            workspace_config: Arc::clone(&self.workspace_config),
+                            workspace_graph: Arc::new(OnceLock::new()),
+                        },
+                        Arc::new(config.clone()),
+                    )?;
+
+                    Box::new(PluginVcs::load(&registry, &self.workspace_root, config).await?)
+                } else {
+                    Box::new(Git::load(
+                        &self.workspace_root,
+                        &config.default_branch,
+                        &config.remote_candidates,
+                    )?)
+                };
 
-        Ok(self.vcs_adapter.get().map(Arc::clone).unwrap())
+                Ok(Arc::new(vcs))
+            })
+            .await
+            .map(Arc::clone)
     }
 
     pub async fn get_workspace_graph(&self) -> miette::Result<Arc<WorkspaceGraph>> {
@@ -371,6 +390,7 @@ impl MoonSession {
                 | Commands::Exec(_)
                 | Commands::Run(_)
                 | Commands::Sync { .. }
+                | Commands::Watch(_)
         )
     }
 
@@ -493,13 +513,13 @@ impl AppSession for MoonSession {
             analyze::validate_version_constraint(constraint, &self.cli_version)?;
         }
 
-        let vcs = self.get_vcs_adapter()?;
+        let vcs = self.get_vcs_adapter().await?;
 
         analyze::extract_repo_info(&vcs).await?;
 
         // Preload components
         if self.requires_workspace_configured() {
-            let _ = self.get_cache_engine()?;
+            let _ = self.get_cache_engine().await?;
         }
 
         Ok(None)
@@ -510,7 +530,11 @@ impl AppSession for MoonSession {
         // Connect to the storage backends as early as possible,
         // but only for commands that will actually read/write to it
         if self.is_pipeline_command() || self.is_daemon_server_command() {
-            self.get_cache_engine()?.storage.connect_backends().await?;
+            self.get_cache_engine()
+                .await?
+                .storage
+                .connect_backends()
+                .await?;
         }
 
         // Check for a new version and log to the console
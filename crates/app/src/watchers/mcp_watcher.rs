@@ -0,0 +1,96 @@
+use crate::session::MoonSession;
+use crate::watchers::{WorkspaceChange, WorkspaceWatcher};
+use async_trait::async_trait;
+use moon_file_watcher::*;
+use moon_mcp::AtomicWorkspaceGraph;
+use moon_mcp::resources::McpResourceChange;
+use moon_workspace::{STATE_CACHE_FILE_NAME, STATE_GRAPH_FILE_NAME};
+use starbase_utils::fs;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Forwards workspace config changes to the MCP server, so that it can
+/// notify clients of resources that need to be read again. When projects
+/// change, the workspace graph is rebuilt before the client is notified.
+pub struct McpWatcher {
+    change_tx: mpsc::Sender<McpResourceChange>,
+    session: MoonSession,
+    workspace_graph: AtomicWorkspaceGraph,
+    workspace_watcher: WorkspaceWatcher,
+}
+
+impl McpWatcher {
+    pub fn new(
+        session: MoonSession,
+        workspace_graph: AtomicWorkspaceGraph,
+        change_tx: mpsc::Sender<McpResourceChange>,
+    ) -> Self {
+        Self {
+            change_tx,
+            workspace_watcher: WorkspaceWatcher::new(session.clone()),
+            session,
+            workspace_graph,
+        }
+    }
+
+    async fn rebuild_workspace_graph(&mut self) -> miette::Result<()> {
+        debug!("Rebuilding workspace graph for the MCP server");
+
+        // Ensure the cache/state files are cleared before rebuilding
+        let cache_engine = self.session.get_cache_engine().await?;
+
+        fs::remove_file(cache_engine.state.resolve_path(STATE_GRAPH_FILE_NAME))?;
+        fs::remove_file(cache_engine.state.resolve_path(STATE_CACHE_FILE_NAME))?;
+
+        self.session.reset_components();
+
+        let workspace_graph = self.session.get_workspace_graph().await?;
+
+        *self.workspace_graph.write().await = workspace_graph;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FileWatcher<()> for McpWatcher {
+    async fn on_init(&mut self, _state: ()) -> miette::Result<()> {
+        Ok(())
+    }
+
+    async fn on_file_event(&mut self, _state: (), event: &FileEvent) -> miette::Result<()> {
+        let change = match self.workspace_watcher.detect_change(event)? {
+            Some(WorkspaceChange::ProjectConfig(source)) => {
+                let known = self
+                    .workspace_graph
+                    .read()
+                    .await
+                    .get_projects_unexpanded()
+                    .iter()
+                    .any(|project| project.source == source);
+
+                self.rebuild_workspace_graph().await?;
+
+                // A config for a new project changes the list of resources
+                if known {
+                    McpResourceChange::ProjectConfig(source)
+                } else {
+                    McpResourceChange::Projects
+                }
+            }
+            Some(WorkspaceChange::ProjectRoot) => {
+                self.rebuild_workspace_graph().await?;
+
+                McpResourceChange::Projects
+            }
+            Some(WorkspaceChange::TasksConfig) => McpResourceChange::TasksConfig,
+            Some(WorkspaceChange::WorkspaceConfig) => McpResourceChange::WorkspaceConfig,
+            _ => return Ok(()),
+        };
+
+        // The server has shutdown, so there's nothing to notify
+        let _ = self.change_tx.send(change).await;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+mod mcp_watcher;
 mod workspace_watcher;
 
+pub use mcp_watcher::*;
 pub use workspace_watcher::*;
@@ -1,6 +1,6 @@
 use crate::session::MoonSession;
 use async_trait::async_trait;
-use moon_common::path::WorkspaceRelativePath;
+use moon_common::path::{WorkspaceRelativePath, WorkspaceRelativePathBuf};
 use moon_config::WorkspaceProjects;
 use moon_daemon::AtomicDaemonState;
 use moon_file_watcher::*;
@@ -13,6 +13,18 @@ use std::sync::Arc;
 use tokio::task::JoinHandle;
 use tracing::debug;
 
+#[derive(Clone, Debug, PartialEq)]
+pub enum WorkspaceChange {
+    ExtensionsConfig,
+    /// Contains the source of the project the config belongs to.
+    ProjectConfig(WorkspaceRelativePathBuf),
+    ProjectRoot,
+    Proto,
+    TasksConfig,
+    ToolchainsConfig,
+    WorkspaceConfig,
+}
+
 pub struct WorkspaceWatcher {
     context_handle: Option<JoinHandle<()>>,
     graph_handle: Option<JoinHandle<()>>,
@@ -57,51 +69,64 @@ impl FileWatcher<AtomicDaemonState> for WorkspaceWatcher {
             return Ok(());
         }
 
+        match self.detect_change(event)? {
+            Some(WorkspaceChange::ExtensionsConfig) => self.reset_extensions(&state).await?,
+            Some(WorkspaceChange::ProjectConfig(_) | WorkspaceChange::ProjectRoot) => {
+                self.reset_projects(&state).await?
+            }
+            Some(WorkspaceChange::Proto) => self.reset_proto(&state).await?,
+            Some(WorkspaceChange::TasksConfig) => self.reset_tasks(&state).await?,
+            Some(WorkspaceChange::ToolchainsConfig) => self.reset_toolchains(&state).await?,
+            Some(WorkspaceChange::WorkspaceConfig) => self.reset_workspace(&state).await?,
+            None => {}
+        };
+
+        Ok(())
+    }
+}
+
+impl WorkspaceWatcher {
+    /// Determine which part of the workspace, if any, was changed by a file event.
+    pub fn detect_change(&self, event: &FileEvent) -> miette::Result<Option<WorkspaceChange>> {
         // Handle `.prototools` changes
         if event.path.ends_with(".prototools") {
-            self.reset_proto(&state).await?;
-
-            return Ok(());
+            return Ok(Some(WorkspaceChange::Proto));
         }
 
         // Handle `.moon/*.config` changes
         if let Some(caps) = self.workspace_config_regex.captures(event.path.as_str()) {
-            match caps.name("name").map(|cap| cap.as_str()) {
-                Some("extensions") => self.reset_extensions(&state).await?,
-                Some("toolchains") => self.reset_toolchains(&state).await?,
-                Some("workspace") => self.reset_workspace(&state).await?,
-                _ => {}
-            };
-
-            return Ok(());
+            return Ok(match caps.name("name").map(|cap| cap.as_str()) {
+                Some("extensions") => Some(WorkspaceChange::ExtensionsConfig),
+                Some("toolchains") => Some(WorkspaceChange::ToolchainsConfig),
+                Some("workspace") => Some(WorkspaceChange::WorkspaceConfig),
+                _ => None,
+            });
         }
 
         // Handle `.moon/tasks/**/*.config` changes
         if self.tasks_config_regex.is_match(event.path.as_str()) {
-            self.reset_tasks(&state).await?;
-
-            return Ok(());
+            return Ok(Some(WorkspaceChange::TasksConfig));
         }
 
         // Handle `moon.config` changes
         if self.project_config_regex.is_match(event.path.as_str()) {
-            self.reset_projects(&state).await?;
-
-            return Ok(());
+            return Ok(Some(WorkspaceChange::ProjectConfig(
+                event
+                    .path
+                    .parent()
+                    .map(|parent| parent.to_owned())
+                    .unwrap_or_default(),
+            )));
         }
 
         // Handle the creation/removal of project directories
         if event.is_mutated_directory() && self.is_a_project_root(&event.path)? {
-            self.reset_projects(&state).await?;
-
-            return Ok(());
+            return Ok(Some(WorkspaceChange::ProjectRoot));
         }
 
-        Ok(())
+        Ok(None)
     }
-}
 
-impl WorkspaceWatcher {
     fn is_a_project_root(&self, path: &WorkspaceRelativePath) -> miette::Result<bool> {
         let (sources, globs): (Vec<_>, Vec<_>) = match &self.session.workspace_config.projects {
             WorkspaceProjects::Sources(sources) => (sources.values().collect(), Vec::new()),
@@ -144,7 +169,7 @@ impl WorkspaceWatcher {
         }
 
         // Ensure the cache/state files are cleared before rebuilding
-        let cache_engine = self.session.get_cache_engine()?;
+        let cache_engine = self.session.get_cache_engine().await?;
 
         fs::remove_file(cache_engine.state.resolve_path(STATE_GRAPH_FILE_NAME))?;
         fs::remove_file(cache_engine.state.resolve_path(STATE_CACHE_FILE_NAME))?;
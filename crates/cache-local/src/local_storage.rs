@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use miette::IntoDiagnostic;
 use moon_blob::{Blob, BlobCleanStats, BlobContent, BlobInput, BlobOutput};
 use moon_cache_storage::{CacheCapabilities, CacheContext, Manifest, StorageBackend};
-use moon_cas::CasStore;
+use moon_cas::{CasStore, parse_byte_size};
 use moon_common::Id;
 use moon_hash::{ContentHash, Digest};
 use rustc_hash::FxHashSet;
@@ -305,62 +305,3 @@ fn evict_manifests(
 
     Ok((keep, removed, saved))
 }
-
-/// Parse a human-readable byte size such as `"10gb"`, `"512mib"`, or `"2048"`.
-/// Decimal units (kb/mb/gb/tb) are powers of 1000; binary units (kib/mib/gib/tib)
-/// are powers of 1024. A bare number is bytes. Returns `None` when unparseable.
-fn parse_byte_size(input: &str) -> Option<u64> {
-    let trimmed = input.trim().to_lowercase();
-
-    if trimmed.is_empty() {
-        return None;
-    }
-
-    let boundary = trimmed
-        .find(|c: char| c.is_ascii_alphabetic())
-        .unwrap_or(trimmed.len());
-    let (number, unit) = trimmed.split_at(boundary);
-
-    let value: f64 = number.trim().parse().ok()?;
-    let multiplier: f64 = match unit.trim() {
-        "" | "b" => 1.0,
-        "k" | "kb" => 1_000.0,
-        "kib" => 1_024.0,
-        "m" | "mb" => 1_000_000.0,
-        "mib" => 1_048_576.0,
-        "g" | "gb" => 1_000_000_000.0,
-        "gib" => 1_073_741_824.0,
-        "t" | "tb" => 1_000_000_000_000.0,
-        "tib" => 1_099_511_627_776.0,
-        _ => return None,
-    };
-
-    Some((value * multiplier) as u64)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::parse_byte_size;
-
-    #[test]
-    fn parses_bare_bytes_and_decimal_units() {
-        assert_eq!(parse_byte_size("2048"), Some(2048));
-        assert_eq!(parse_byte_size("10gb"), Some(10_000_000_000));
-        assert_eq!(parse_byte_size("512mb"), Some(512_000_000));
-        assert_eq!(parse_byte_size("1.5gb"), Some(1_500_000_000));
-    }
-
-    #[test]
-    fn parses_binary_units_and_tolerates_spacing_case() {
-        assert_eq!(parse_byte_size("1gib"), Some(1_073_741_824));
-        assert_eq!(parse_byte_size("512 MiB"), Some(536_870_912));
-        assert_eq!(parse_byte_size("  10 GB  "), Some(10_000_000_000));
-    }
-
-    #[test]
-    fn rejects_garbage() {
-        assert_eq!(parse_byte_size(""), None);
-        assert_eq!(parse_byte_size("abc"), None);
-        assert_eq!(parse_byte_size("10xb"), None);
-    }
-}
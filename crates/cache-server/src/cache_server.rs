@@ -0,0 +1,156 @@
+use crate::cache_server_error::CacheServerError;
+use crate::cache_store::CacheStore;
+use crate::grpc_services::{CacheService, MAX_BATCH_TOTAL_SIZE};
+use crate::http_routes::create_http_router;
+use bazel_remote_apis::build::bazel::remote::execution::v2::{
+    action_cache_server::ActionCacheServer, capabilities_server::CapabilitiesServer,
+    content_addressable_storage_server::ContentAddressableStorageServer,
+};
+use bazel_remote_apis::google::bytestream::byte_stream_server::ByteStreamServer;
+use moon_common::color;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tonic::service::Routes;
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use tracing::{debug, instrument, warn};
+
+/// Leave room for protobuf framing on top of the largest batch.
+const MAX_MESSAGE_SIZE: usize = MAX_BATCH_TOTAL_SIZE * 2;
+
+/// Default maximum size of a single uploaded blob.
+pub const DEFAULT_MAX_UPLOAD_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// A remote cache server that speaks both the Bazel remote execution gRPC API
+/// and the bazel-remote HTTP API on the same address, storing everything on
+/// the local file system.
+pub struct CacheServer {
+    pub gc_interval: Duration,
+
+    /// Maximum size of a single blob uploaded through ByteStream or HTTP.
+    pub max_upload_size: u64,
+
+    store: Arc<CacheStore>,
+}
+
+impl CacheServer {
+    pub fn new(store: CacheStore) -> Self {
+        Self {
+            gc_interval: Duration::from_secs(300),
+            max_upload_size: DEFAULT_MAX_UPLOAD_SIZE,
+            store: Arc::new(store),
+        }
+    }
+
+    pub fn get_store(&self) -> Arc<CacheStore> {
+        Arc::clone(&self.store)
+    }
+
+    /// Bind a TCP listener to the provided address.
+    pub async fn bind(address: &str) -> miette::Result<TcpListener> {
+        TcpListener::bind(address).await.map_err(|error| {
+            CacheServerError::BindFailed {
+                address: address.to_owned(),
+                error: Box::new(error),
+            }
+            .into()
+        })
+    }
+
+    /// Serve requests on the listener until the shutdown signal resolves,
+    /// while periodically running garbage collection in the background.
+    #[instrument(skip_all)]
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown_signal: impl Future<Output = ()>,
+    ) -> miette::Result<()> {
+        let address: Option<SocketAddr> = listener.local_addr().ok();
+
+        debug!(address = ?address, "Starting cache server");
+
+        let (gc_stop_tx, gc_stop_rx) = oneshot::channel();
+        let gc_handle = tokio::spawn(run_gc_loop(
+            Arc::clone(&self.store),
+            self.gc_interval,
+            gc_stop_rx,
+        ));
+
+        let service = CacheService::new(Arc::clone(&self.store), self.max_upload_size);
+
+        let routes = Routes::new(
+            CapabilitiesServer::new(service.clone()).max_decoding_message_size(MAX_MESSAGE_SIZE),
+        )
+        .add_service(
+            ActionCacheServer::new(service.clone()).max_decoding_message_size(MAX_MESSAGE_SIZE),
+        )
+        .add_service(
+            ContentAddressableStorageServer::new(service.clone())
+                .max_decoding_message_size(MAX_MESSAGE_SIZE)
+                .max_encoding_message_size(MAX_MESSAGE_SIZE),
+        )
+        .add_service(ByteStreamServer::new(service).max_decoding_message_size(MAX_MESSAGE_SIZE));
+
+        // gRPC routes are matched exactly, so they take precedence
+        // over the HTTP catch-all routes
+        let router = routes.into_axum_router().merge(create_http_router(
+            Arc::clone(&self.store),
+            self.max_upload_size,
+        ));
+
+        let result = Server::builder()
+            .accept_http1(true)
+            .add_routes(Routes::from(router))
+            .serve_with_incoming_shutdown(TcpIncoming::from(listener), shutdown_signal)
+            .await
+            .map_err(|error| CacheServerError::ServerFailed {
+                error: Box::new(error),
+            });
+
+        let _ = gc_stop_tx.send(());
+        let _ = gc_handle.await;
+
+        debug!("Stopped cache server");
+
+        result?;
+
+        Ok(())
+    }
+}
+
+async fn run_gc_loop(
+    store: Arc<CacheStore>,
+    interval: Duration,
+    mut stop_rx: oneshot::Receiver<()>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = &mut stop_rx => break,
+        };
+
+        match store.gc().await {
+            Ok(stats) => {
+                if stats.blobs_removed > 0 {
+                    debug!(
+                        blobs_removed = stats.blobs_removed,
+                        bytes_saved = stats.bytes_saved,
+                        "Cleaned stale cache entries"
+                    );
+                }
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to clean stale cache entries: {}",
+                    color::muted_light(error.to_string())
+                );
+            }
+        };
+    }
+}
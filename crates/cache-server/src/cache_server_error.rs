@@ -0,0 +1,29 @@
+use miette::Diagnostic;
+use moon_common::{Style, Stylize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Diagnostic)]
+pub enum CacheServerError {
+    #[diagnostic(code(cache_server::bind_failed))]
+    #[error("Failed to bind cache server to address {}.", .address.style(Style::Url))]
+    BindFailed {
+        address: String,
+        #[source]
+        error: Box<std::io::Error>,
+    },
+
+    #[diagnostic(code(cache_server::digest_mismatch))]
+    #[error(
+        "Uploaded blob does not match its digest: expected {}, computed {}.",
+        .expected.style(Style::Symbol),
+        .actual.style(Style::Symbol)
+    )]
+    DigestMismatch { expected: String, actual: String },
+
+    #[diagnostic(code(cache_server::server_failed))]
+    #[error("Cache server encountered an error.")]
+    ServerFailed {
+        #[source]
+        error: Box<tonic::transport::Error>,
+    },
+}
@@ -0,0 +1,274 @@
+use crate::cache_server_error::CacheServerError;
+use bazel_remote_apis::build::bazel::remote::execution::v2::ActionResult;
+use miette::IntoDiagnostic;
+use moon_blob::{Blob, BlobCleanStats};
+use moon_cas::CasStore;
+use moon_config::CacheCasConfig;
+use moon_hash::{ContentHash, Digest, InternalDigestExt};
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::task::spawn_blocking;
+use tracing::{debug, instrument};
+
+/// How long an unreferenced blob is spared from the reachability sweep, covering
+/// the window between a client uploading blobs and the action result that references them.
+const BLOB_GRACE: Duration = Duration::from_secs(3600);
+
+#[derive(Clone, Debug)]
+pub struct CacheStoreOptions {
+    /// Maximum total size of blobs referenced by action results. Blobs that are
+    /// not referenced yet, but are within the upload grace period, are not counted.
+    pub max_size: Option<u64>,
+
+    /// How long an action result is kept after it was last read or written.
+    pub lifetime: Duration,
+
+    /// Verify the hash of blobs when they are read.
+    pub verify_integrity: bool,
+}
+
+impl Default for CacheStoreOptions {
+    fn default() -> Self {
+        Self {
+            max_size: None,
+            lifetime: Duration::from_secs(7 * 24 * 60 * 60),
+            verify_integrity: false,
+        }
+    }
+}
+
+/// Storage for the cache server, split into an action cache (action results
+/// keyed by action digest) and a content-addressable store (blobs keyed by
+/// content hash), both backed by a [`CasStore`].
+#[derive(Debug)]
+pub struct CacheStore {
+    pub options: CacheStoreOptions,
+
+    action_results: Arc<CasStore>,
+    blobs: Arc<CasStore>,
+}
+
+impl CacheStore {
+    pub fn new(root: impl AsRef<Path>, options: CacheStoreOptions) -> miette::Result<Self> {
+        let root = root.as_ref();
+
+        debug!(root = ?root, "Creating cache server store");
+
+        Ok(Self {
+            action_results: Arc::new(CasStore::new(
+                root.join("ac"),
+                CacheCasConfig {
+                    // Action digests do not align with the result's contents
+                    verify_integrity: false,
+                    ..Default::default()
+                },
+            )?),
+            blobs: Arc::new(CasStore::new(
+                root.join("cas"),
+                CacheCasConfig {
+                    verify_integrity: options.verify_integrity,
+                    ..Default::default()
+                },
+            )?),
+            options,
+        })
+    }
+
+    // ---- Action cache ----
+
+    pub fn get_action_result(&self, hash: &ContentHash) -> miette::Result<Option<ActionResult>> {
+        if !self.action_results.contains_object(hash) {
+            return Ok(None);
+        }
+
+        let bytes = self.action_results.read(hash)?;
+        let result: ActionResult = serde_json::from_slice(&bytes).into_diagnostic()?;
+
+        // A result is only usable when all of its outputs still exist,
+        // otherwise the client would fail midway through restoring
+        if collect_result_digests(&result)
+            .iter()
+            .any(|digest| !self.blobs.contains_object(digest))
+        {
+            return Ok(None);
+        }
+
+        // Refresh the mtime so that GC evicts the least recently used results
+        let _ = self.action_results.touch(hash);
+
+        Ok(Some(result))
+    }
+
+    pub fn update_action_result(
+        &self,
+        hash: &ContentHash,
+        result: &ActionResult,
+    ) -> miette::Result<()> {
+        let bytes = serde_json::to_vec(result).into_diagnostic()?;
+
+        self.action_results.write(hash, &bytes)?;
+
+        Ok(())
+    }
+
+    // ---- Content-addressable storage ----
+
+    pub fn contains_blob(&self, hash: &ContentHash) -> bool {
+        self.blobs.contains_object(hash)
+    }
+
+    pub fn find_missing_blobs(&self, mut digests: Vec<Digest>) -> Vec<Digest> {
+        digests.retain(|digest| !self.blobs.contains_object(digest));
+        digests
+    }
+
+    pub fn read_blob(&self, hash: &ContentHash) -> miette::Result<Option<Vec<u8>>> {
+        if !self.blobs.contains_object(hash) {
+            return Ok(None);
+        }
+
+        let bytes = self.blobs.read(hash)?;
+
+        let _ = self.blobs.touch(hash);
+
+        Ok(Some(bytes))
+    }
+
+    /// Write a blob after verifying that its content matches the digest,
+    /// as clients are not trusted to upload the correct content.
+    pub fn write_blob(&self, digest: Digest, bytes: Vec<u8>) -> miette::Result<()> {
+        let actual = ContentHash::hash_bytes(&bytes)?;
+
+        if actual != digest.hash || digest.size != bytes.len() as i64 {
+            return Err(CacheServerError::DigestMismatch {
+                expected: digest.hash.to_string(),
+                actual: actual.to_string(),
+            }
+            .into());
+        }
+
+        self.blobs.store_blob(&Blob::new(digest, bytes))
+    }
+
+    // ---- Lifecycle ----
+
+    /// Evict action results that are stale or exceed the size limit, and then
+    /// remove all blobs that are no longer referenced by a remaining result.
+    #[instrument(skip(self))]
+    pub async fn gc(&self) -> miette::Result<BlobCleanStats> {
+        let action_results = Arc::clone(&self.action_results);
+        let lifetime = self.options.lifetime;
+        let max_size = self.options.max_size;
+
+        let (keep, removed, saved) =
+            spawn_blocking(move || evict_action_results(action_results, lifetime, max_size))
+                .await
+                .into_diagnostic()??;
+
+        let blob_stats = self.blobs.retain(Arc::new(keep), BLOB_GRACE).await?;
+
+        Ok(BlobCleanStats {
+            blobs_removed: removed + blob_stats.blobs_removed,
+            bytes_saved: saved + blob_stats.bytes_saved,
+        })
+    }
+}
+
+/// Collect the digests of all blobs an action result references.
+fn collect_result_digests(result: &ActionResult) -> Vec<Digest> {
+    result
+        .output_files
+        .iter()
+        .filter_map(|file| file.digest.clone())
+        .chain(result.stdout_digest.clone())
+        .chain(result.stderr_digest.clone())
+        .chain(
+            result
+                .output_directories
+                .iter()
+                .filter_map(|dir| dir.tree_digest.clone()),
+        )
+        .filter_map(|digest| Digest::from_external(digest).ok())
+        .collect()
+}
+
+/// Walk action results newest-first, keeping each one until its unique blobs
+/// would exceed the size limit, and evicting results older than the lifetime.
+/// Returns the blob hashes still referenced, plus `(removed, freed)`.
+fn evict_action_results(
+    action_results: Arc<CasStore>,
+    lifetime: Duration,
+    max_size: Option<u64>,
+) -> miette::Result<(FxHashSet<ContentHash>, usize, u64)> {
+    let now = SystemTime::now();
+
+    struct Entry {
+        path: PathBuf,
+        mtime: SystemTime,
+        file_size: u64,
+        digests: Vec<Digest>,
+    }
+
+    let mut entries = vec![];
+
+    for path in action_results.object_paths()? {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        let Ok(bytes) = fs::read(&path) else {
+            continue;
+        };
+
+        // Remove results that can't be parsed, as they can never be served
+        let Ok(result) = serde_json::from_slice::<ActionResult>(&bytes) else {
+            let _ = fs::remove_file(&path);
+            continue;
+        };
+
+        entries.push(Entry {
+            mtime: metadata.modified().unwrap_or(now),
+            file_size: metadata.len(),
+            digests: collect_result_digests(&result),
+            path,
+        });
+    }
+
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.mtime));
+
+    let mut keep = FxHashSet::default();
+    let mut keep_size: u64 = 0;
+    let mut removed = 0;
+    let mut saved = 0;
+
+    for entry in entries {
+        let age = now.duration_since(entry.mtime).unwrap_or_default();
+        let marginal: u64 = entry
+            .digests
+            .iter()
+            .filter(|digest| !keep.contains(&digest.hash))
+            .map(|digest| digest.size.max(0) as u64)
+            .sum();
+
+        let over_lifetime = age > lifetime;
+        let over_budget = max_size.is_some_and(|max| keep_size + marginal > max);
+
+        if over_lifetime || over_budget {
+            let _ = fs::remove_file(&entry.path);
+            removed += 1;
+            saved += entry.file_size;
+        } else {
+            for digest in entry.digests {
+                let size = digest.size.max(0) as u64;
+
+                if keep.insert(digest.hash) {
+                    keep_size += size;
+                }
+            }
+        }
+    }
+
+    Ok((keep, removed, saved))
+}
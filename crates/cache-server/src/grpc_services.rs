@@ -0,0 +1,422 @@
+use crate::cache_store::CacheStore;
+use bazel_remote_apis::build::bazel::remote::execution::v2::{
+    ActionResult, BatchReadBlobsRequest, BatchReadBlobsResponse, BatchUpdateBlobsRequest,
+    BatchUpdateBlobsResponse, Digest as BazelDigest, FindMissingBlobsRequest,
+    FindMissingBlobsResponse, GetActionResultRequest, GetCapabilitiesRequest, GetTreeRequest,
+    GetTreeResponse, ServerCapabilities, SpliceBlobRequest, SpliceBlobResponse, SplitBlobRequest,
+    SplitBlobResponse, UpdateActionResultRequest, action_cache_server::ActionCache,
+    batch_read_blobs_response, batch_update_blobs_response, capabilities_server::Capabilities,
+    content_addressable_storage_server::ContentAddressableStorage,
+};
+use bazel_remote_apis::build::bazel::semver::SemVer;
+use bazel_remote_apis::google::bytestream::{
+    QueryWriteStatusRequest, QueryWriteStatusResponse, ReadRequest, ReadResponse, WriteRequest,
+    WriteResponse, byte_stream_server::ByteStream,
+};
+use bazel_remote_apis::google::rpc::Status as RpcStatus;
+use moon_cache_storage::{CacheCapabilities, Compressor, DigestFunction};
+use moon_hash::{Digest, InternalDigestExt};
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::task::spawn_blocking;
+use tonic::codegen::tokio_stream::{self, Stream};
+use tonic::{Code, Request, Response, Status, Streaming};
+use tracing::{trace, warn};
+
+/// Maximum size of a batch request, matching what most remote caches advertise.
+pub const MAX_BATCH_TOTAL_SIZE: usize = 4 * 1024 * 1024;
+
+/// Size of each chunk when streaming a blob to the client.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Implements the Bazel remote execution API services that are required
+/// for caching: Capabilities, ActionCache, ContentAddressableStorage,
+/// and ByteStream. Instance names are accepted but not partitioned.
+#[derive(Clone)]
+pub struct CacheService {
+    store: Arc<CacheStore>,
+    max_upload_size: u64,
+}
+
+impl CacheService {
+    pub fn new(store: Arc<CacheStore>, max_upload_size: u64) -> Self {
+        Self {
+            store,
+            max_upload_size,
+        }
+    }
+
+    async fn run_blocking<T, F>(&self, op: F) -> Result<T, Status>
+    where
+        T: Send + 'static,
+        F: FnOnce(&CacheStore) -> miette::Result<T> + Send + 'static,
+    {
+        let store = Arc::clone(&self.store);
+
+        spawn_blocking(move || op(&store))
+            .await
+            .map_err(|error| Status::internal(error.to_string()))?
+            .map_err(|error| Status::internal(error.to_string()))
+    }
+}
+
+fn parse_digest(digest: Option<BazelDigest>) -> Result<Digest, Status> {
+    digest
+        .ok_or_else(|| Status::invalid_argument("Missing digest."))
+        .and_then(|digest| {
+            Digest::from_external(digest)
+                .map_err(|error| Status::invalid_argument(error.to_string()))
+        })
+}
+
+/// Extract the digest from a ByteStream resource name. Reads use the form
+/// `{instance}/blobs/{hash}/{size}` and writes use the form
+/// `{instance}/uploads/{uuid}/blobs/{hash}/{size}`.
+fn parse_resource_name(name: &str) -> Result<Digest, Status> {
+    let parts = name.split('/').collect::<Vec<_>>();
+
+    if parts.contains(&"compressed-blobs") {
+        return Err(Status::invalid_argument(
+            "Compressed blobs are not supported.",
+        ));
+    }
+
+    let index = parts
+        .iter()
+        .position(|part| *part == "blobs")
+        .ok_or_else(|| Status::invalid_argument(format!("Invalid resource name {name}.")))?;
+
+    let (Some(hash), Some(size)) = (parts.get(index + 1), parts.get(index + 2)) else {
+        return Err(Status::invalid_argument(format!(
+            "Invalid resource name {name}."
+        )));
+    };
+
+    parse_digest(Some(BazelDigest {
+        hash: hash.to_string(),
+        size_bytes: size
+            .parse()
+            .map_err(|_| Status::invalid_argument(format!("Invalid blob size {size}.")))?,
+    }))
+}
+
+#[tonic::async_trait]
+impl Capabilities for CacheService {
+    async fn get_capabilities(
+        &self,
+        _request: Request<GetCapabilitiesRequest>,
+    ) -> Result<Response<ServerCapabilities>, Status> {
+        let capabilities = CacheCapabilities {
+            digest_functions: vec![DigestFunction::Sha256],
+            max_batch_total_size_bytes: MAX_BATCH_TOTAL_SIZE,
+            store_manifests: true,
+            supported_batch_update_compressors: vec![Compressor::Identity],
+            supported_compressors: vec![Compressor::Identity],
+        };
+
+        Ok(Response::new(ServerCapabilities {
+            cache_capabilities: Some(capabilities.into_bazel_capabilities()),
+            low_api_version: Some(SemVer {
+                major: 2,
+                ..Default::default()
+            }),
+            high_api_version: Some(SemVer {
+                major: 2,
+                minor: 3,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+    }
+}
+
+#[tonic::async_trait]
+impl ActionCache for CacheService {
+    async fn get_action_result(
+        &self,
+        request: Request<GetActionResultRequest>,
+    ) -> Result<Response<ActionResult>, Status> {
+        let digest = parse_digest(request.into_inner().action_digest)?;
+
+        trace!(hash = digest.hash.as_str(), "Getting action result");
+
+        match self
+            .run_blocking(move |store| store.get_action_result(&digest.hash))
+            .await?
+        {
+            Some(result) => Ok(Response::new(result)),
+            None => Err(Status::not_found("Action result not found.")),
+        }
+    }
+
+    async fn update_action_result(
+        &self,
+        request: Request<UpdateActionResultRequest>,
+    ) -> Result<Response<ActionResult>, Status> {
+        let request = request.into_inner();
+        let digest = parse_digest(request.action_digest)?;
+        let result = request
+            .action_result
+            .ok_or_else(|| Status::invalid_argument("Missing action result."))?;
+
+        trace!(hash = digest.hash.as_str(), "Updating action result");
+
+        self.run_blocking(move |store| {
+            store.update_action_result(&digest.hash, &result)?;
+
+            Ok(result)
+        })
+        .await
+        .map(Response::new)
+    }
+}
+
+#[tonic::async_trait]
+impl ContentAddressableStorage for CacheService {
+    type GetTreeStream = Pin<Box<dyn Stream<Item = Result<GetTreeResponse, Status>> + Send>>;
+
+    async fn find_missing_blobs(
+        &self,
+        request: Request<FindMissingBlobsRequest>,
+    ) -> Result<Response<FindMissingBlobsResponse>, Status> {
+        let digests = request
+            .into_inner()
+            .blob_digests
+            .into_iter()
+            .map(|digest| parse_digest(Some(digest)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let missing = self
+            .run_blocking(move |store| Ok(store.find_missing_blobs(digests)))
+            .await?;
+
+        Ok(Response::new(FindMissingBlobsResponse {
+            missing_blob_digests: missing
+                .into_iter()
+                .map(|digest| digest.into_external_digest())
+                .collect(),
+        }))
+    }
+
+    async fn batch_update_blobs(
+        &self,
+        request: Request<BatchUpdateBlobsRequest>,
+    ) -> Result<Response<BatchUpdateBlobsResponse>, Status> {
+        let requests = request.into_inner().requests;
+
+        let responses = self
+            .run_blocking(move |store| {
+                let mut responses = vec![];
+
+                for request in requests {
+                    let status = if request.compressor != Compressor::Identity as i32 {
+                        Some(RpcStatus {
+                            code: Code::InvalidArgument as i32,
+                            message: "Compressed blobs are not supported.".into(),
+                            details: vec![],
+                        })
+                    } else {
+                        match parse_digest(request.digest.clone()) {
+                            Ok(digest) => match store.write_blob(digest, request.data) {
+                                Ok(_) => None,
+                                Err(error) => Some(RpcStatus {
+                                    code: Code::InvalidArgument as i32,
+                                    message: error.to_string(),
+                                    details: vec![],
+                                }),
+                            },
+                            Err(status) => Some(RpcStatus {
+                                code: status.code() as i32,
+                                message: status.message().to_owned(),
+                                details: vec![],
+                            }),
+                        }
+                    };
+
+                    responses.push(batch_update_blobs_response::Response {
+                        digest: request.digest,
+                        status,
+                    });
+                }
+
+                Ok(responses)
+            })
+            .await?;
+
+        Ok(Response::new(BatchUpdateBlobsResponse { responses }))
+    }
+
+    async fn batch_read_blobs(
+        &self,
+        request: Request<BatchReadBlobsRequest>,
+    ) -> Result<Response<BatchReadBlobsResponse>, Status> {
+        let digests = request.into_inner().digests;
+
+        let responses = self
+            .run_blocking(move |store| {
+                let mut responses = vec![];
+
+                for external in digests {
+                    let (data, status) =
+                        match parse_digest(Some(external.clone())).and_then(|digest| {
+                            store
+                                .read_blob(&digest.hash)
+                                .map_err(|error| Status::internal(error.to_string()))
+                        }) {
+                            Ok(Some(data)) => (data, None),
+                            Ok(None) => (
+                                vec![],
+                                Some(RpcStatus {
+                                    code: Code::NotFound as i32,
+                                    message: "Blob not found.".into(),
+                                    details: vec![],
+                                }),
+                            ),
+                            Err(status) => (
+                                vec![],
+                                Some(RpcStatus {
+                                    code: status.code() as i32,
+                                    message: status.message().to_owned(),
+                                    details: vec![],
+                                }),
+                            ),
+                        };
+
+                    responses.push(batch_read_blobs_response::Response {
+                        digest: Some(external),
+                        data,
+                        compressor: Compressor::Identity as i32,
+                        status,
+                    });
+                }
+
+                Ok(responses)
+            })
+            .await?;
+
+        Ok(Response::new(BatchReadBlobsResponse { responses }))
+    }
+
+    async fn get_tree(
+        &self,
+        _request: Request<GetTreeRequest>,
+    ) -> Result<Response<Self::GetTreeStream>, Status> {
+        Err(Status::unimplemented("Directory trees are not supported."))
+    }
+
+    async fn split_blob(
+        &self,
+        _request: Request<SplitBlobRequest>,
+    ) -> Result<Response<SplitBlobResponse>, Status> {
+        Err(Status::unimplemented("Splitting blobs is not supported."))
+    }
+
+    async fn splice_blob(
+        &self,
+        _request: Request<SpliceBlobRequest>,
+    ) -> Result<Response<SpliceBlobResponse>, Status> {
+        Err(Status::unimplemented("Splicing blobs is not supported."))
+    }
+}
+
+#[tonic::async_trait]
+impl ByteStream for CacheService {
+    type ReadStream = Pin<Box<dyn Stream<Item = Result<ReadResponse, Status>> + Send>>;
+
+    async fn read(
+        &self,
+        request: Request<ReadRequest>,
+    ) -> Result<Response<Self::ReadStream>, Status> {
+        let request = request.into_inner();
+        let digest = parse_resource_name(&request.resource_name)?;
+
+        let Some(data) = self
+            .run_blocking(move |store| store.read_blob(&digest.hash))
+            .await?
+        else {
+            return Err(Status::not_found("Blob not found."));
+        };
+
+        let offset = (request.read_offset.max(0) as usize).min(data.len());
+        let limit = if request.read_limit > 0 {
+            (offset + request.read_limit as usize).min(data.len())
+        } else {
+            data.len()
+        };
+
+        let chunks = data[offset..limit]
+            .chunks(READ_CHUNK_SIZE)
+            .map(|chunk| {
+                Ok(ReadResponse {
+                    data: chunk.to_vec(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Response::new(Box::pin(tokio_stream::iter(chunks))))
+    }
+
+    async fn write(
+        &self,
+        request: Request<Streaming<WriteRequest>>,
+    ) -> Result<Response<WriteResponse>, Status> {
+        let mut stream = request.into_inner();
+
+        // The resource name is only required on the first request, and
+        // declares the size of the blob, so parse it before buffering
+        let Some(first) = stream.message().await? else {
+            return Err(Status::invalid_argument("Missing write request."));
+        };
+
+        let digest = parse_resource_name(&first.resource_name)?;
+        let expected_size = digest.size.max(0) as u64;
+
+        if expected_size > self.max_upload_size {
+            return Err(Status::resource_exhausted(format!(
+                "Blob of {expected_size} bytes exceeds the maximum upload size of {} bytes.",
+                self.max_upload_size
+            )));
+        }
+
+        let mut data = Vec::with_capacity(expected_size as usize);
+        let mut chunk = first;
+
+        loop {
+            if (data.len() + chunk.data.len()) as u64 > expected_size {
+                return Err(Status::invalid_argument(format!(
+                    "Uploaded data exceeds the declared size of {expected_size} bytes."
+                )));
+            }
+
+            data.extend(chunk.data);
+
+            if chunk.finish_write {
+                break;
+            }
+
+            match stream.message().await? {
+                Some(next) => chunk = next,
+                None => break,
+            };
+        }
+
+        let committed_size = data.len() as i64;
+
+        self.run_blocking(move |store| store.write_blob(digest, data))
+            .await
+            .map_err(|status| {
+                warn!("Failed to write streamed blob: {}", status.message());
+
+                Status::invalid_argument(status.message())
+            })?;
+
+        Ok(Response::new(WriteResponse { committed_size }))
+    }
+
+    async fn query_write_status(
+        &self,
+        _request: Request<QueryWriteStatusRequest>,
+    ) -> Result<Response<QueryWriteStatusResponse>, Status> {
+        Err(Status::unimplemented("Resumable writes are not supported."))
+    }
+}
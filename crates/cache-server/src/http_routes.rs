@@ -0,0 +1,127 @@
+use crate::cache_store::CacheStore;
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::{DefaultBodyLimit, Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use bazel_remote_apis::build::bazel::remote::execution::v2::ActionResult;
+use miette::IntoDiagnostic;
+use moon_hash::{ContentHash, Digest};
+use std::sync::Arc;
+use tokio::task::spawn_blocking;
+use tracing::trace;
+
+// Mirrors the HTTP protocol of bazel-remote, where action results are
+// served at `/{instance}/ac/{hash}` (as JSON for moon clients) and blobs
+// at `/{instance}/cas/{hash}`. The instance name may be empty or contain
+// slashes, so the path is matched as a whole and split from the end.
+
+type SharedStore = Arc<CacheStore>;
+
+pub fn create_http_router(store: SharedStore, max_upload_size: u64) -> Router {
+    Router::new()
+        .route("/status", get(status))
+        .route("/{*path}", get(get_entry).put(put_entry))
+        // Blobs can be much larger than the default limit of 2MB
+        .layer(DefaultBodyLimit::max(
+            usize::try_from(max_upload_size).unwrap_or(usize::MAX),
+        ))
+        .with_state(store)
+}
+
+enum Entry {
+    ActionResult(ContentHash),
+    Blob(ContentHash),
+}
+
+fn parse_path(path: &str) -> Option<Entry> {
+    let (rest, hash) = path.rsplit_once('/')?;
+    let kind = rest.rsplit('/').next()?;
+    let hash = ContentHash::from_hex(hash).ok()?;
+
+    match kind {
+        "ac" => Some(Entry::ActionResult(hash)),
+        "cas" => Some(Entry::Blob(hash)),
+        _ => None,
+    }
+}
+
+fn error_response(error: impl ToString) -> Response {
+    (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+}
+
+async fn status() -> impl IntoResponse {
+    StatusCode::OK
+}
+
+async fn get_entry(State(store): State<SharedStore>, Path(path): Path<String>) -> Response {
+    let Some(entry) = parse_path(&path) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let result = spawn_blocking(move || match entry {
+        Entry::ActionResult(hash) => {
+            trace!(hash = hash.as_str(), "Getting action result");
+
+            store.get_action_result(&hash).and_then(|result| {
+                result
+                    .map(|result| serde_json::to_vec(&result).into_diagnostic())
+                    .transpose()
+                    .map(|bytes| bytes.map(|bytes| (bytes, "application/json")))
+            })
+        }
+        Entry::Blob(hash) => store
+            .read_blob(&hash)
+            .map(|bytes| bytes.map(|bytes| (bytes, "application/octet-stream"))),
+    })
+    .await;
+
+    match result {
+        Ok(Ok(Some((bytes, content_type)))) => {
+            ([(header::CONTENT_TYPE, content_type)], bytes).into_response()
+        }
+        Ok(Ok(None)) => StatusCode::NOT_FOUND.into_response(),
+        Ok(Err(error)) => error_response(error),
+        Err(error) => error_response(error),
+    }
+}
+
+async fn put_entry(
+    State(store): State<SharedStore>,
+    Path(path): Path<String>,
+    body: Bytes,
+) -> Response {
+    let Some(entry) = parse_path(&path) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let result = spawn_blocking(move || match entry {
+        Entry::ActionResult(hash) => {
+            trace!(hash = hash.as_str(), "Updating action result");
+
+            let result: ActionResult = serde_json::from_slice(&body)
+                .map_err(|error| (StatusCode::BAD_REQUEST, error.to_string()))?;
+
+            store
+                .update_action_result(&hash, &result)
+                .map_err(|error| (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()))
+        }
+        Entry::Blob(hash) => store
+            .write_blob(
+                Digest {
+                    size: body.len() as i64,
+                    hash,
+                },
+                body.to_vec(),
+            )
+            .map_err(|error| (StatusCode::BAD_REQUEST, error.to_string())),
+    })
+    .await;
+
+    match result {
+        Ok(Ok(_)) => StatusCode::OK.into_response(),
+        Ok(Err(error)) => error.into_response(),
+        Err(error) => error_response(error),
+    }
+}
@@ -0,0 +1,11 @@
+mod cache_server;
+mod cache_server_error;
+mod cache_store;
+mod grpc_services;
+mod http_routes;
+
+pub use cache_server::*;
+pub use cache_server_error::*;
+pub use cache_store::*;
+pub use grpc_services::*;
+pub use http_routes::*;
@@ -0,0 +1,415 @@
+use moon_blob::{BlobContent, BlobInput, Bytes};
+use moon_cache_remote::{GrpcRemoteStorage, HttpRemoteStorage};
+use moon_cache_server::{CacheServer, CacheStore, CacheStoreOptions};
+use moon_cache_storage::{CacheContext, Manifest, ManifestFile, StorageBackend};
+use moon_config::RemoteConfig;
+use moon_hash::Digest;
+use starbase_sandbox::{Sandbox, create_empty_sandbox};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_server(sandbox: &Sandbox) -> CacheServer {
+    CacheServer::new(
+        CacheStore::new(sandbox.path().join("server"), CacheStoreOptions::default()).unwrap(),
+    )
+}
+
+async fn spawn_server(server: CacheServer) -> (String, Arc<CacheStore>) {
+    let store = server.get_store();
+    let listener = CacheServer::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        server
+            .serve(listener, std::future::pending())
+            .await
+            .unwrap();
+    });
+
+    (address.to_string(), store)
+}
+
+fn create_context(sandbox: &Sandbox, host: String) -> CacheContext {
+    let mut remote = RemoteConfig {
+        host: Some(host),
+        ..Default::default()
+    };
+    remote.cache.instance_name = "moon-test".to_owned();
+
+    let mut context = CacheContext::new(&sandbox.path().join("client"));
+    context.remote_config = Arc::new(remote);
+    context
+}
+
+async fn connect_grpc(sandbox: &Sandbox) -> (GrpcRemoteStorage, Arc<CacheStore>) {
+    let (address, store) = spawn_server(create_server(sandbox)).await;
+    let storage =
+        GrpcRemoteStorage::new(create_context(sandbox, format!("grpc://{address}"))).unwrap();
+
+    storage.connect().await.unwrap();
+
+    (storage, store)
+}
+
+async fn connect_http(sandbox: &Sandbox) -> (HttpRemoteStorage, String) {
+    let (address, _) = spawn_server(create_server(sandbox)).await;
+    let host = format!("http://{address}");
+    let storage = HttpRemoteStorage::new(create_context(sandbox, host.clone())).unwrap();
+
+    storage.connect().await.unwrap();
+
+    (storage, host)
+}
+
+fn digest_of(bytes: &[u8]) -> Digest {
+    Digest::from_bytes(bytes).unwrap()
+}
+
+fn inline(content: &'static [u8]) -> BlobInput {
+    BlobInput {
+        content: BlobContent::Inline(Bytes::from_static(content)),
+        digest: digest_of(content),
+    }
+}
+
+fn create_manifest(content: &[u8]) -> Manifest {
+    Manifest {
+        exit_code: 3,
+        files: vec![ManifestFile {
+            digest: Some(digest_of(content)),
+            path: "out/file.txt".into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+mod cache_server {
+    use super::*;
+
+    mod grpc {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn advertises_capabilities() {
+            let sandbox = create_empty_sandbox();
+            let (storage, _) = connect_grpc(&sandbox).await;
+
+            assert!(storage.is_readable());
+            assert_eq!(
+                storage.get_capabilities().max_batch_total_size_bytes,
+                4 * 1024 * 1024
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn round_trips_blobs_and_manifests() {
+            let sandbox = create_empty_sandbox();
+            let (storage, _) = connect_grpc(&sandbox).await;
+            let digest = digest_of(b"output");
+
+            assert_eq!(
+                storage
+                    .find_missing_blobs(vec![digest.clone()])
+                    .await
+                    .unwrap(),
+                vec![digest.clone()]
+            );
+
+            storage
+                .store_blobs(vec![inline(b"output")], false)
+                .await
+                .unwrap();
+
+            assert!(
+                storage
+                    .find_missing_blobs(vec![digest.clone()])
+                    .await
+                    .unwrap()
+                    .is_empty()
+            );
+
+            storage
+                .store_manifest(digest_of(b"action"), create_manifest(b"output"))
+                .await
+                .unwrap();
+
+            let manifest = storage
+                .retrieve_manifest(digest_of(b"action"))
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(manifest.exit_code, 3);
+            assert_eq!(manifest.files[0].digest.as_ref(), Some(&digest));
+
+            let blobs = storage.retrieve_blobs(vec![digest], false).await.unwrap();
+
+            assert_eq!(blobs[0].content.get_bytes().unwrap(), b"output".as_slice());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn round_trips_streamed_blobs() {
+            let sandbox = create_empty_sandbox();
+            let (storage, _) = connect_grpc(&sandbox).await;
+            let digest = digest_of(b"streamed output");
+
+            let stored = storage
+                .store_blobs(vec![inline(b"streamed output")], true)
+                .await
+                .unwrap();
+
+            assert_eq!(stored, vec![digest.clone()]);
+
+            let blobs = storage.retrieve_blobs(vec![digest], true).await.unwrap();
+
+            assert_eq!(
+                blobs[0].content.get_bytes().unwrap(),
+                b"streamed output".as_slice()
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn misses_manifest_when_outputs_are_missing() {
+            let sandbox = create_empty_sandbox();
+            let (storage, _) = connect_grpc(&sandbox).await;
+
+            storage
+                .store_manifest(digest_of(b"action"), create_manifest(b"never uploaded"))
+                .await
+                .unwrap();
+
+            assert!(
+                storage
+                    .retrieve_manifest(digest_of(b"action"))
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn rejects_blobs_that_dont_match_digest() {
+            let sandbox = create_empty_sandbox();
+            let (storage, store) = connect_grpc(&sandbox).await;
+
+            let stored = storage
+                .store_blobs(
+                    vec![BlobInput {
+                        content: BlobContent::Inline(Bytes::from_static(b"tampered")),
+                        digest: digest_of(b"original"),
+                    }],
+                    false,
+                )
+                .await
+                .unwrap();
+
+            assert!(stored.is_empty());
+            assert!(!store.contains_blob(&digest_of(b"original").hash));
+        }
+    }
+
+    mod http {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn round_trips_blobs_and_manifests() {
+            let sandbox = create_empty_sandbox();
+            let (storage, _) = connect_http(&sandbox).await;
+            let digest = digest_of(b"output");
+
+            storage
+                .store_blobs(vec![inline(b"output")], false)
+                .await
+                .unwrap();
+
+            storage
+                .store_manifest(digest_of(b"action"), create_manifest(b"output"))
+                .await
+                .unwrap();
+
+            let manifest = storage
+                .retrieve_manifest(digest_of(b"action"))
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(manifest.exit_code, 3);
+
+            let blobs = storage.retrieve_blobs(vec![digest], false).await.unwrap();
+
+            assert_eq!(blobs[0].content.get_bytes().unwrap(), b"output".as_slice());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn returns_404_for_unknown_entries() {
+            let sandbox = create_empty_sandbox();
+            let (storage, host) = connect_http(&sandbox).await;
+
+            assert!(
+                storage
+                    .retrieve_manifest(digest_of(b"missing"))
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+
+            let response = reqwest::get(format!("{host}//cas/{}", digest_of(b"missing").hash))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status().as_u16(), 404);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn rejects_blobs_that_dont_match_digest() {
+            let sandbox = create_empty_sandbox();
+            let (_, host) = connect_http(&sandbox).await;
+
+            let response = reqwest::Client::new()
+                .put(format!(
+                    "{host}/moon-test/cas/{}",
+                    digest_of(b"original").hash
+                ))
+                .body("tampered")
+                .send()
+                .await
+                .unwrap();
+
+            assert_eq!(response.status().as_u16(), 400);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn rejects_blobs_over_upload_size() {
+            let sandbox = create_empty_sandbox();
+            let mut server = create_server(&sandbox);
+            server.max_upload_size = 4;
+
+            let (address, store) = spawn_server(server).await;
+
+            let response = reqwest::Client::new()
+                .put(format!(
+                    "http://{address}/moon-test/cas/{}",
+                    digest_of(b"too large").hash
+                ))
+                .body("too large")
+                .send()
+                .await
+                .unwrap();
+
+            assert_eq!(response.status().as_u16(), 413);
+            assert!(
+                store
+                    .read_blob(&digest_of(b"too large").hash)
+                    .unwrap()
+                    .is_none()
+            );
+        }
+    }
+
+    mod gc {
+        use super::*;
+
+        fn create_store(sandbox: &Sandbox, options: CacheStoreOptions) -> CacheStore {
+            CacheStore::new(sandbox.path(), options).unwrap()
+        }
+
+        fn store_entry(store: &CacheStore, action: &[u8], content: &'static [u8]) {
+            store
+                .write_blob(digest_of(content), content.to_vec())
+                .unwrap();
+            store
+                .update_action_result(
+                    &digest_of(action).hash,
+                    &create_manifest(content).into_bazel_action_result(false),
+                )
+                .unwrap();
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn keeps_entries_within_limits() {
+            let sandbox = create_empty_sandbox();
+            let store = create_store(&sandbox, CacheStoreOptions::default());
+
+            store_entry(&store, b"a", b"aaaa");
+            store_entry(&store, b"b", b"bbbb");
+
+            let stats = store.gc().await.unwrap();
+
+            assert_eq!(stats.blobs_removed, 0);
+            assert!(
+                store
+                    .get_action_result(&digest_of(b"a").hash)
+                    .unwrap()
+                    .is_some()
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn evicts_stale_entries() {
+            let sandbox = create_empty_sandbox();
+            let store = create_store(
+                &sandbox,
+                CacheStoreOptions {
+                    lifetime: Duration::ZERO,
+                    ..Default::default()
+                },
+            );
+
+            store_entry(&store, b"a", b"aaaa");
+
+            std::thread::sleep(Duration::from_millis(10));
+
+            let stats = store.gc().await.unwrap();
+
+            // Blobs are protected by a grace period
+            assert_eq!(stats.blobs_removed, 1);
+            assert!(
+                store
+                    .get_action_result(&digest_of(b"a").hash)
+                    .unwrap()
+                    .is_none()
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn evicts_least_recently_used_entries_over_size() {
+            let sandbox = create_empty_sandbox();
+            let store = create_store(
+                &sandbox,
+                CacheStoreOptions {
+                    max_size: Some(6),
+                    ..Default::default()
+                },
+            );
+
+            store_entry(&store, b"a", b"aaaa");
+            std::thread::sleep(Duration::from_millis(20));
+            store_entry(&store, b"b", b"bbbb");
+            std::thread::sleep(Duration::from_millis(20));
+
+            // Reading refreshes the entry, making `b` the oldest
+            assert!(
+                store
+                    .get_action_result(&digest_of(b"a").hash)
+                    .unwrap()
+                    .is_some()
+            );
+
+            store.gc().await.unwrap();
+
+            assert!(
+                store
+                    .get_action_result(&digest_of(b"a").hash)
+                    .unwrap()
+                    .is_some()
+            );
+            assert!(
+                store
+                    .get_action_result(&digest_of(b"b").hash)
+                    .unwrap()
+                    .is_none()
+            );
+        }
+    }
+}
@@ -1,10 +1,12 @@
 mod capabilities;
 mod helpers;
+mod metrics;
 mod storage;
 mod storage_backend;
 
 pub use capabilities::*;
 pub use helpers::*;
+pub use metrics::*;
 pub use storage::*;
 pub use storage_backend::*;
 
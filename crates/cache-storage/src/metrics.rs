@@ -0,0 +1,146 @@
+use crate::storage_backend::{RetrieveResult, StoreResult};
+use moon_common::Id;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Read and write activity of a single storage backend.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageBackendMetrics {
+    /// Whether the backend is a remote cache.
+    pub remote: bool,
+
+    /// Manifests that were found in the backend.
+    pub hits: u64,
+
+    /// Manifests that were not found in the backend.
+    pub misses: u64,
+
+    /// Blobs retrieved from the backend.
+    pub blobs_read: u64,
+
+    /// Blobs that did not already exist and were stored in the backend.
+    pub blobs_written: u64,
+
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+
+    /// Failed requests, including partially failed batches.
+    pub errors: u64,
+
+    pub read_requests: u64,
+    pub read_duration: Duration,
+    pub write_requests: u64,
+    pub write_duration: Duration,
+}
+
+impl StorageBackendMetrics {
+    /// Average time spent per read and write request.
+    pub fn get_average_latency(&self) -> Duration {
+        let requests = self.read_requests + self.write_requests;
+
+        if requests == 0 {
+            return Duration::ZERO;
+        }
+
+        (self.read_duration + self.write_duration) / requests as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read_requests == 0 && self.write_requests == 0
+    }
+}
+
+/// Aggregated metrics for all storage backends, shared between
+/// a storage instance and all of its derivatives.
+#[derive(Clone, Debug, Default)]
+pub struct StorageMetrics {
+    backends: Arc<Mutex<BTreeMap<Id, StorageBackendMetrics>>>,
+}
+
+impl StorageMetrics {
+    pub fn register(&self, id: &Id, remote: bool) {
+        self.update(id, |metrics| {
+            metrics.remote = remote;
+        });
+    }
+
+    pub fn record_manifest_lookup(&self, id: &Id, hit: bool, duration: Duration) {
+        self.update(id, |metrics| {
+            if hit {
+                metrics.hits += 1;
+            } else {
+                metrics.misses += 1;
+            }
+
+            metrics.read_requests += 1;
+            metrics.read_duration += duration;
+        });
+    }
+
+    pub fn record_manifest_store(&self, id: &Id, success: bool, duration: Duration) {
+        self.update(id, |metrics| {
+            if !success {
+                metrics.errors += 1;
+            }
+
+            metrics.write_requests += 1;
+            metrics.write_duration += duration;
+        });
+    }
+
+    pub fn record_read(&self, id: &Id, result: &RetrieveResult, duration: Duration) {
+        self.update(id, |metrics| {
+            metrics.blobs_read += result.retrieved_count as u64;
+            metrics.bytes_read += result
+                .blobs
+                .iter()
+                .map(|blob| blob.digest.size.max(0) as u64)
+                .sum::<u64>();
+            metrics.errors += result.error_count as u64;
+            metrics.read_requests += 1;
+            metrics.read_duration += duration;
+        });
+    }
+
+    pub fn record_write(&self, id: &Id, result: &StoreResult, duration: Duration) {
+        self.update(id, |metrics| {
+            // When nothing was missing, the digests are those that already
+            // existed in the backend, so nothing was actually transferred
+            if result.missing_count > 0 {
+                metrics.blobs_written += result.stored_count as u64;
+                metrics.bytes_written += result
+                    .digests
+                    .iter()
+                    .map(|digest| digest.size.max(0) as u64)
+                    .sum::<u64>();
+            }
+
+            metrics.errors += result.error_count as u64;
+            metrics.write_requests += 1;
+            metrics.write_duration += duration;
+        });
+    }
+
+    pub fn record_error(&self, id: &Id) {
+        self.update(id, |metrics| {
+            metrics.errors += 1;
+        });
+    }
+
+    /// Return a snapshot of the current metrics, keyed by backend.
+    pub fn get_snapshot(&self) -> BTreeMap<Id, StorageBackendMetrics> {
+        self.backends
+            .lock()
+            .map(|backends| backends.clone())
+            .unwrap_or_default()
+    }
+
+    fn update(&self, id: &Id, op: impl FnOnce(&mut StorageBackendMetrics)) {
+        if let Ok(mut backends) = self.backends.lock() {
+            op(backends.entry(id.to_owned()).or_default());
+        }
+    }
+}
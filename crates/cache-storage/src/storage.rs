@@ -1,3 +1,4 @@
+use crate::metrics::{StorageBackendMetrics, StorageMetrics};
 use crate::storage_backend::{BoxedStorageBackend, StorageBackend};
 use miette::IntoDiagnostic;
 use moon_blob::{BlobCleanStats, BlobContent, BlobInput, BlobOutput};
@@ -6,10 +7,10 @@ use moon_config::{CacheConfig, RemoteConfig};
 use moon_hash::Digest;
 use moon_manifest::Manifest;
 use rustc_hash::FxHashMap;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::Mutex;
 use tokio::task::{AbortHandle, JoinHandle, JoinSet};
 use tracing::{debug, warn};
@@ -73,6 +74,7 @@ pub struct Storage {
     background_tasks: Arc<Mutex<Vec<JoinHandle<miette::Result<()>>>>>,
     local_backends: Vec<BoxedStorageBackend>,
     remote_backends: Vec<BoxedStorageBackend>,
+    metrics: StorageMetrics,
 
     context: CacheContext,
     options: StorageOptions,
@@ -84,6 +86,7 @@ impl Storage {
             background_tasks: Arc::new(Mutex::new(vec![])),
             local_backends: vec![],
             remote_backends: vec![],
+            metrics: StorageMetrics::default(),
             context,
             options: StorageOptions::default(),
         }
@@ -94,16 +97,19 @@ impl Storage {
             background_tasks: Arc::clone(&self.background_tasks),
             local_backends: self.local_backends.clone(),
             remote_backends: self.remote_backends.clone(),
+            metrics: self.metrics.clone(),
             context: self.context.clone(),
             options,
         }
     }
 
     pub fn add_local_backend(&mut self, backend: impl StorageBackend + 'static) {
+        self.metrics.register(backend.get_id(), false);
         self.local_backends.push(Arc::new(backend));
     }
 
     pub fn add_remote_backend(&mut self, backend: impl StorageBackend + 'static) {
+        self.metrics.register(backend.get_id(), true);
         self.remote_backends.push(Arc::new(backend));
     }
 
@@ -158,6 +164,13 @@ impl Storage {
         backends
     }
 
+    /// Return read and write metrics for every backend, accumulated
+    /// since the storage was created. Writes that are still queued in
+    /// the background are not included until they complete.
+    pub fn get_metrics(&self) -> BTreeMap<Id, StorageBackendMetrics> {
+        self.metrics.get_snapshot()
+    }
+
     pub fn get_local_backends(&self) -> Vec<&BoxedStorageBackend> {
         self.get_backends_with_options(&StorageOptions {
             // Respect previously configured options
@@ -227,9 +240,14 @@ impl Storage {
                 continue;
             }
 
+            let started = Instant::now();
             let result = Arc::clone(backend)
                 .retrieve_blobs_batched(Digest::default(), digests.clone())
-                .await?;
+                .await
+                .inspect_err(|_| self.metrics.record_error(backend.get_id()))?;
+
+            self.metrics
+                .record_read(backend.get_id(), &result, started.elapsed());
 
             if !result.blobs.is_empty() {
                 return Ok(result.blobs);
@@ -253,12 +271,18 @@ impl Storage {
 
             let backend = Arc::clone(backend);
             let blobs = blobs.clone();
+            let metrics = self.metrics.clone();
 
             background_tasks.push(tokio::spawn(Box::pin(async move {
-                backend
+                let id = backend.get_id().to_owned();
+                let started = Instant::now();
+                let result = backend
                     .store_blobs_batched(Digest::default(), blobs)
-                    .await
-                    .map(|_| ())
+                    .await?;
+
+                metrics.record_write(&id, &result, started.elapsed());
+
+                Ok(())
             })));
         }
 
@@ -273,7 +297,19 @@ impl Storage {
                 continue;
             }
 
-            if let Some(manifest) = backend.retrieve_manifest(digest.to_owned()).await? {
+            let started = Instant::now();
+            let result = backend.retrieve_manifest(digest.to_owned()).await;
+
+            match &result {
+                Ok(manifest) => self.metrics.record_manifest_lookup(
+                    backend.get_id(),
+                    manifest.is_some(),
+                    started.elapsed(),
+                ),
+                Err(_) => self.metrics.record_error(backend.get_id()),
+            };
+
+            if let Some(manifest) = result? {
                 debug!(
                     storage = backend.get_id().as_str(),
                     hash = digest.hash.as_str(),
@@ -329,6 +365,7 @@ impl Storage {
                 digest.to_owned(),
                 manifest.clone(),
                 self.context.workspace_root.clone(),
+                self.metrics.clone(),
             ));
 
             if in_background {
@@ -375,7 +412,8 @@ impl Storage {
 
         // Hydrate the manifest from the backend it was originally loaded from,
         // as that's the most likely to have all the blobs available
-        hydrate_manifest_from_backend(&original_backend, digest, &mut manifest).await?;
+        hydrate_manifest_from_backend(&original_backend, digest, &mut manifest, &self.metrics)
+            .await?;
 
         // If the original backend doesn't have all the blobs available,
         // we should attempt to hydrate from the other backends,
@@ -394,6 +432,7 @@ impl Storage {
                 backend,
                 digest,
                 &mut manifest,
+                &self.metrics,
             )
             .await?;
         }
@@ -450,6 +489,7 @@ impl Storage {
                 digest.to_owned(),
                 manifest.clone(),
                 self.context.workspace_root.clone(),
+                self.metrics.clone(),
             ))));
         }
     }
@@ -514,6 +554,7 @@ async fn persist_manifest_in_backend(
     digest: Digest,
     mut manifest: Manifest,
     workspace_root: PathBuf,
+    metrics: StorageMetrics,
 ) -> miette::Result<()> {
     let blob_inputs = manifest.collect_blob_inputs(&workspace_root);
 
@@ -522,10 +563,13 @@ async fn persist_manifest_in_backend(
     if !blob_inputs.is_empty() {
         manifest.upload_started_at = Some(SystemTime::now());
 
+        let started = Instant::now();
         let stored = Arc::clone(&backend)
             .store_blobs_batched(digest.clone(), blob_inputs)
             .await?;
 
+        metrics.record_write(backend.get_id(), &stored, started.elapsed());
+
         manifest.upload_completed_at = Some(SystemTime::now());
 
         if !stored.success {
@@ -534,7 +578,12 @@ async fn persist_manifest_in_backend(
     }
 
     if backend.get_capabilities().store_manifests {
-        if let Err(error) = backend.store_manifest(digest.clone(), manifest).await {
+        let started = Instant::now();
+        let result = backend.store_manifest(digest.clone(), manifest).await;
+
+        metrics.record_manifest_store(backend.get_id(), result.is_ok(), started.elapsed());
+
+        if let Err(error) = result {
             warn!(
                 storage = backend.get_id().as_str(),
                 hash = digest.hash.as_str(),
@@ -557,13 +606,18 @@ async fn hydrate_manifest_from_backend(
     backend: &BoxedStorageBackend,
     digest: &Digest,
     manifest: &mut Manifest,
+    metrics: &StorageMetrics,
 ) -> miette::Result<FxHashMap<Digest, BlobContent>> {
     let blob_digests = manifest.collect_unhydrated_blob_digests();
 
     // Retrieve all blobs for digests that have yet to be hydrated
+    let started = Instant::now();
     let received = Arc::clone(backend)
         .retrieve_blobs_batched(digest.clone(), blob_digests)
-        .await?;
+        .await
+        .inspect_err(|_| metrics.record_error(backend.get_id()))?;
+
+    metrics.record_read(backend.get_id(), &received, started.elapsed());
 
     let blobs_map = received
         .blobs
@@ -582,11 +636,12 @@ async fn hydrate_manifest_from_backend_and_copy_to_original(
     backend: &BoxedStorageBackend,
     digest: &Digest,
     manifest: &mut Manifest,
+    metrics: &StorageMetrics,
 ) -> miette::Result<()> {
     // Collect the unhydrated blob digests from the manifest before hydrating,
     // so we can compare which are missing and attempt to copy them
     let unhydrated_digests = manifest.collect_unhydrated_blob_digests();
-    let blobs_map = hydrate_manifest_from_backend(backend, digest, manifest).await?;
+    let blobs_map = hydrate_manifest_from_backend(backend, digest, manifest, metrics).await?;
 
     // Loop through and create the blob inputs for the missing blobs
     let mut blob_inputs = vec![];
@@ -610,9 +665,12 @@ async fn hydrate_manifest_from_backend_and_copy_to_original(
             blob_inputs.len()
         );
 
-        Arc::clone(original_backend)
+        let started = Instant::now();
+        let stored = Arc::clone(original_backend)
             .store_blobs_batched(digest.to_owned(), blob_inputs)
             .await?;
+
+        metrics.record_write(original_backend.get_id(), &stored, started.elapsed());
     }
 
     Ok(())
@@ -19,6 +19,7 @@ pub struct StoreResult {
     pub store_count: usize,
     pub stored_count: usize,
     pub missing_count: usize,
+    pub error_count: usize,
     pub success: bool,
 }
 
@@ -26,6 +27,7 @@ pub struct RetrieveResult {
     pub blobs: Vec<BlobOutput>,
     pub retrieve_count: usize,
     pub retrieved_count: usize,
+    pub error_count: usize,
     pub success: bool,
 }
 
@@ -156,6 +158,7 @@ where
             store_count: blob_inputs.len(),
             stored_count: 0,
             missing_count: 0,
+            error_count: 0,
             success: false,
         };
 
@@ -173,6 +176,8 @@ where
                     "Failed to find missing blobs, aborting store operation",
                 );
 
+                result.error_count = 1;
+
                 return Ok(result);
             }
         };
@@ -231,6 +236,7 @@ where
 
         result.stored_count = uploaded_digests.len();
         result.digests = uploaded_digests;
+        result.error_count = upload_errors.len();
 
         // If we received a shutdown signal, we should abort storing the blobs
         if abort {
@@ -278,6 +284,7 @@ where
             blobs: vec![],
             retrieve_count: blob_digests.len(),
             retrieved_count: 0,
+            error_count: 0,
             success: false,
         };
         let cap = self.get_capabilities();
@@ -323,6 +330,7 @@ where
 
         result.retrieved_count = downloaded_blobs.len();
         result.blobs = downloaded_blobs;
+        result.error_count = download_errors.len();
 
         // If we received a shutdown signal, we should abort receiving the blobs
         if abort {
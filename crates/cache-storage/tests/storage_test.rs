@@ -651,4 +651,82 @@ mod storage {
             "warming must honor include_local = false"
         );
     }
+
+    // ---- metrics ----
+
+    #[tokio::test]
+    async fn records_hits_and_misses_per_backend() {
+        // The local tier misses, so the lookup falls through to the remote,
+        // and both backends should account for it.
+        let local = MemoryBackend::new("local");
+        let remote = MemoryBackend::new("remote");
+
+        let action = digest('a', 0);
+        let blob = Digest::from_bytes(b"output").unwrap();
+
+        seed_backend(&remote, &action, &blob, b"output");
+
+        let mut storage = create_storage();
+        storage.add_local_backend(local);
+        storage.add_remote_backend(remote);
+
+        let source = storage.load_manifest(&action).await.unwrap().unwrap();
+        storage
+            .hydrate_manifest(&action, source)
+            .await
+            .unwrap()
+            .unwrap();
+        storage.wait_for_background_tasks().await.unwrap();
+
+        let metrics = storage.get_metrics();
+        let local = &metrics[&Id::raw("local")];
+        let remote = &metrics[&Id::raw("remote")];
+
+        assert!(!local.remote);
+        assert_eq!(local.misses, 1);
+        assert_eq!(local.hits, 0);
+
+        // Warming the local tier counts as a write
+        assert_eq!(local.blobs_written, 1);
+        assert_eq!(local.bytes_written, 6);
+
+        assert!(remote.remote);
+        assert_eq!(remote.hits, 1);
+        assert_eq!(remote.blobs_read, 1);
+        assert_eq!(remote.bytes_read, 6);
+        assert_eq!(remote.errors, 0);
+    }
+
+    #[tokio::test]
+    async fn records_errors_when_blob_upload_fails() {
+        let mut storage = create_storage();
+        storage.add_remote_backend(MemoryBackend::new("remote").failing_store_blobs());
+
+        let action = digest('a', 0);
+        let blob = Digest::from_bytes(b"output").unwrap();
+
+        storage
+            .archive_manifest(&action, manifest_with_file(&blob))
+            .await
+            .unwrap();
+        storage.wait_for_background_tasks().await.unwrap();
+
+        let metrics = storage.get_metrics();
+        let remote = &metrics[&Id::raw("remote")];
+
+        assert_eq!(remote.errors, 1);
+        assert_eq!(remote.blobs_written, 0);
+        assert_eq!(remote.bytes_written, 0);
+    }
+
+    #[tokio::test]
+    async fn shares_metrics_with_scoped_storage() {
+        let mut storage = create_storage();
+        storage.add_local_backend(MemoryBackend::new("local"));
+
+        let scoped = storage.with_options(StorageOptions::default());
+        scoped.load_manifest(&digest('a', 0)).await.unwrap();
+
+        assert_eq!(storage.get_metrics()[&Id::raw("local")].misses, 1);
+    }
 }
@@ -0,0 +1,301 @@
+use crate::cache_bundle_error::CacheBundleError;
+use moon_blob::BlobContent;
+use moon_cache_storage::{Manifest, Storage, StorageOptions, check_blob_integrity};
+use moon_common::color;
+use moon_hash::Digest;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use starbase_archive::Archiver;
+use starbase_utils::{fs, json};
+use std::path::{Path, PathBuf};
+use tracing::{debug, instrument, warn};
+
+pub const CACHE_BUNDLE_VERSION: u8 = 1;
+
+const INDEX_FILE: &str = "bundle.json";
+const BLOBS_DIR: &str = "blobs";
+const MANIFESTS_DIR: &str = "manifests";
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CacheBundleIndex {
+    pub manifests: Vec<Digest>,
+    pub version: u8,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CacheBundleStats {
+    /// Manifests that were exported or imported.
+    pub manifests: usize,
+
+    /// Unique blobs referenced by those manifests.
+    pub blobs: usize,
+
+    /// Manifests that were skipped, as they (or one of their blobs)
+    /// could not be found, or failed an integrity check.
+    pub skipped: usize,
+}
+
+/// Drop guard that removes a staging directory, even when
+/// exporting or importing fails part way through.
+struct StagingDir(PathBuf);
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A self-contained archive of cache manifests and the blobs they reference,
+/// that can be carried between machines without a remote cache. The archive
+/// contains an index file, a `manifests` directory keyed by action hash, and
+/// a `blobs` directory keyed by content hash.
+pub struct CacheBundle<'app> {
+    storage: Storage,
+    temp_dir: &'app Path,
+}
+
+impl<'app> CacheBundle<'app> {
+    /// Create a bundle that reads from and writes to the local
+    /// backends of the provided storage, never the remote. Errors
+    /// if the storage has no local backends.
+    pub fn new(storage: &Storage, temp_dir: &'app Path) -> miette::Result<Self> {
+        if !storage.is_local_enabled() {
+            return Err(CacheBundleError::LocalCacheDisabled.into());
+        }
+
+        Ok(Self {
+            storage: storage.with_options(StorageOptions {
+                include_remote: false,
+                ..StorageOptions::default()
+            }),
+            temp_dir,
+        })
+    }
+
+    /// Export the manifests for the provided digests, and all of their blobs,
+    /// into an archive. The archive format is inferred from the file extension.
+    #[instrument(skip(self, digests))]
+    pub async fn export(
+        &self,
+        digests: &[Digest],
+        archive_file: &Path,
+    ) -> miette::Result<CacheBundleStats> {
+        let staging = self.create_staging_dir("export")?;
+        let staging_dir = &staging.0;
+        let mut stats = CacheBundleStats::default();
+        let mut index = CacheBundleIndex {
+            manifests: vec![],
+            version: CACHE_BUNDLE_VERSION,
+        };
+
+        for digest in digests {
+            if self
+                .export_manifest(digest, staging_dir, &mut stats)
+                .await?
+            {
+                index.manifests.push(digest.to_owned());
+                stats.manifests += 1;
+            } else {
+                stats.skipped += 1;
+            }
+        }
+
+        json::write_file(staging_dir.join(INDEX_FILE), &index, false)?;
+
+        debug!(
+            archive_file = ?archive_file,
+            manifests = stats.manifests,
+            blobs = stats.blobs,
+            "Packing cache bundle"
+        );
+
+        let mut archiver = Archiver::new(staging_dir, archive_file);
+        archiver.add_source_file(INDEX_FILE, None);
+        archiver.add_source_file(MANIFESTS_DIR, None);
+        archiver.add_source_file(BLOBS_DIR, None);
+        archiver.pack_from_ext()?;
+
+        Ok(stats)
+    }
+
+    /// Import all manifests and blobs from an archive into the local backends.
+    /// Every blob is verified against its digest before it's stored, and
+    /// manifests with a missing or corrupted blob are skipped entirely.
+    #[instrument(skip(self))]
+    pub async fn import(&self, archive_file: &Path) -> miette::Result<CacheBundleStats> {
+        let staging = self.create_staging_dir("import")?;
+        let staging_dir = &staging.0;
+
+        debug!(archive_file = ?archive_file, "Unpacking cache bundle");
+
+        Archiver::new(staging_dir, archive_file).unpack_from_ext()?;
+
+        let index_file = staging_dir.join(INDEX_FILE);
+
+        if !index_file.exists() {
+            return Err(CacheBundleError::MissingIndex {
+                path: archive_file.to_path_buf(),
+            }
+            .into());
+        }
+
+        let index: CacheBundleIndex = json::read_file(&index_file)?;
+
+        if index.version != CACHE_BUNDLE_VERSION {
+            return Err(CacheBundleError::UnsupportedVersion {
+                path: archive_file.to_path_buf(),
+                version: index.version,
+            }
+            .into());
+        }
+
+        let mut stats = CacheBundleStats::default();
+
+        for digest in &index.manifests {
+            match self.import_manifest(digest, staging_dir)? {
+                Some((manifest, blob_count)) => {
+                    self.storage.archive_manifest(digest, manifest).await?;
+
+                    stats.manifests += 1;
+                    stats.blobs += blob_count;
+                }
+                None => {
+                    stats.skipped += 1;
+                }
+            };
+        }
+
+        // Blobs are read from the staging directory,
+        // so wait for them to be written before removing it
+        self.storage.wait_for_background_tasks().await?;
+
+        Ok(stats)
+    }
+
+    fn create_staging_dir(&self, kind: &str) -> miette::Result<StagingDir> {
+        let dir = self
+            .temp_dir
+            .join(format!("bundle-{kind}-{}", std::process::id()));
+
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+
+        fs::create_dir_all(dir.join(BLOBS_DIR))?;
+        fs::create_dir_all(dir.join(MANIFESTS_DIR))?;
+
+        Ok(StagingDir(dir))
+    }
+
+    async fn export_manifest(
+        &self,
+        digest: &Digest,
+        staging_dir: &Path,
+        stats: &mut CacheBundleStats,
+    ) -> miette::Result<bool> {
+        let Some(source) = self.storage.load_manifest(digest).await? else {
+            warn!(
+                hash = digest.hash.as_str(),
+                "No cache manifest found for hash {}, skipping",
+                color::hash(digest.hash.as_str())
+            );
+
+            return Ok(false);
+        };
+
+        let blob_digests = source.manifest.collect_blob_digests();
+        let blobs = self.storage.retrieve_blobs(blob_digests.clone()).await?;
+        let blobs_map = blobs
+            .into_iter()
+            .map(|blob| (blob.digest, blob.content))
+            .collect::<FxHashMap<_, _>>();
+
+        for blob_digest in &blob_digests {
+            let Some(content) = blobs_map.get(blob_digest) else {
+                warn!(
+                    hash = digest.hash.as_str(),
+                    blob = blob_digest.hash.as_str(),
+                    "Cache manifest for hash {} references a missing blob, skipping",
+                    color::hash(digest.hash.as_str())
+                );
+
+                return Ok(false);
+            };
+
+            let blob_file = staging_dir.join(BLOBS_DIR).join(blob_digest.hash.as_str());
+
+            if blob_file.exists() {
+                continue;
+            }
+
+            match content {
+                BlobContent::File(path) => fs::copy_file(path, &blob_file)?,
+                BlobContent::Inline(bytes) => fs::write_file(&blob_file, bytes)?,
+            };
+
+            stats.blobs += 1;
+        }
+
+        json::write_file(
+            staging_dir
+                .join(MANIFESTS_DIR)
+                .join(format!("{}.json", digest.hash)),
+            &source.manifest,
+            false,
+        )?;
+
+        Ok(true)
+    }
+
+    fn import_manifest(
+        &self,
+        digest: &Digest,
+        staging_dir: &Path,
+    ) -> miette::Result<Option<(Manifest, usize)>> {
+        let manifest_file = staging_dir
+            .join(MANIFESTS_DIR)
+            .join(format!("{}.json", digest.hash));
+
+        if !manifest_file.exists() {
+            warn!(
+                hash = digest.hash.as_str(),
+                "Cache bundle is missing the manifest for hash {}, skipping",
+                color::hash(digest.hash.as_str())
+            );
+
+            return Ok(None);
+        }
+
+        let mut manifest: Manifest = json::read_file(&manifest_file)?;
+        let blob_digests = manifest.collect_blob_digests();
+        let mut blobs_map = FxHashMap::default();
+
+        for blob_digest in blob_digests {
+            let blob_file = staging_dir.join(BLOBS_DIR).join(blob_digest.hash.as_str());
+
+            if !blob_file.exists()
+                || !check_blob_integrity(&blob_digest, &fs::read_file_bytes(&blob_file)?)?
+            {
+                warn!(
+                    hash = digest.hash.as_str(),
+                    blob = blob_digest.hash.as_str(),
+                    "Cache bundle has a missing or corrupted blob for hash {}, skipping",
+                    color::hash(digest.hash.as_str())
+                );
+
+                return Ok(None);
+            }
+
+            blobs_map.insert(blob_digest, BlobContent::File(blob_file));
+        }
+
+        let blob_count = blobs_map.len();
+
+        // Point the manifest at the staged blobs, so that archiving
+        // copies them into the local CAS
+        manifest.hydrate(&blobs_map)?;
+
+        Ok(Some((manifest, blob_count)))
+    }
+}
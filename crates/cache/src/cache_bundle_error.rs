@@ -0,0 +1,30 @@
+use miette::Diagnostic;
+use moon_common::{Style, Stylize};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug, Diagnostic)]
+pub enum CacheBundleError {
+    #[diagnostic(code(cache_bundle::local_cache_disabled))]
+    #[error(
+        "Cache bundles require the local content-addressable cache. Enable the {} experiment to use them.",
+        "experiments.casOutputsCache".style(Style::Property),
+    )]
+    LocalCacheDisabled,
+
+    #[diagnostic(code(cache_bundle::missing_index))]
+    #[error(
+        "Invalid cache bundle {}, it does not contain a {} index.",
+        .path.style(Style::Path),
+        "bundle.json".style(Style::File)
+    )]
+    MissingIndex { path: PathBuf },
+
+    #[diagnostic(code(cache_bundle::unsupported_version))]
+    #[error(
+        "Cache bundle {} uses version {}, which is not supported by this version of moon.",
+        .path.style(Style::Path),
+        .version.to_string().style(Style::Symbol)
+    )]
+    UnsupportedVersion { path: PathBuf, version: u8 },
+}
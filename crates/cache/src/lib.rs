@@ -1,7 +1,11 @@
+mod cache_bundle;
+mod cache_bundle_error;
 mod cache_engine;
 mod hash_engine;
 mod state_engine;
 
+pub use cache_bundle::*;
+pub use cache_bundle_error::*;
 pub use cache_engine::*;
 pub use hash_engine::*;
 pub use moon_cache_item::*;
@@ -0,0 +1,221 @@
+use moon_blob::Bytes;
+use moon_cache::*;
+use moon_cache_local::LocalStorage;
+use starbase_archive::Archiver;
+use starbase_sandbox::{Sandbox, create_empty_sandbox};
+
+fn create_engine(sandbox: &Sandbox) -> CacheEngine {
+    let context = CacheContext::new(sandbox.path());
+    let cache_dir = context.cache_dir.clone();
+
+    let mut engine = CacheEngine::new(context.clone()).unwrap();
+    engine
+        .storage
+        .add_local_backend(LocalStorage::new(context, cache_dir).unwrap());
+    engine
+}
+
+fn action_digest() -> Digest {
+    Digest::from_bytes(b"action").unwrap()
+}
+
+fn create_manifest(content: &'static [u8]) -> Manifest {
+    Manifest {
+        exit_code: 0,
+        files: vec![ManifestFile {
+            bytes: Some(Bytes::from_static(content)),
+            digest: Some(Digest::from_bytes(content).unwrap()),
+            path: "out/file.txt".into(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+async fn seed_engine(engine: &CacheEngine) {
+    engine
+        .storage
+        .archive_manifest(&action_digest(), create_manifest(b"output"))
+        .await
+        .unwrap();
+    engine.storage.wait_for_background_tasks().await.unwrap();
+}
+
+mod cache_bundle {
+    use super::*;
+
+    #[tokio::test]
+    async fn round_trips_manifests_and_blobs() {
+        let source = create_empty_sandbox();
+        let source_engine = create_engine(&source);
+        let archive_file = source.path().join("bundle.tar.gz");
+
+        seed_engine(&source_engine).await;
+
+        let stats = CacheBundle::new(&source_engine.storage, &source_engine.temp_dir)
+            .unwrap()
+            .export(&[action_digest()], &archive_file)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            stats,
+            CacheBundleStats {
+                manifests: 1,
+                blobs: 1,
+                skipped: 0
+            }
+        );
+        assert!(archive_file.exists());
+
+        let dest = create_empty_sandbox();
+        let dest_engine = create_engine(&dest);
+
+        let stats = CacheBundle::new(&dest_engine.storage, &dest_engine.temp_dir)
+            .unwrap()
+            .import(&archive_file)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.manifests, 1);
+        assert_eq!(stats.blobs, 1);
+
+        let source = dest_engine
+            .storage
+            .load_manifest(&action_digest())
+            .await
+            .unwrap()
+            .unwrap();
+        let manifest = dest_engine
+            .storage
+            .hydrate_manifest(&action_digest(), source)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(manifest.is_hydrated());
+    }
+
+    #[tokio::test]
+    async fn skips_unknown_hashes_on_export() {
+        let sandbox = create_empty_sandbox();
+        let engine = create_engine(&sandbox);
+
+        seed_engine(&engine).await;
+
+        let stats = CacheBundle::new(&engine.storage, &engine.temp_dir)
+            .unwrap()
+            .export(
+                &[action_digest(), Digest::from_bytes(b"unknown").unwrap()],
+                &sandbox.path().join("bundle.tar.gz"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(stats.manifests, 1);
+        assert_eq!(stats.skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn skips_manifests_with_corrupted_blobs_on_import() {
+        let sandbox = create_empty_sandbox();
+        let blob_digest = Digest::from_bytes(b"output").unwrap();
+
+        sandbox.create_file(
+            "bundle/bundle.json",
+            format!(
+                r#"{{ "version": 1, "manifests": [{{ "hash": "{}", "size": 6 }}] }}"#,
+                action_digest().hash
+            ),
+        );
+        sandbox.create_file(
+            format!("bundle/manifests/{}.json", action_digest().hash),
+            serde_json::to_string(&create_manifest(b"output")).unwrap(),
+        );
+        sandbox.create_file(format!("bundle/blobs/{}", blob_digest.hash), "tampered");
+
+        let archive_file = sandbox.path().join("bundle.tar.gz");
+        let bundle_dir = sandbox.path().join("bundle");
+        let mut archiver = Archiver::new(&bundle_dir, &archive_file);
+        archiver.add_source_glob("**/*");
+        archiver.pack_from_ext().unwrap();
+
+        let engine = create_engine(&sandbox);
+        let stats = CacheBundle::new(&engine.storage, &engine.temp_dir)
+            .unwrap()
+            .import(&archive_file)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.manifests, 0);
+        assert_eq!(stats.skipped, 1);
+        assert!(
+            engine
+                .storage
+                .load_manifest(&action_digest())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_when_index_is_missing() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("bundle/manifests/unknown.json", "{}");
+
+        let archive_file = sandbox.path().join("bundle.tar.gz");
+        let bundle_dir = sandbox.path().join("bundle");
+        let mut archiver = Archiver::new(&bundle_dir, &archive_file);
+        archiver.add_source_glob("**/*");
+        archiver.pack_from_ext().unwrap();
+
+        let engine = create_engine(&sandbox);
+        let error = CacheBundle::new(&engine.storage, &engine.temp_dir)
+            .unwrap()
+            .import(&archive_file)
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("does not contain"));
+    }
+
+    #[tokio::test]
+    async fn errors_without_local_backends() {
+        let sandbox = create_empty_sandbox();
+        let engine = CacheEngine::new(CacheContext::new(sandbox.path())).unwrap();
+
+        let error = CacheBundle::new(&engine.storage, &engine.temp_dir)
+            .err()
+            .unwrap();
+
+        assert!(error.to_string().contains("casOutputsCache"));
+    }
+
+    #[tokio::test]
+    async fn removes_staging_dir_on_error() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("bundle/manifests/unknown.json", "{}");
+
+        let archive_file = sandbox.path().join("bundle.tar.gz");
+        let bundle_dir = sandbox.path().join("bundle");
+        let mut archiver = Archiver::new(&bundle_dir, &archive_file);
+        archiver.add_source_glob("**/*");
+        archiver.pack_from_ext().unwrap();
+
+        let engine = create_engine(&sandbox);
+
+        CacheBundle::new(&engine.storage, &engine.temp_dir)
+            .unwrap()
+            .import(&archive_file)
+            .await
+            .unwrap_err();
+
+        assert!(
+            !engine
+                .temp_dir
+                .join(format!("bundle-import-{}", std::process::id()))
+                .exists()
+        );
+    }
+}
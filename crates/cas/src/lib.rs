@@ -1,6 +1,8 @@
+mod byte_size;
 mod cas;
 mod cas_error;
 mod gc;
 
+pub use byte_size::parse_byte_size;
 pub use cas::CasStore;
 pub use cas_error::CasError;
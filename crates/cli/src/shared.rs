@@ -1,10 +1,12 @@
 use crate::lookup::*;
 use clap::Parser;
 use mimalloc::MiMalloc;
+use moon_app::commands::cache::CacheCommands;
 use moon_app::commands::daemon::DaemonCommands;
 use moon_app::commands::debug::DebugCommands;
 use moon_app::commands::docker::DockerCommands;
 use moon_app::commands::extension::ExtensionCommands;
+use moon_app::commands::graph::GraphCommands;
 use moon_app::commands::migrate::MigrateCommands;
 use moon_app::commands::query::QueryCommands;
 use moon_app::commands::sync::SyncCommands;
@@ -193,6 +195,17 @@ pub async fn run_cli(args: Vec<OsString>) -> MainResult {
                     commands::action_graph::action_graph(session, args).await
                 }
                 Commands::Bin(args) => commands::bin::bin(session, args).await,
+                Commands::Cache { command } => match command {
+                    CacheCommands::Export(args) => {
+                        commands::cache::export::export(session, args).await
+                    }
+                    CacheCommands::Import(args) => {
+                        commands::cache::import::import(session, args).await
+                    }
+                    CacheCommands::Serve(args) => {
+                        commands::cache::serve::serve(session, args).await
+                    }
+                },
                 Commands::Ci(args) => commands::ci::ci(session, args).await,
                 Commands::Check(args) => commands::check::check(session, args).await,
                 Commands::Clean(args) => commands::clean::clean(session, args).await,
@@ -200,6 +213,9 @@ pub async fn run_cli(args: Vec<OsString>) -> MainResult {
                     commands::completions::completions(session, args).await
                 }
                 Commands::Daemon { command } => match command {
+                    DaemonCommands::Events(args) => {
+                        commands::daemon::events::events(session, args).await
+                    }
                     DaemonCommands::Logs => commands::daemon::logs::logs(session).await,
                     DaemonCommands::Restart => commands::daemon::restart::restart(session).await,
                     DaemonCommands::Start => commands::daemon::start::start(session).await,
@@ -232,7 +248,13 @@ pub async fn run_cli(args: Vec<OsString>) -> MainResult {
                         commands::extension::info::info(session, args).await
                     }
                 },
+                Commands::Flaky(args) => commands::flaky::flaky(session, args).await,
                 Commands::Generate(args) => commands::generate::generate(session, args).await,
+                Commands::Graph { command } => match command {
+                    GraphCommands::Analyze(args) => {
+                        commands::graph::analyze::analyze(session, args).await
+                    }
+                },
                 Commands::Hash(args) => commands::hash::hash(session, args).await,
                 Commands::Init(args) => commands::init::init(session, args).await,
                 Commands::Mcp(args) => commands::mcp::mcp(session, args).await,
@@ -291,6 +313,7 @@ pub async fn run_cli(args: Vec<OsString>) -> MainResult {
                     }
                 },
                 Commands::Upgrade(args) => commands::upgrade::upgrade(session, args).await,
+                Commands::Watch(args) => commands::watch::watch(session, args).await,
             }
         })
         .await;
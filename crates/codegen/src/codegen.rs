@@ -1,16 +1,19 @@
 use crate::codegen_error::CodegenError;
+use crate::post_generate::*;
 use crate::template::Template;
 use miette::IntoDiagnostic;
 use moon_common::Id;
 use moon_common::path::{PathExt, RelativePathBuf, locate_config_dir};
 use moon_config::{GeneratorConfig, PartialTemplateConfig, TemplateLocator};
 use moon_config_loader::ConfigLoader;
+use moon_console::Console;
 use moon_env::MoonEnvironment;
 use moon_process::{Command, Output};
 use moon_time::now_millis;
 use rustc_hash::FxHashMap;
 use starbase_archive::Archiver;
 use starbase_utils::{fs, glob, net, yaml};
+use std::env;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::task::JoinSet;
@@ -194,6 +197,63 @@ impl<'app> CodeGenerator<'app> {
         Ok(())
     }
 
+    /// Return true if the template, or a template it extends, was downloaded
+    /// from a remote location (archive, git, or npm) instead of the workspace.
+    pub fn is_remote_template(&self, template: &Template) -> bool {
+        template.root.starts_with(&self.moon_env.templates_dir)
+            || template
+                .templates
+                .iter()
+                .any(|extended| self.is_remote_template(extended))
+    }
+
+    /// Run the post-generate steps in order, from the destination directory.
+    /// When a step fails and is not allowed to, subsequent steps are skipped.
+    /// If `skip` is true, all steps are skipped without running.
+    #[instrument(skip_all)]
+    pub async fn run_post_generate(
+        &self,
+        steps: Vec<PostGenerateStep>,
+        dest: &Path,
+        skip: bool,
+        console: Arc<Console>,
+    ) -> miette::Result<Vec<PostGenerateResult>> {
+        let mut results = vec![];
+        let mut halted = skip;
+
+        for step in steps {
+            if halted {
+                results.push(PostGenerateResult::skipped(step));
+                continue;
+            }
+
+            debug!(step = step.get_label(), dest = ?dest, "Running post-generate step");
+
+            let mut command = match &step.action {
+                PostGenerateAction::Command(script) => Command::new_script(script),
+                PostGenerateAction::Target(target) => {
+                    let mut command = Command::new(env::current_exe().into_diagnostic()?);
+                    command.args(["run", target]);
+                    command
+                }
+            };
+
+            let output = command
+                .cwd(dest)
+                .set_console(Arc::clone(&console))
+                .set_error_on_nonzero(false)
+                .exec_stream_and_capture_output()
+                .await?;
+
+            let result = PostGenerateResult::from_output(step, &output);
+
+            halted = result.is_fatal();
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
     #[instrument(skip_all)]
     async fn resolve_template_locations(&mut self) -> miette::Result<()> {
         let mut locations = vec![];
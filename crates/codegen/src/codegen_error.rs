@@ -27,6 +27,52 @@ pub enum CodegenError {
         error: miette::Report,
     },
 
+    #[diagnostic(code(codegen::post_generate::missing_action))]
+    #[error(
+        "A post-generate step in template {} must define either a {} or a {}.",
+        .0.style(Style::Id),
+        "command".style(Style::Property),
+        "target".style(Style::Property),
+    )]
+    MissingPostGenerateAction(String),
+
+    #[diagnostic(code(codegen::post_generate::failed))]
+    #[error(
+        "Post-generate step {} failed with exit code {}.{}",
+        .command.style(Style::Shell),
+        .code,
+        .stderr.lines().map(|line| format!("\n  {}", line.style(Style::MutedLight))).collect::<String>(),
+    )]
+    PostGenerateFailed {
+        command: String,
+        code: i32,
+        stderr: String,
+    },
+
+    #[diagnostic(code(codegen::post_generate::render_failed))]
+    #[error(
+        "Failed to interpolate variables into post-generate step {}.",
+        .value.style(Style::Shell),
+    )]
+    RenderPostGenerateFailed {
+        value: String,
+        #[source]
+        error: Box<tera::Error>,
+    },
+
+    #[diagnostic(code(codegen::template_file::condition_failed))]
+    #[error(
+        "Failed to evaluate condition {} for template files matching {}.",
+        .condition.style(Style::Symbol),
+        .pattern.style(Style::File),
+    )]
+    EvaluateFileConditionFailed {
+        condition: String,
+        pattern: String,
+        #[source]
+        error: Box<tera::Error>,
+    },
+
     #[diagnostic(code(codegen::template::missing))]
     #[error(
         "No template with the name {} could be found at any of the configured template locations.",
@@ -3,13 +3,19 @@ mod codegen;
 mod codegen_error;
 mod filters;
 mod funcs;
+mod post_generate;
 mod template;
+mod template_baseline;
 mod template_file;
+mod template_merge;
 
 pub use asset_file::*;
 pub use codegen::*;
 pub use codegen_error::*;
+pub use post_generate::*;
 pub use template::*;
+pub use template_baseline::*;
 pub use template_file::*;
+pub use template_merge::*;
 pub use tera;
 pub use tera::Context as TemplateContext;
@@ -0,0 +1,76 @@
+use moon_process::{Output, output_to_trimmed_string};
+use serde::Serialize;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PostGenerateAction {
+    /// A shell command to run in the destination.
+    Command(String),
+
+    /// A target to run with `moon run` in the destination.
+    Target(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct PostGenerateStep {
+    /// The command or target to run, with variables interpolated.
+    pub action: PostGenerateAction,
+
+    /// Continue running subsequent steps when this step fails.
+    pub allow_failure: bool,
+}
+
+impl PostGenerateStep {
+    pub fn get_label(&self) -> String {
+        match &self.action {
+            PostGenerateAction::Command(command) => command.to_owned(),
+            PostGenerateAction::Target(target) => format!("moon run {target}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PostGenerateState {
+    Failed,
+    Passed,
+    #[default]
+    Skipped,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct PostGenerateResult {
+    pub exit_code: Option<i32>,
+    pub state: PostGenerateState,
+    pub stderr: String,
+    pub step: PostGenerateStep,
+}
+
+impl PostGenerateResult {
+    pub fn skipped(step: PostGenerateStep) -> Self {
+        Self {
+            exit_code: None,
+            state: PostGenerateState::Skipped,
+            stderr: String::new(),
+            step,
+        }
+    }
+
+    pub fn from_output(step: PostGenerateStep, output: &Output) -> Self {
+        Self {
+            exit_code: output.code(),
+            state: if output.success() {
+                PostGenerateState::Passed
+            } else {
+                PostGenerateState::Failed
+            },
+            stderr: output_to_trimmed_string(&output.stderr),
+            step,
+        }
+    }
+
+    /// Whether the step failed, and subsequent steps should not run.
+    pub fn is_fatal(&self) -> bool {
+        self.state == PostGenerateState::Failed && !self.step.allow_failure
+    }
+}
@@ -1,14 +1,16 @@
 use crate::asset_file::AssetFile;
 use crate::codegen_error::CodegenError;
+use crate::post_generate::{PostGenerateAction, PostGenerateStep};
 use crate::template_file::{FileState, MergeType, TemplateFile};
 use crate::{filters, funcs};
 use miette::IntoDiagnostic;
 use moon_common::Id;
 use moon_common::path::{RelativePathBuf, to_virtual_string};
-use moon_config::{TemplateConfig, schematic::helpers::strip_bom};
+use moon_config::{TemplateConfig, TemplatePostGenerateStep, schematic::helpers::strip_bom};
 use moon_config_loader::ConfigLoader;
 use regex::Regex;
 use serde::Serialize;
+use starbase_utils::glob::GlobSet;
 use starbase_utils::{fs, json, yaml};
 use std::collections::BTreeMap;
 use std::mem;
@@ -26,6 +28,7 @@ pub struct Template {
     pub config: TemplateConfig,
     #[serde(skip)]
     pub engine: Tera,
+    pub excluded: Vec<RelativePathBuf>,
     pub files: BTreeMap<RelativePathBuf, TemplateFile>,
     pub id: Id,
     pub root: PathBuf,
@@ -56,6 +59,7 @@ impl Template {
             assets: BTreeMap::new(),
             config,
             engine,
+            excluded: vec![],
             files: BTreeMap::new(),
             root,
             templates: vec![],
@@ -92,6 +96,7 @@ impl Template {
 
             assets.extend(mem::take(&mut template.assets));
             files.extend(mem::take(&mut template.files));
+            self.excluded.extend(mem::take(&mut template.excluded));
         }
 
         assets.extend(mem::take(&mut self.assets));
@@ -111,6 +116,7 @@ impl Template {
 
         let mut files = vec![];
         let filenames = ConfigLoader::default().get_template_file_names();
+        let excluded_globs = self.load_excluded_globs(context)?;
 
         debug!(
             template = self.id.as_str(),
@@ -128,9 +134,27 @@ impl Template {
             }
 
             let source_path = entry.path();
+            let rel_path = source_path.strip_prefix(&self.root).unwrap();
+            let name = self.interpolate_path(rel_path, context)?;
+
+            // Exclude files whose conditions are not met, before rendering
+            if excluded_globs
+                .as_ref()
+                .is_some_and(|globs| globs.matches(rel_path))
+            {
+                debug!(
+                    template = self.id.as_str(),
+                    file = name.as_str(),
+                    source = ?source_path,
+                    "Excluding file as its condition is not met",
+                );
+
+                self.excluded.push(name);
+
+                continue;
+            }
+
             let source_content = fs::read_file_bytes(&source_path)?;
-            let name =
-                self.interpolate_path(source_path.strip_prefix(&self.root).unwrap(), context)?;
 
             // Images, etc
             if content_inspector::inspect(&source_content).is_binary() {
@@ -221,6 +245,96 @@ impl Template {
         Ok(())
     }
 
+    /// Evaluate the condition of each path or glob in the `files` setting,
+    /// and return a glob set of all paths whose condition is not truthy.
+    fn load_excluded_globs(
+        &mut self,
+        context: &Context,
+    ) -> miette::Result<Option<GlobSet<'static>>> {
+        let mut patterns = vec![];
+
+        for (pattern, condition) in &self.config.files {
+            let result = self
+                .engine
+                .render_str(&format!("{{% if {condition} %}}true{{% endif %}}"), context)
+                .map_err(|error| CodegenError::EvaluateFileConditionFailed {
+                    condition: condition.to_owned(),
+                    pattern: pattern.to_owned(),
+                    error: Box::new(error),
+                })?;
+
+            if result != "true" {
+                patterns.push(pattern.to_owned());
+            }
+        }
+
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(GlobSet::new_owned(&patterns)?))
+    }
+
+    /// Load the steps to run after generation, with extended templates first,
+    /// and interpolate variables into each command and target.
+    pub fn load_post_generate_steps(
+        &mut self,
+        context: &Context,
+    ) -> miette::Result<Vec<PostGenerateStep>> {
+        let mut configs = vec![];
+
+        collect_post_generate_configs(self, &mut configs);
+
+        let mut steps = vec![];
+
+        for (template_id, config) in configs {
+            let (action, allow_failure) = match config {
+                TemplatePostGenerateStep::Command(command) => {
+                    (PostGenerateAction::Command(command), false)
+                }
+                TemplatePostGenerateStep::Object(cfg) => match (cfg.command, cfg.target) {
+                    (Some(command), _) => (PostGenerateAction::Command(command), cfg.allow_failure),
+                    (None, Some(target)) => (PostGenerateAction::Target(target), cfg.allow_failure),
+                    (None, None) => {
+                        return Err(CodegenError::MissingPostGenerateAction(
+                            template_id.to_string(),
+                        )
+                        .into());
+                    }
+                },
+            };
+
+            let action = match action {
+                PostGenerateAction::Command(command) => {
+                    PostGenerateAction::Command(self.render_post_generate_value(&command, context)?)
+                }
+                PostGenerateAction::Target(target) => {
+                    PostGenerateAction::Target(self.render_post_generate_value(&target, context)?)
+                }
+            };
+
+            steps.push(PostGenerateStep {
+                action,
+                allow_failure,
+            });
+        }
+
+        Ok(steps)
+    }
+
+    fn render_post_generate_value(
+        &mut self,
+        value: &str,
+        context: &Context,
+    ) -> miette::Result<String> {
+        Ok(self.engine.render_str(value, context).map_err(|error| {
+            CodegenError::RenderPostGenerateFailed {
+                value: value.to_owned(),
+                error: Box::new(error),
+            }
+        })?)
+    }
+
     /// Tera *does not* support iterating over the context, so we're unable
     /// to interpolate a path ourselves. Instead, let's use Tera and its
     /// template rendering to handle this.
@@ -314,6 +428,15 @@ impl Template {
                     _ => {}
                 };
             }
+            FileState::Conflict | FileState::Update => {
+                debug!(
+                    file = file.name.as_str(),
+                    to = ?file.dest_path,
+                    "Updating destination with template changes",
+                );
+
+                fs::write_file(&file.dest_path, &file.content)?;
+            }
             FileState::Replace => {
                 debug!(
                     file = file.name.as_str(),
@@ -337,3 +460,16 @@ impl Template {
         Ok(())
     }
 }
+
+fn collect_post_generate_configs(
+    template: &Template,
+    configs: &mut Vec<(Id, TemplatePostGenerateStep)>,
+) {
+    for extended in &template.templates {
+        collect_post_generate_configs(extended, configs);
+    }
+
+    for config in &template.config.post_generate {
+        configs.push((template.id.clone(), config.clone()));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::template::Template;
+use moon_common::Id;
+use moon_common::path::to_virtual_string;
+use serde::{Deserialize, Serialize};
+use starbase_utils::json;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::debug;
+
+pub const BASELINE_FILE_NAME: &str = ".moon-template.json";
+
+/// The rendered content of each file from the last time a template was
+/// generated into a destination. Used as the common ancestor when merging
+/// template changes into files that may have been edited since.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TemplateBaseline {
+    /// Rendered content, keyed by file path relative to the destination.
+    pub files: BTreeMap<String, String>,
+}
+
+impl TemplateBaseline {
+    /// Create a baseline from the rendered files of a template that will be
+    /// written. Files that are skipped keep their content from the previous
+    /// baseline, as the destination still reflects that generation. Files
+    /// written outside of the destination are not recorded.
+    pub fn from_template(
+        template: &Template,
+        dest: &Path,
+        previous: Option<&TemplateBaseline>,
+    ) -> miette::Result<Self> {
+        let mut files = BTreeMap::new();
+
+        for file in template.files.values() {
+            let Ok(rel_path) = file.dest_path.strip_prefix(dest) else {
+                continue;
+            };
+
+            let rel_path = to_virtual_string(rel_path)?;
+
+            if file.should_write() {
+                files.insert(rel_path, file.content.clone());
+            } else if let Some(content) =
+                previous.and_then(|previous| previous.files.get(&rel_path))
+            {
+                files.insert(rel_path, content.clone());
+            }
+        }
+
+        Ok(Self { files })
+    }
+
+    /// Return the baseline content for a file at the destination path.
+    pub fn get_content(&self, dest: &Path, dest_path: &Path) -> Option<&str> {
+        let rel_path = dest_path.strip_prefix(dest).ok()?;
+
+        self.files
+            .get(&to_virtual_string(rel_path).ok()?)
+            .map(|content| content.as_str())
+    }
+}
+
+/// The baselines of every template that has been generated into a
+/// destination, as multiple templates may write to the same folder.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TemplateBaselines {
+    /// Baselines keyed by template ID.
+    pub templates: BTreeMap<Id, TemplateBaseline>,
+}
+
+impl TemplateBaselines {
+    /// Load the baselines from the destination, if they exist.
+    pub fn load(dest: &Path) -> miette::Result<Self> {
+        let path = dest.join(BASELINE_FILE_NAME);
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        debug!(file = ?path, "Loading template baselines");
+
+        Ok(json::read_file(path)?)
+    }
+
+    /// Save the baselines into the destination.
+    pub fn save(&self, dest: &Path) -> miette::Result<()> {
+        let path = dest.join(BASELINE_FILE_NAME);
+
+        debug!(file = ?path, "Saving template baselines");
+
+        json::write_file(path, self, true)?;
+
+        Ok(())
+    }
+
+    /// Return the baseline for the provided template, if it has been generated.
+    pub fn get(&self, template_id: &Id) -> Option<&TemplateBaseline> {
+        self.templates.get(template_id)
+    }
+
+    /// Record the baseline for the provided template, replacing the previous one.
+    pub fn insert(&mut self, template_id: Id, baseline: TemplateBaseline) {
+        self.templates.insert(template_id, baseline);
+    }
+}
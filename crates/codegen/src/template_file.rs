@@ -1,3 +1,4 @@
+use crate::template_merge::merge_three_way;
 use moon_common::path::RelativePathBuf;
 use moon_config::TemplateFrontmatterConfig;
 use moon_config_loader::ConfigLoader;
@@ -15,10 +16,12 @@ pub enum MergeType {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum FileState {
+    Conflict,
     Create,
     Merge,
     Replace,
     Skip,
+    Update,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -127,6 +130,38 @@ impl TemplateFile {
         self.set_content(content, dest)
     }
 
+    /// Merge the changes between the previously rendered `baseline` and
+    /// the newly rendered content into the `current` destination content,
+    /// preserving local edits. Conflicting hunks are written with markers.
+    pub fn apply_update(&mut self, baseline: &str, current: &str) {
+        // Nothing changed in the template, so keep local edits
+        if self.content == baseline || self.content == current {
+            self.state = FileState::Skip;
+            return;
+        }
+
+        // Nothing changed locally, so use the new content as-is
+        if current == baseline {
+            self.state = FileState::Update;
+            return;
+        }
+
+        let result = merge_three_way(baseline, current, &self.content);
+
+        debug!(
+            file = %self.name,
+            conflicts = result.conflicts,
+            "Merged template changes with local changes",
+        );
+
+        self.content = result.content;
+        self.state = if result.conflicts > 0 {
+            FileState::Conflict
+        } else {
+            FileState::Update
+        };
+    }
+
     pub fn should_write(&self) -> bool {
         !matches!(self.state, FileState::Skip)
     }
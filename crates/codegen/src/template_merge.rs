@@ -0,0 +1,118 @@
+use similar::{Algorithm, DiffOp, capture_diff_slices};
+
+pub const CONFLICT_START: &str = "<<<<<<< current";
+pub const CONFLICT_SEPARATOR: &str = "=======";
+pub const CONFLICT_END: &str = ">>>>>>> template";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct MergeResult {
+    /// Merged content, including conflict markers.
+    pub content: String,
+
+    /// Number of conflicting hunks.
+    pub conflicts: usize,
+}
+
+// Map each line in the base to its matching line in the other side,
+// if the line was left unchanged
+fn match_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        if let DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = op
+        {
+            for offset in 0..len {
+                matches[old_index + offset] = Some(new_index + offset);
+            }
+        }
+    }
+
+    matches
+}
+
+fn push_lines(content: &mut String, lines: &[&str]) {
+    for line in lines {
+        content.push_str(line);
+    }
+}
+
+fn push_marker(content: &mut String, marker: &str) {
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+
+    content.push_str(marker);
+    content.push('\n');
+}
+
+/// Merge the changes between the `baseline` and `next` content into the
+/// `current` content, line by line (diff3). When both sides changed the same
+/// lines differently, the hunk is wrapped in Git-style conflict markers.
+pub fn merge_three_way(baseline: &str, current: &str, next: &str) -> MergeResult {
+    let base_lines = baseline.split_inclusive('\n').collect::<Vec<_>>();
+    let current_lines = current.split_inclusive('\n').collect::<Vec<_>>();
+    let next_lines = next.split_inclusive('\n').collect::<Vec<_>>();
+
+    let current_matches = match_lines(&base_lines, &current_lines);
+    let next_matches = match_lines(&base_lines, &next_lines);
+
+    let mut result = MergeResult::default();
+    let (mut b, mut c, mut n) = (0, 0, 0);
+
+    loop {
+        // Find the next line that is unchanged on both sides
+        let stable = (b..base_lines.len()).find_map(|index| {
+            match (current_matches[index], next_matches[index]) {
+                (Some(ci), Some(ni)) if ci >= c && ni >= n => Some((index, ci, ni)),
+                _ => None,
+            }
+        });
+
+        if let Some((index, ci, ni)) = stable
+            && index == b
+            && ci == c
+            && ni == n
+        {
+            result.content.push_str(base_lines[b]);
+            b += 1;
+            c += 1;
+            n += 1;
+            continue;
+        }
+
+        let (b_end, c_end, n_end) =
+            stable.unwrap_or((base_lines.len(), current_lines.len(), next_lines.len()));
+
+        let base_hunk = &base_lines[b..b_end];
+        let current_hunk = &current_lines[c..c_end];
+        let next_hunk = &next_lines[n..n_end];
+
+        if current_hunk == base_hunk || current_hunk == next_hunk {
+            push_lines(&mut result.content, next_hunk);
+        } else if next_hunk == base_hunk {
+            push_lines(&mut result.content, current_hunk);
+        } else {
+            push_marker(&mut result.content, CONFLICT_START);
+            push_lines(&mut result.content, current_hunk);
+            push_marker(&mut result.content, CONFLICT_SEPARATOR);
+            push_lines(&mut result.content, next_hunk);
+            push_marker(&mut result.content, CONFLICT_END);
+
+            result.conflicts += 1;
+        }
+
+        if stable.is_none() {
+            break;
+        }
+
+        b = b_end;
+        c = c_end;
+        n = n_end;
+    }
+
+    result
+}
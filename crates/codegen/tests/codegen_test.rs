@@ -1,4 +1,4 @@
-use moon_codegen::CodeGenerator;
+use moon_codegen::{CodeGenerator, Template};
 use moon_common::Id;
 use moon_config::{
     FilePath, GeneratorConfig, GlobPath, TemplateLocator, TemplateVariable, Version,
@@ -380,4 +380,70 @@ mod codegen {
             }
         }
     }
+
+    mod is_remote_template {
+        use super::*;
+
+        #[tokio::test]
+        async fn returns_false_for_workspace_templates() {
+            let sandbox = create_sandbox("generator");
+            let config = GeneratorConfig::default();
+
+            let mut codegen = CodeGenerator::new(
+                sandbox.path(),
+                &config,
+                MoonEnvironment::new_testing(sandbox.path()).into(),
+            );
+            codegen.load_templates().await.unwrap();
+
+            let template = codegen.get_template("one").unwrap();
+
+            assert!(!codegen.is_remote_template(&template));
+        }
+
+        #[test]
+        fn returns_true_for_downloaded_templates() {
+            let sandbox = create_empty_sandbox();
+            let env = Arc::new(MoonEnvironment::new_testing(sandbox.path()));
+            let root = env.templates_dir.join("npm").join("example");
+            let config = GeneratorConfig::default();
+
+            sandbox.create_file(
+                ".moon/templates/npm/example/template.yml",
+                "title: Example\ndescription: Example",
+            );
+
+            let codegen = CodeGenerator::new(sandbox.path(), &config, Arc::clone(&env));
+            let template = Template::new(Id::raw("example"), root).unwrap();
+
+            assert!(codegen.is_remote_template(&template));
+        }
+
+        #[test]
+        fn returns_true_when_extending_downloaded_templates() {
+            let sandbox = create_empty_sandbox();
+            let env = Arc::new(MoonEnvironment::new_testing(sandbox.path()));
+            let root = env.templates_dir.join("npm").join("example");
+            let config = GeneratorConfig::default();
+
+            sandbox.create_file(
+                ".moon/templates/npm/example/template.yml",
+                "title: Example\ndescription: Example",
+            );
+            sandbox.create_file(
+                "templates/local/template.yml",
+                "title: Local\ndescription: Local",
+            );
+
+            let codegen = CodeGenerator::new(sandbox.path(), &config, Arc::clone(&env));
+            let mut template =
+                Template::new(Id::raw("local"), sandbox.path().join("templates/local")).unwrap();
+
+            assert!(!codegen.is_remote_template(&template));
+
+            template.extend_template(Template::new(Id::raw("example"), root).unwrap());
+
+            assert!(codegen.is_remote_template(&template));
+        }
+    }
 }
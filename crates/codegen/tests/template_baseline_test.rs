@@ -0,0 +1,153 @@
+use moon_codegen::{
+    BASELINE_FILE_NAME, FileState, Template, TemplateBaseline, TemplateBaselines, TemplateContext,
+};
+use moon_common::Id;
+use starbase_sandbox::{create_empty_sandbox, locate_fixture};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+fn create_baseline(files: &[(&str, &str)]) -> TemplateBaseline {
+    TemplateBaseline {
+        files: BTreeMap::from_iter(
+            files
+                .iter()
+                .map(|(path, content)| (path.to_string(), content.to_string())),
+        ),
+    }
+}
+
+fn create_template(dest: &Path) -> Template {
+    let mut context = TemplateContext::new();
+    context.insert("string", "string");
+    context.insert("number", &123);
+    context.insert("bool", &true);
+
+    let mut template = Template::new(Id::raw("standard"), locate_fixture("template")).unwrap();
+    template.load_files(dest, &context).unwrap();
+    template
+}
+
+mod template_baselines {
+    use super::*;
+
+    #[test]
+    fn defaults_when_file_doesnt_exist() {
+        let sandbox = create_empty_sandbox();
+
+        assert_eq!(
+            TemplateBaselines::load(sandbox.path()).unwrap(),
+            TemplateBaselines::default()
+        );
+    }
+
+    #[test]
+    fn keys_baselines_by_template() {
+        let sandbox = create_empty_sandbox();
+        let mut baselines = TemplateBaselines::default();
+
+        baselines.insert(Id::raw("a"), create_baseline(&[("file.txt", "a")]));
+        baselines.insert(Id::raw("b"), create_baseline(&[("file.txt", "b")]));
+        baselines.save(sandbox.path()).unwrap();
+
+        assert!(sandbox.path().join(BASELINE_FILE_NAME).exists());
+
+        let baselines = TemplateBaselines::load(sandbox.path()).unwrap();
+
+        assert_eq!(
+            baselines.get(&Id::raw("a")).unwrap(),
+            &create_baseline(&[("file.txt", "a")])
+        );
+        assert_eq!(
+            baselines.get(&Id::raw("b")).unwrap(),
+            &create_baseline(&[("file.txt", "b")])
+        );
+        assert!(baselines.get(&Id::raw("c")).is_none());
+    }
+
+    #[test]
+    fn replaces_baseline_of_same_template() {
+        let sandbox = create_empty_sandbox();
+        let mut baselines = TemplateBaselines::default();
+
+        baselines.insert(Id::raw("a"), create_baseline(&[("file.txt", "a")]));
+        baselines.insert(Id::raw("b"), create_baseline(&[("file.txt", "b")]));
+        baselines.save(sandbox.path()).unwrap();
+
+        let mut baselines = TemplateBaselines::load(sandbox.path()).unwrap();
+        baselines.insert(Id::raw("a"), create_baseline(&[("other.txt", "a2")]));
+        baselines.save(sandbox.path()).unwrap();
+
+        let baselines = TemplateBaselines::load(sandbox.path()).unwrap();
+
+        assert_eq!(
+            baselines.get(&Id::raw("a")).unwrap(),
+            &create_baseline(&[("other.txt", "a2")])
+        );
+        assert_eq!(
+            baselines.get(&Id::raw("b")).unwrap(),
+            &create_baseline(&[("file.txt", "b")])
+        );
+    }
+}
+
+mod template_baseline {
+    use super::*;
+
+    #[test]
+    fn returns_content_relative_to_dest() {
+        let sandbox = create_empty_sandbox();
+        let baseline = create_baseline(&[("nested/file.txt", "content")]);
+
+        assert_eq!(
+            baseline.get_content(sandbox.path(), &sandbox.path().join("nested/file.txt")),
+            Some("content")
+        );
+        assert_eq!(
+            baseline.get_content(sandbox.path(), &sandbox.path().join("missing.txt")),
+            None
+        );
+    }
+
+    #[test]
+    fn records_written_files_and_keeps_skipped_files() {
+        let sandbox = create_empty_sandbox();
+        let mut template = create_template(sandbox.path());
+
+        for file in template.files.values_mut() {
+            file.state = if file.dest_path.ends_with("file.txt") {
+                FileState::Skip
+            } else {
+                FileState::Replace
+            };
+        }
+
+        let previous = create_baseline(&[("file.txt", "previous"), ("removed.txt", "removed")]);
+        let baseline =
+            TemplateBaseline::from_template(&template, sandbox.path(), Some(&previous)).unwrap();
+
+        assert_eq!(
+            baseline.files.keys().collect::<Vec<_>>(),
+            [
+                "file.raw.txt",
+                "file.ts",
+                "file.txt",
+                "folder/nested-file.ts"
+            ]
+        );
+        assert_eq!(baseline.files["file.txt"], "previous");
+    }
+
+    #[test]
+    fn doesnt_record_skipped_files_without_previous() {
+        let sandbox = create_empty_sandbox();
+        let mut template = create_template(sandbox.path());
+
+        for file in template.files.values_mut() {
+            file.state = FileState::Skip;
+        }
+
+        let baseline = TemplateBaseline::from_template(&template, sandbox.path(), None).unwrap();
+
+        assert!(baseline.files.is_empty());
+    }
+}
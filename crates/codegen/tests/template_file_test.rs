@@ -138,3 +138,49 @@ mod template_file {
         }
     }
 }
+
+mod apply_update {
+    use super::*;
+    use moon_codegen::FileState;
+
+    fn create_file(content: &str) -> TemplateFile {
+        let mut file = TemplateFile::new(RelativePathBuf::from("file.txt"), PathBuf::new());
+        file.content = content.into();
+        file
+    }
+
+    #[test]
+    fn skips_when_template_unchanged() {
+        let mut file = create_file("a\nb\n");
+        file.apply_update("a\nb\n", "a\nlocal\n");
+
+        assert_eq!(file.state, FileState::Skip);
+    }
+
+    #[test]
+    fn replaces_when_no_local_changes() {
+        let mut file = create_file("a\nnew\n");
+        file.apply_update("a\nb\n", "a\nb\n");
+
+        assert_eq!(file.state, FileState::Update);
+        assert_eq!(file.content, "a\nnew\n");
+    }
+
+    #[test]
+    fn merges_both_changes() {
+        let mut file = create_file("a\nb\nc\nNEW\n");
+        file.apply_update("a\nb\nc\n", "LOCAL\nb\nc\n");
+
+        assert_eq!(file.state, FileState::Update);
+        assert_eq!(file.content, "LOCAL\nb\nc\nNEW\n");
+    }
+
+    #[test]
+    fn marks_conflicts() {
+        let mut file = create_file("a\nnew\n");
+        file.apply_update("a\nb\n", "a\nlocal\n");
+
+        assert_eq!(file.state, FileState::Conflict);
+        assert!(file.content.contains("<<<<<<< current"));
+    }
+}
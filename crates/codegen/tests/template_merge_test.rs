@@ -0,0 +1,94 @@
+use moon_codegen::{MergeResult, merge_three_way};
+
+mod merge_three_way {
+    use super::*;
+
+    #[test]
+    fn returns_current_when_next_is_unchanged() {
+        assert_eq!(
+            merge_three_way("a\nb\nc\n", "a\nB\nc\n", "a\nb\nc\n"),
+            MergeResult {
+                content: "a\nB\nc\n".into(),
+                conflicts: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn returns_next_when_current_is_unchanged() {
+        assert_eq!(
+            merge_three_way("a\nb\nc\n", "a\nb\nc\n", "a\nb\nC\n"),
+            MergeResult {
+                content: "a\nb\nC\n".into(),
+                conflicts: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn merges_changes_to_different_lines() {
+        assert_eq!(
+            merge_three_way(
+                "one\ntwo\nthree\nfour\nfive\n",
+                "ONE\ntwo\nthree\nfour\nfive\n",
+                "one\ntwo\nthree\nfour\nFIVE\nsix\n"
+            ),
+            MergeResult {
+                content: "ONE\ntwo\nthree\nfour\nFIVE\nsix\n".into(),
+                conflicts: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn merges_insertions_and_deletions() {
+        assert_eq!(
+            merge_three_way("a\nb\nc\nd\n", "a\nlocal\nb\nc\nd\n", "a\nb\nd\n"),
+            MergeResult {
+                content: "a\nlocal\nb\nd\n".into(),
+                conflicts: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn accepts_identical_changes() {
+        assert_eq!(
+            merge_three_way("a\nb\nc\n", "a\nx\nc\n", "a\nx\nc\n"),
+            MergeResult {
+                content: "a\nx\nc\n".into(),
+                conflicts: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn writes_conflict_markers() {
+        assert_eq!(
+            merge_three_way("a\nb\nc\n", "a\nlocal\nc\n", "a\ntemplate\nc\n"),
+            MergeResult {
+                content: "a\n<<<<<<< current\nlocal\n=======\ntemplate\n>>>>>>> template\nc\n"
+                    .into(),
+                conflicts: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn writes_conflict_markers_without_trailing_newline() {
+        assert_eq!(
+            merge_three_way("a\nb", "a\nlocal", "a\ntemplate"),
+            MergeResult {
+                content: "a\n<<<<<<< current\nlocal\n=======\ntemplate\n>>>>>>> template\n".into(),
+                conflicts: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn counts_each_conflict() {
+        let result = merge_three_way("a\nb\nc\nd\ne\n", "a\nB1\nc\nD1\ne\n", "a\nB2\nc\nD2\ne\n");
+
+        assert_eq!(result.conflicts, 2);
+    }
+}
@@ -425,4 +425,248 @@ mod template {
             assert_snapshot!(file.content);
         }
     }
+    mod post_generate {
+        use super::*;
+        use moon_codegen::{PostGenerateAction, PostGenerateState, PostGenerateStep};
+        use moon_console::Console;
+        use starbase_sandbox::create_empty_sandbox;
+        use std::sync::Arc;
+
+        fn create_post_generate_template(root: &std::path::Path) -> Template {
+            std::fs::write(
+                root.join("template.yml"),
+                r#"
+title: title
+description: description
+postGenerate:
+  - echo {{ string }}
+  - command: exit 1
+    allowFailure: true
+  - target: "{{ string }}:build"
+"#,
+            )
+            .unwrap();
+
+            Template::new(Id::raw("post"), root.to_path_buf()).unwrap()
+        }
+
+        #[test]
+        fn loads_and_interpolates_steps() {
+            let sandbox = create_empty_sandbox();
+            let mut template = create_post_generate_template(sandbox.path());
+
+            assert_eq!(
+                template
+                    .load_post_generate_steps(&create_context())
+                    .unwrap(),
+                vec![
+                    PostGenerateStep {
+                        action: PostGenerateAction::Command("echo string".into()),
+                        allow_failure: false,
+                    },
+                    PostGenerateStep {
+                        action: PostGenerateAction::Command("exit 1".into()),
+                        allow_failure: true,
+                    },
+                    PostGenerateStep {
+                        action: PostGenerateAction::Target("string:build".into()),
+                        allow_failure: false,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn errors_if_step_has_no_action() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(
+                "template.yml",
+                "title: title\ndescription: description\npostGenerate:\n  - allowFailure: true",
+            );
+
+            let mut template =
+                Template::new(Id::raw("post"), sandbox.path().to_path_buf()).unwrap();
+
+            assert!(
+                template
+                    .load_post_generate_steps(&create_context())
+                    .is_err()
+            );
+        }
+
+        #[tokio::test]
+        async fn runs_steps_until_a_failure() {
+            let sandbox = create_empty_sandbox();
+            let config = GeneratorConfig::default();
+            let generator = CodeGenerator::new(
+                sandbox.path(),
+                &config,
+                MoonEnvironment::new_testing(sandbox.path()).into(),
+            );
+
+            let results = generator
+                .run_post_generate(
+                    vec![
+                        PostGenerateStep {
+                            action: PostGenerateAction::Command("exit 1".into()),
+                            allow_failure: true,
+                        },
+                        PostGenerateStep {
+                            action: PostGenerateAction::Command("exit 2".into()),
+                            allow_failure: false,
+                        },
+                        PostGenerateStep {
+                            action: PostGenerateAction::Command("echo unreachable".into()),
+                            allow_failure: false,
+                        },
+                    ],
+                    sandbox.path(),
+                    false,
+                    Arc::new(Console::new_testing()),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|result| (result.state.clone(), result.exit_code))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (PostGenerateState::Failed, Some(1)),
+                    (PostGenerateState::Failed, Some(2)),
+                    (PostGenerateState::Skipped, None),
+                ]
+            );
+            assert!(!results[0].is_fatal());
+            assert!(results[1].is_fatal());
+        }
+
+        #[tokio::test]
+        async fn records_stderr_of_failed_steps() {
+            let sandbox = create_empty_sandbox();
+            let config = GeneratorConfig::default();
+            let generator = CodeGenerator::new(
+                sandbox.path(),
+                &config,
+                MoonEnvironment::new_testing(sandbox.path()).into(),
+            );
+
+            let results = generator
+                .run_post_generate(
+                    vec![PostGenerateStep {
+                        action: PostGenerateAction::Command("echo broken >&2 && exit 3".into()),
+                        allow_failure: false,
+                    }],
+                    sandbox.path(),
+                    false,
+                    Arc::new(Console::new_testing()),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(results[0].state, PostGenerateState::Failed);
+            assert_eq!(results[0].stderr, "broken");
+        }
+
+        #[tokio::test]
+        async fn skips_all_steps_in_dry_run() {
+            let sandbox = create_empty_sandbox();
+            let config = GeneratorConfig::default();
+            let generator = CodeGenerator::new(
+                sandbox.path(),
+                &config,
+                MoonEnvironment::new_testing(sandbox.path()).into(),
+            );
+
+            let results = generator
+                .run_post_generate(
+                    vec![PostGenerateStep {
+                        action: PostGenerateAction::Command("touch file".into()),
+                        allow_failure: false,
+                    }],
+                    sandbox.path(),
+                    true,
+                    Arc::new(Console::new_testing()),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(results[0].state, PostGenerateState::Skipped);
+            assert!(!sandbox.path().join("file").exists());
+        }
+    }
+    mod file_conditions {
+        use super::*;
+        use starbase_sandbox::create_empty_sandbox;
+
+        fn load_names(context: &TemplateContext) -> (Vec<String>, Vec<String>) {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(
+                "template.yml",
+                r"
+title: title
+description: description
+files:
+  'docker/**': withDocker
+  'graphql.ts': api == 'graphql'
+  'rest.ts': api == 'rest'
+",
+            );
+            sandbox.create_file("docker/Dockerfile", "FROM scratch");
+            sandbox.create_file("graphql.ts", "");
+            sandbox.create_file("index.ts", "");
+            sandbox.create_file("rest.ts", "");
+
+            let mut template =
+                Template::new(Id::raw("conditions"), sandbox.path().to_path_buf()).unwrap();
+            template
+                .load_files(&sandbox.path().join("out"), context)
+                .unwrap();
+
+            (
+                template.files.keys().map(|name| name.to_string()).collect(),
+                template
+                    .excluded
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect(),
+            )
+        }
+
+        #[test]
+        fn excludes_files_when_conditions_fail() {
+            let mut context = TemplateContext::new();
+            context.insert("withDocker", &false);
+            context.insert("api", "rest");
+
+            let (files, mut excluded) = load_names(&context);
+            excluded.sort();
+
+            assert_eq!(files, vec!["index.ts", "rest.ts"]);
+            assert_eq!(excluded, vec!["docker/Dockerfile", "graphql.ts"]);
+        }
+
+        #[test]
+        fn includes_files_when_conditions_pass() {
+            let mut context = TemplateContext::new();
+            context.insert("withDocker", &true);
+            context.insert("api", "graphql");
+
+            let (files, excluded) = load_names(&context);
+
+            assert_eq!(files, vec!["docker/Dockerfile", "graphql.ts", "index.ts"]);
+            assert_eq!(excluded, vec!["rest.ts"]);
+        }
+
+        #[test]
+        fn excludes_when_variable_is_undefined() {
+            let mut context = TemplateContext::new();
+            context.insert("api", "rest");
+
+            let (files, _) = load_names(&context);
+
+            assert!(!files.contains(&"docker/Dockerfile".to_owned()));
+        }
+    }
 }
@@ -1,7 +1,9 @@
 use crate::{config_enum, config_struct, config_unit_enum, generate_switch};
 use deserialize_untagged_verbose_error::DeserializeUntaggedVerboseError;
 use schematic::schema::{StringType, UnionType};
-use schematic::{Config, ParseError, Schema, SchemaBuilder, Schematic, ValidateError};
+use schematic::{
+    Config, ParseError, RegexSetting, Schema, SchemaBuilder, Schematic, ValidateError,
+};
 use serde::{Deserialize, Serialize, Serializer};
 
 fn check_script(script: &str) -> Result<(), String> {
@@ -89,6 +91,39 @@ config_unit_enum!(
     }
 );
 
+config_struct!(
+    /// Readiness probes for a persistent task. Persistent tasks that depend
+    /// on this task will not start until all configured probes have passed.
+    #[derive(Config)]
+    pub struct TaskReadinessConfig {
+        /// A file path, relative from the task's working directory, that
+        /// must exist.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub file: Option<String>,
+
+        /// A URL that must respond with a 2xx status code.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub http: Option<String>,
+
+        /// The interval in milliseconds between each probe.
+        #[setting(default = 250)]
+        pub interval: u64,
+
+        /// A regex pattern that a line of the task's output must match.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub log: Option<RegexSetting>,
+
+        /// A port on localhost that must accept TCP connections.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub port: Option<u16>,
+
+        /// The number of seconds to wait for all probes to pass, before
+        /// failing the task.
+        #[setting(default = 60)]
+        pub timeout: u64,
+    }
+);
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
 #[serde(try_from = "TaskCheckShape")]
 pub enum TaskCheck {
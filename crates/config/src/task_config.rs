@@ -1,11 +1,12 @@
 use crate::shapes::{Input, OneOrMany, Output};
-use crate::task::TaskCheck;
+use crate::task::{PartialTaskReadinessConfig, TaskCheck, TaskReadinessConfig};
 use crate::task_options_config::{PartialTaskOptionsConfig, TaskOptionsConfig};
 use crate::{config_enum, config_struct, config_unit_enum};
 use indexmap::IndexMap;
 use moon_common::Id;
 use moon_target::{Target, TargetProjectScope};
 use schematic::{Config, ConfigEnum, ValidateError, merge};
+use std::collections::BTreeMap;
 
 pub type EnvMap = IndexMap<String, Option<String>>;
 
@@ -217,6 +218,23 @@ impl TaskDependency {
     }
 }
 
+config_struct!(
+    /// Configures a variant of a task matrix.
+    /// @since 2.6.0
+    #[derive(Config)]
+    pub struct TaskMatrixVariantConfig {
+        /// Additional arguments to pass to the command when this variant is ran.
+        /// Can be defined as a string, or a list of individual arguments.
+        #[setting(nested)]
+        pub args: TaskArgs,
+
+        /// A map of environment variables that will be set in the child
+        /// process when this variant is ran.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub env: Option<EnvMap>,
+    }
+);
+
 config_struct!(
     /// Configures a task to be ran within the action pipeline.
     #[derive(Config)]
@@ -281,6 +299,15 @@ config_struct!(
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub outputs: Option<Vec<Output>>,
 
+        /// Expands the task into multiple variants, keyed by a unique identifier,
+        /// each with their own arguments and environment variables. Each variant
+        /// becomes a task with the identifier `<task>-<variant>`, while the
+        /// original task becomes an aggregate that depends on all variants.
+        /// @since 2.6.0
+        #[setting(nested)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub matrix: Option<BTreeMap<Id, TaskMatrixVariantConfig>>,
+
         /// Options to control task inheritance, execution, and more.
         #[setting(nested)]
         pub options: TaskOptionsConfig,
@@ -289,6 +316,13 @@ config_struct!(
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub preset: Option<TaskPreset>,
 
+        /// Readiness probes for persistent tasks. Persistent tasks that depend
+        /// on this task will wait for all probes to pass before starting.
+        /// @since 2.6.0
+        #[setting(nested)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub readiness: Option<TaskReadinessConfig>,
+
         /// A script to run within a shell. A script is anything from a single command,
         /// to multiple commands, or shell specific syntax. Does not support
         /// arguments, merging, or inheritance. This overrides `command` and `args`.
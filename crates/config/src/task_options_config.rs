@@ -1,6 +1,8 @@
 use crate::merging::MergeStrategy;
 use crate::shapes::{FilePath, GlobPath, OneOrMany};
 use crate::{config_enum, config_struct, config_unit_enum, config_untagged_enum, generate_switch};
+use moon_common::Id;
+use rustc_hash::FxHashMap;
 use schematic::schema::{StringType, UnionType};
 use schematic::{Config, ConfigEnum, Schema, SchemaBuilder, Schematic, ValidateError};
 use std::env::consts;
@@ -370,6 +372,14 @@ config_struct!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub priority: Option<TaskPriority>,
 
+        /// A mapping of resource pools to the weight (number of permits) this
+        /// task acquires from each pool before running. The `cpu` pool is
+        /// reserved for the pipeline's concurrency, while other pools are
+        /// configured with `pipeline.resourcePools`.
+        /// @since 2.6.0
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub resources: Option<FxHashMap<Id, u32>>,
+
         /// The number of times a failing task will be retried to succeed.
         #[setting(env = "MOON_RETRY_COUNT")]
         #[serde(skip_serializing_if = "Option::is_none")]
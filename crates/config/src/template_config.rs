@@ -264,6 +264,57 @@ impl TemplateVariable {
     }
 }
 
+config_struct!(
+    /// Configures a step to run after a template has been generated.
+    #[derive(Config)]
+    pub struct TemplatePostGenerateConfig {
+        /// Continue running subsequent steps when this step fails.
+        #[serde(default, skip_serializing_if = "is_false")]
+        pub allow_failure: bool,
+
+        /// A shell command to run in the destination directory.
+        /// Supports variable interpolation.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub command: Option<String>,
+
+        /// A target to run with `moon run` from the destination directory.
+        /// A task identifier without a project runs in the generated project.
+        /// Supports variable interpolation.
+        #[setting(validate = validate_post_generate_target)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub target: Option<String>,
+    }
+);
+
+fn validate_post_generate_target<C>(
+    _target: &String,
+    partial: &PartialTemplatePostGenerateConfig,
+    _context: &C,
+    _finalize: bool,
+) -> Result<(), ValidateError> {
+    if partial.command.is_some() {
+        return Err(ValidateError::new(
+            "a step cannot define both a `command` and a `target`",
+        ));
+    }
+
+    Ok(())
+}
+
+config_enum!(
+    /// A step to run after a template has been generated.
+    #[derive(Config)]
+    #[serde(untagged)]
+    pub enum TemplatePostGenerateStep {
+        /// A shell command to run in the destination directory.
+        Command(String),
+
+        /// A shell command or target, with additional options.
+        #[setting(nested)]
+        Object(TemplatePostGenerateConfig),
+    }
+);
+
 config_struct!(
     /// Configures a template and its files to be scaffolded.
     /// Docs: https://moonrepo.dev/docs/config/template
@@ -285,10 +336,22 @@ config_struct!(
         #[serde(default, skip_serializing_if = "OneOrMany::is_empty")]
         pub extends: OneOrMany<Id>,
 
+        /// A map of file paths or globs, relative from the template root, to a
+        /// condition that must be truthy for matching files to be generated.
+        /// Conditions are Tera expressions that can reference variables.
+        #[serde(default, skip_serializing_if = "FxHashMap::is_empty")]
+        pub files: FxHashMap<String, String>,
+
         /// Overrides the identifier of the template, instead of using the folder name.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub id: Option<Id>,
 
+        /// A list of shell commands or targets to run, in order, after
+        /// the template files have been written to the destination.
+        #[setting(nested)]
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub post_generate: Vec<TemplatePostGenerateStep>,
+
         /// A human-readable title for the template.
         #[setting(validate = validate::not_empty)]
         pub title: String,
@@ -1,6 +1,37 @@
-use crate::{config_enum, config_struct, is_false};
+use crate::{config_enum, config_struct, generate_switch, is_false};
 use moon_common::Id;
-use schematic::{Config, env};
+use moon_target::Target;
+use rustc_hash::FxHashMap;
+use schematic::schema::{StringType, UnionType};
+use schematic::{Config, Schema, SchemaBuilder, Schematic, env};
+
+config_enum!(
+    /// The strategy for auditing the files that a task reads and writes.
+    #[serde(expecting = "expected `snapshot`, `trace`, or a boolean")]
+    pub enum PipelineAuditTaskFiles {
+        /// Compare snapshots of the file tree before and after a run.
+        Snapshot,
+        /// Compare snapshots, and also trace file opens with `strace` (Linux only).
+        Trace,
+        /// Whether to audit with snapshots.
+        #[serde(untagged)]
+        Enabled(bool),
+    }
+);
+
+generate_switch!(PipelineAuditTaskFiles, ["snapshot", "trace"]);
+
+impl Default for PipelineAuditTaskFiles {
+    fn default() -> Self {
+        Self::Enabled(false)
+    }
+}
+
+impl PipelineAuditTaskFiles {
+    pub fn is_tracing(&self) -> bool {
+        matches!(self, Self::Trace)
+    }
+}
 
 config_enum!(
     /// Toggles the state of actions within the pipeline.
@@ -41,6 +72,12 @@ config_struct!(
     /// Configures aspects of the action pipeline.
     #[derive(Config)]
     pub struct PipelineConfig {
+        /// Audit the files that a task reads and writes while running, and
+        /// warn about files that are not declared as `inputs` or `outputs`.
+        /// @since 2.6.0
+        #[serde(default)]
+        pub audit_task_files: PipelineAuditTaskFiles,
+
         /// Automatically clean the cache after every task run.
         /// @since 1.24.0
         #[setting(default = true, env = "MOON_PIPELINE_AUTO_CLEAN_CACHE", parse_env = env::parse_bool)]
@@ -70,6 +107,20 @@ config_struct!(
         #[serde(default, skip_serializing_if = "is_false")]
         pub log_running_command: bool,
 
+        /// A list of task targets that are quarantined. Failures of quarantined
+        /// tasks are reported, but do not fail the pipeline. Supports
+        /// fully-qualified targets, and all project scoped targets (`:task`).
+        /// @since 2.6.0
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub quarantine: Vec<Target>,
+
+        /// A mapping of named resource pools to their capacity. Tasks acquire
+        /// weighted permits from these pools (via the `resources` task option)
+        /// before running. Pools that are not configured have a capacity of 1.
+        /// @since 2.6.0
+        #[serde(default, skip_serializing_if = "FxHashMap::is_empty")]
+        pub resource_pools: FxHashMap<Id, u32>,
+
         /// Run the `SyncProject` actions in the pipeline for each owning project
         /// of a running task.
         /// @since 1.34.0
@@ -88,3 +139,15 @@ config_struct!(
         pub sync_workspace: bool,
     }
 );
+
+impl PipelineConfig {
+    /// Return true if the provided target has been quarantined.
+    pub fn is_quarantined(&self, target: &Target) -> bool {
+        self.quarantine.iter().any(|quarantined| {
+            quarantined == target
+                || target
+                    .get_task_id()
+                    .is_ok_and(|task_id| quarantined.is_all_task(task_id))
+        })
+    }
+}
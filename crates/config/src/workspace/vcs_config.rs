@@ -1,6 +1,7 @@
 use crate::{config_struct, config_unit_enum, is_false};
 use rustc_hash::FxHashMap;
 use schematic::{Config, ConfigEnum};
+use warpgate_api::PluginLocator;
 
 config_unit_enum!(
     /// The VCS being utilized by the repository.
@@ -62,6 +63,13 @@ config_struct!(
         /// @since 1.29.0
         pub hook_format: VcsHookFormat,
 
+        /// Location of a WASM plugin that implements the VCS client,
+        /// for example, Jujutsu or Sapling. When configured, the plugin
+        /// is used instead of the built-in `client`.
+        /// @since 2.6.0
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub plugin: Option<PluginLocator>,
+
         /// The upstream version control provider, where the repository
         /// source code is stored.
         /// @since 1.8.0
@@ -84,5 +92,6 @@ impl VcsConfig {
     pub fn should_invalidate(&self, other: &Self) -> bool {
         self.default_branch != other.default_branch
             || self.remote_candidates != other.remote_candidates
+            || self.plugin != other.plugin
     }
 }
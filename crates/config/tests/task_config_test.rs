@@ -6,10 +6,12 @@ use moon_config::{
     FileGroupInput, FileGroupInputFormat, FilePath, Input, MergeStrategy, OneOrMany, Output,
     ProjectInput, TaskArgs, TaskCheck, TaskCheckConditionConfig, TaskCheckFingerprint,
     TaskCheckFingerprintConfig, TaskCheckRequirementConfig, TaskConfig, TaskDependency,
-    TaskDependencyCacheStrategy, TaskDependencyConfig, TaskOptionCache, TaskOutputStyle, TaskType,
+    TaskDependencyCacheStrategy, TaskDependencyConfig, TaskMatrixVariantConfig, TaskOptionCache,
+    TaskOutputStyle, TaskReadinessConfig, TaskType,
 };
 use moon_target::Target;
 use schematic::{ConfigLoader as BaseLoader, RegexSetting};
+use std::collections::BTreeMap;
 use utils::*;
 
 fn load_config_from_code(code: &str) -> miette::Result<TaskConfig> {
@@ -24,7 +26,7 @@ mod task_config {
 
     #[test]
     #[should_panic(
-        expected = "unknown field `unknown`, expected one of `extends`, `description`, `command`, `args`, `checks`, `dependsOn`, `deps`, `env`, `inputs`, `outputs`, `options`, `preset`, `script`, `tags`, `toolchain`, `toolchains`, `type`"
+        expected = "unknown field `unknown`, expected one of `extends`, `description`, `command`, `args`, `checks`, `dependsOn`, `deps`, `env`, `inputs`, `outputs`, `options`, `preset`, `readiness`, `script`, `tags`, `toolchain`, `toolchains`, `type`"
     )]
     fn error_unknown_field() {
         test_parse_config("unknown: 123", load_config_from_code);
@@ -1012,6 +1014,138 @@ checks:
         }
     }
 
+    mod matrix {
+        use super::*;
+
+        #[test]
+        fn defaults_to_none() {
+            let config = test_parse_config("{}", load_config_from_code);
+
+            assert_eq!(config.matrix, None);
+        }
+
+        #[test]
+        fn supports_variants() {
+            let config = test_parse_config(
+                r"
+matrix:
+  node20:
+    args: '--shard 1/2'
+    env:
+      NODE_VERSION: '20'
+  node22:
+    args: ['--shard', '2/2']
+  empty: {}
+",
+                load_config_from_code,
+            );
+
+            assert_eq!(
+                config.matrix,
+                Some(BTreeMap::from_iter([
+                    (
+                        Id::raw("empty"),
+                        TaskMatrixVariantConfig {
+                            args: TaskArgs::Noop,
+                            env: None,
+                        }
+                    ),
+                    (
+                        Id::raw("node20"),
+                        TaskMatrixVariantConfig {
+                            args: TaskArgs::String("--shard 1/2".into()),
+                            env: Some(IndexMap::from_iter([(
+                                "NODE_VERSION".into(),
+                                Some("20".into())
+                            )])),
+                        }
+                    ),
+                    (
+                        Id::raw("node22"),
+                        TaskMatrixVariantConfig {
+                            args: TaskArgs::List(vec!["--shard".into(), "2/2".into()]),
+                            env: None,
+                        }
+                    ),
+                ]))
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "Invalid identifier format for `node 20`")]
+        fn errors_on_invalid_variant_id() {
+            test_parse_config("matrix:\n  'node 20': {}", load_config_from_code);
+        }
+    }
+
+    mod readiness {
+        use super::*;
+
+        #[test]
+        fn defaults_to_none() {
+            let config = test_parse_config("{}", load_config_from_code);
+
+            assert_eq!(config.readiness, None);
+        }
+
+        #[test]
+        fn sets_default_interval_and_timeout() {
+            let config = test_parse_config("readiness:\n  port: 5432", load_config_from_code);
+
+            assert_eq!(
+                config.readiness,
+                Some(TaskReadinessConfig {
+                    file: None,
+                    http: None,
+                    interval: 250,
+                    log: None,
+                    port: Some(5432),
+                    timeout: 60,
+                })
+            );
+        }
+
+        #[test]
+        fn supports_all_probes() {
+            let config = test_parse_config(
+                r"
+readiness:
+  file: '.ready'
+  http: 'http://localhost:3000/health'
+  interval: 100
+  log: 'listening on \d+'
+  port: 3000
+  timeout: 10
+",
+                load_config_from_code,
+            );
+
+            assert_eq!(
+                config.readiness,
+                Some(TaskReadinessConfig {
+                    file: Some(".ready".into()),
+                    http: Some("http://localhost:3000/health".into()),
+                    interval: 100,
+                    log: Some(RegexSetting::new("listening on \\d+").unwrap()),
+                    port: Some(3000),
+                    timeout: 10,
+                })
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "regex parse error")]
+        fn errors_on_invalid_log_pattern() {
+            test_parse_config("readiness:\n  log: '[a-'", load_config_from_code);
+        }
+
+        #[test]
+        #[should_panic(expected = "invalid value: integer `100000`")]
+        fn errors_on_invalid_port() {
+            test_parse_config("readiness:\n  port: 100000", load_config_from_code);
+        }
+    }
+
     mod type_of {
         use super::*;
 
@@ -1461,6 +1595,45 @@ options:
             }
         }
 
+        mod resources {
+            use super::*;
+            use rustc_hash::FxHashMap;
+
+            #[test]
+            fn can_set_weights() {
+                let config = test_parse_config(
+                    r"
+options:
+  resources:
+    cpu: 2
+    gpu-like: 1
+",
+                    load_config_from_code,
+                );
+
+                assert_eq!(
+                    config.options.resources,
+                    Some(FxHashMap::from_iter([
+                        (Id::raw("cpu"), 2),
+                        (Id::raw("gpu-like"), 1)
+                    ]))
+                );
+            }
+
+            #[test]
+            #[should_panic(expected = "invalid type: string")]
+            fn errors_for_invalid_weight() {
+                test_parse_config(
+                    r"
+options:
+  resources:
+    cpu: many
+",
+                    load_config_from_code,
+                );
+            }
+        }
+
         mod os {
             use super::*;
             use moon_config::TaskOperatingSystem;
@@ -16,7 +16,7 @@ mod template_config {
 
     #[test]
     #[should_panic(
-        expected = "unknown field `unknown`, expected one of `$schema`, `description`, `destination`, `extends`, `id`, `title`, `variables`"
+        expected = "unknown field `unknown`, expected one of `$schema`, `description`, `destination`, `extends`, `files`, `id`, `postGenerate`, `title`, `variables`"
     )]
     fn error_unknown_field() {
         test_load_config("template.yml", "unknown: 123", |path| {
@@ -478,6 +478,97 @@ variables:
         }
     }
 
+    mod files {
+        use super::*;
+
+        #[test]
+        fn loads_conditions() {
+            let config = test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+files:
+  'docker/**': withDocker
+  'src/graphql.ts': api == 'graphql'
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.files,
+                FxHashMap::from_iter([
+                    ("docker/**".into(), "withDocker".into()),
+                    ("src/graphql.ts".into(), "api == 'graphql'".into()),
+                ])
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "invalid type: integer `123`, expected a string")]
+        fn invalid_condition_type() {
+            test_load_config(
+                "template.yml",
+                "title: title\ndescription: description\nfiles:\n  'docker/**': 123",
+                load_config_from_root,
+            );
+        }
+    }
+
+    mod post_generate {
+        use super::*;
+
+        #[test]
+        fn loads_commands_and_targets() {
+            let config = test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+postGenerate:
+  - npm install
+  - command: npm run format
+    allowFailure: true
+  - target: build
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.post_generate,
+                vec![
+                    TemplatePostGenerateStep::Command("npm install".into()),
+                    TemplatePostGenerateStep::Object(TemplatePostGenerateConfig {
+                        allow_failure: true,
+                        command: Some("npm run format".into()),
+                        target: None,
+                    }),
+                    TemplatePostGenerateStep::Object(TemplatePostGenerateConfig {
+                        allow_failure: false,
+                        command: None,
+                        target: Some("build".into()),
+                    }),
+                ]
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "a step cannot define both a `command` and a `target`")]
+        fn errors_if_command_and_target() {
+            test_load_config(
+                "template.yml",
+                r"
+title: title
+description: description
+postGenerate:
+  - command: npm install
+    target: build
+",
+                load_config_from_root,
+            );
+        }
+    }
+
     #[test]
     fn supports_hcl() {
         load_template_config_in_format("hcl");
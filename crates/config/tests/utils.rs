@@ -314,6 +314,7 @@ pub fn load_tasks_config_in_format(format: &str) {
                 output_style: Some(TaskOutputStyle::Stream),
                 persistent: Some(true),
                 priority: None,
+                resources: None,
                 retry_count: Some(3),
                 run_deps_in_parallel: Some(false),
                 run_in_ci: Some(TaskOptionRunInCI::Enabled(true)),
@@ -647,6 +648,7 @@ pub fn load_workspace_config_in_format(format: &str) {
             )]),
             hook_format: VcsHookFormat::Native,
             client: VcsClient::Git,
+            plugin: None,
             provider: VcsProvider::GitLab,
             remote_candidates: vec!["main".into(), "origin/main".into()],
             sync: true,
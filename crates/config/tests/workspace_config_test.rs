@@ -3,10 +3,11 @@ mod utils;
 use httpmock::prelude::*;
 use moon_common::Id;
 use moon_config::{
-    FilePath, GlobPath, TemplateLocator, VcsProvider, WorkspaceConfig, WorkspaceProjectGlobFormat,
-    WorkspaceProjects,
+    FilePath, GlobPath, PipelineAuditTaskFiles, TemplateLocator, VcsProvider, WorkspaceConfig,
+    WorkspaceProjectGlobFormat, WorkspaceProjects,
 };
 use moon_config_loader::ConfigLoader;
+use moon_target::Target;
 use rustc_hash::FxHashMap;
 use schematic::ConfigLoader as BaseLoader;
 use starbase_sandbox::{create_empty_sandbox, create_sandbox};
@@ -752,6 +753,93 @@ pipeline:
             assert_eq!(config.pipeline.cache_lifetime, "10 hours");
             assert!(!config.pipeline.inherit_colors_for_piped_tasks);
         }
+
+        #[test]
+        fn can_set_resource_pools() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+pipeline:
+  resourcePools:
+    memory: 8
+    db: 2
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.pipeline.resource_pools,
+                FxHashMap::from_iter([(Id::raw("memory"), 8), (Id::raw("db"), 2)])
+            );
+        }
+
+        #[test]
+        fn can_set_audit_task_files() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+pipeline:
+  auditTaskFiles: trace
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.pipeline.audit_task_files,
+                PipelineAuditTaskFiles::Trace
+            );
+            assert!(config.pipeline.audit_task_files.is_enabled());
+
+            let config = test_load_config(
+                FILENAME,
+                r"
+pipeline:
+  auditTaskFiles: true
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.pipeline.audit_task_files,
+                PipelineAuditTaskFiles::Enabled(true)
+            );
+            assert!(!config.pipeline.audit_task_files.is_tracing());
+        }
+
+        #[test]
+        fn can_set_quarantine() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+pipeline:
+  quarantine: ['app:test', ':e2e']
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.pipeline.quarantine,
+                vec![
+                    Target::parse("app:test").unwrap(),
+                    Target::parse(":e2e").unwrap()
+                ]
+            );
+            assert!(
+                config
+                    .pipeline
+                    .is_quarantined(&Target::parse("app:test").unwrap())
+            );
+            assert!(
+                config
+                    .pipeline
+                    .is_quarantined(&Target::parse("web:e2e").unwrap())
+            );
+            assert!(
+                !config
+                    .pipeline
+                    .is_quarantined(&Target::parse("web:test").unwrap())
+            );
+        }
     }
 
     mod vcs {
@@ -10,6 +10,25 @@ use starbase_styles::color::owo::{OwoColorize, XtermColors};
 use starbase_styles::color::{Color, OwoStyle, no_color};
 use std::time::Duration;
 
+// Uses decimal units, matching how sizes like `10gb` are parsed
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1000 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1000.0;
+    let mut unit = 0;
+
+    while size >= 1000.0 && unit < UNITS.len() - 1 {
+        size /= 1000.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Level {
     Zero,
@@ -29,8 +48,21 @@ impl Level {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct CacheReportItem {
+    pub backend: String,
+    pub hits: u64,
+    pub misses: u64,
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    pub errors: u64,
+    pub latency: Duration,
+}
+
 #[derive(Debug, Default)]
 pub struct PipelineReportItem {
+    /// Remote cache activity during the run.
+    pub cache: Vec<CacheReportItem>,
     pub duration: Option<Duration>,
     // 1 - Summary
     // 2 - + Stats
@@ -484,21 +516,50 @@ impl MoonReporter {
             elapsed_time = format!("{} {}", elapsed_time, label_to_the_moon());
         }
 
-        if item
+        let cache_key = if item
             .summary
             .as_ref()
             .is_some_and(|level| level.is(Level::Two))
         {
             self.print_entry("Actions", counts_message)?;
             self.print_entry("   Time", elapsed_time)?;
+            "  Cache"
         } else {
             self.print_entry("Tasks", counts_message)?;
             self.print_entry(" Time", elapsed_time)?;
+            "Cache"
+        };
+
+        for cache in &item.cache {
+            self.print_entry(cache_key, self.format_cache_stats(cache))?;
         }
 
         Ok(())
     }
 
+    fn format_cache_stats(&self, cache: &CacheReportItem) -> String {
+        let mut parts = vec![
+            color::success(format!("{} hits", cache.hits)),
+            color::muted_light(format!("{} misses", cache.misses)),
+            format!("{} down", format_bytes(cache.bytes_read)),
+            format!("{} up", format_bytes(cache.bytes_written)),
+        ];
+
+        if let Some(latency) = time::elapsed_opt(cache.latency) {
+            parts.push(format!("{latency} avg"));
+        }
+
+        if cache.errors > 0 {
+            parts.push(color::failure(format!("{} errors", cache.errors)));
+        }
+
+        format!(
+            "{} {}",
+            parts.join(&color::muted(", ")),
+            color::muted_light(format!("({})", cache.backend))
+        )
+    }
+
     fn print_pipeline_summary(&self, actions: &[Action]) -> miette::Result<()> {
         let mut items = vec![];
         let mut failed_items = vec![];
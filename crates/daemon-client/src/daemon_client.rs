@@ -11,7 +11,7 @@ use std::path::Path;
 use std::time::Duration;
 use tokio::time::timeout;
 use tonic::{
-    Request, Status,
+    Request, Status, Streaming,
     transport::{Channel, Endpoint, Error as TransportError, Uri},
 };
 use tower::service_fn;
@@ -23,7 +23,7 @@ use tracing::{debug, instrument};
 /// a connect indefinitely.
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
 
-/// Deadline for fast control procedures (Status, Start, Stop).
+/// Deadline for fast control procedures (Status, Start, Stop, PublishEvent).
 const CONTROL_DEADLINE: Duration = Duration::from_secs(5);
 
 /// Deadline for work procedures (SendWebhook, CleanCache, ArchiveTaskOutputs).
@@ -277,6 +277,43 @@ impl DaemonClient {
         Ok(response.into_inner())
     }
 
+    #[instrument(skip(self, event))]
+    pub async fn publish_event(
+        &mut self,
+        event: PipelineEvent,
+    ) -> miette::Result<PublishEventResponse> {
+        let response = with_deadline(
+            "PublishEvent",
+            CONTROL_DEADLINE,
+            self.inner.publish_event(request_with_deadline(
+                PublishEventRequest { event: Some(event) },
+                CONTROL_DEADLINE,
+            )),
+        )
+        .await?;
+
+        Ok(response.into_inner())
+    }
+
+    /// Subscribe to pipeline events published by other moon processes. Only
+    /// opening the stream is bounded by a deadline, as the stream itself stays
+    /// open until the daemon shuts down or the caller drops it.
+    #[instrument(skip(self))]
+    pub async fn subscribe_events(
+        &mut self,
+        types: Vec<String>,
+    ) -> miette::Result<Streaming<PipelineEvent>> {
+        let response = with_deadline(
+            "SubscribeEvents",
+            CONTROL_DEADLINE,
+            self.inner
+                .subscribe_events(Request::new(SubscribeEventsRequest { types })),
+        )
+        .await?;
+
+        Ok(response.into_inner())
+    }
+
     #[instrument(skip(self))]
     pub async fn send_webhook(
         &mut self,
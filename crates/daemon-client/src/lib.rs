@@ -3,3 +3,4 @@ mod daemon_client_error;
 
 pub use daemon_client::*;
 pub use daemon_client_error::*;
+pub use moon_daemon_proto::PipelineEvent;
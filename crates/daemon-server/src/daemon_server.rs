@@ -19,11 +19,13 @@ use starbase_utils::fs;
 use std::env;
 use std::future::Future;
 use std::path::PathBuf;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, broadcast};
 use tokio::time::timeout;
+use tokio_stream::Stream;
 use tokio_util::task::TaskTracker;
 use tonic::{Request, Response, Status, transport::Server};
 use tracing::{debug, error, info, warn};
@@ -41,6 +43,10 @@ const IDLE_TTL: Duration = Duration::from_secs(4 * 60 * 60);
 /// excludes `node_modules`/`.git`, so the burst is bounded by tracked files.
 const EVENT_CHANNEL_CAPACITY: usize = 16_384;
 
+/// Capacity of the pipeline event broadcast. Subscribers that fall further
+/// behind than this skip the oldest events instead of stalling publishers.
+const PIPELINE_EVENT_CHANNEL_CAPACITY: usize = 1024;
+
 /// Maximum time to wait during shutdown for queued background work to finish.
 const BACKGROUND_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
 
@@ -61,6 +67,7 @@ struct DaemonServiceInner {
     started_at: Instant,
     last_activity: Arc<AtomicU64>,
     background: TaskTracker,
+    events_tx: broadcast::Sender<PipelineEvent>,
 }
 
 pub struct DaemonService {
@@ -83,6 +90,7 @@ impl DaemonService {
                 started_at: Instant::now(),
                 last_activity: Arc::new(AtomicU64::new(0)),
                 background: TaskTracker::new(),
+                events_tx: broadcast::channel(PIPELINE_EVENT_CHANNEL_CAPACITY).0,
             }),
             state,
         }
@@ -115,8 +123,12 @@ impl DaemonService {
     }
 }
 
+pub type PipelineEventStream = Pin<Box<dyn Stream<Item = Result<PipelineEvent, Status>> + Send>>;
+
 #[tonic::async_trait]
 impl MoonDaemon for DaemonService {
+    type SubscribeEventsStream = PipelineEventStream;
+
     async fn archive_task_outputs(
         &self,
         request: Request<ArchiveTaskOutputsRequest>,
@@ -296,6 +308,63 @@ impl MoonDaemon for DaemonService {
         }))
     }
 
+    async fn publish_event(
+        &self,
+        request: Request<PublishEventRequest>,
+    ) -> Result<Response<PublishEventResponse>, Status> {
+        self.track_activity("PublishEvent");
+
+        let event = request
+            .into_inner()
+            .event
+            .ok_or_else(|| Status::invalid_argument("Missing event"))?;
+
+        // Sending only fails when nobody is subscribed, which is fine
+        let subscribers = self.inner.events_tx.send(event).unwrap_or_default();
+
+        Ok(Response::new(PublishEventResponse {
+            subscribers: subscribers as u32,
+        }))
+    }
+
+    async fn subscribe_events(
+        &self,
+        request: Request<SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        self.track_activity("SubscribeEvents");
+
+        let types = request.into_inner().types;
+        let mut events_rx = self.inner.events_tx.subscribe();
+        let mut shutdown_rx = self.inner.shutdown_tx.subscribe();
+
+        // End the stream when the daemon shuts down, otherwise the server
+        // would wait on this connection forever while draining
+        let stream = async_stream::stream! {
+            loop {
+                let result = tokio::select! {
+                    result = events_rx.recv() => result,
+                    _ = shutdown_rx.recv() => break,
+                };
+
+                match result {
+                    Ok(event) => {
+                        if is_event_type_included(&types, &event.r#type) {
+                            yield Ok(event);
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(count)) => {
+                        warn!("Pipeline event subscriber lagged by {count} events");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        break;
+                    }
+                };
+            }
+        };
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     async fn send_webhook(
         &self,
         request: Request<SendWebhookRequest>,
@@ -372,6 +441,18 @@ impl MoonDaemon for DaemonService {
     }
 }
 
+/// Match an event type against the requested filters, where each filter is
+/// either an exact type (`task.ran`) or a category prefix (`task`).
+fn is_event_type_included(filters: &[String], event_type: &str) -> bool {
+    filters.is_empty()
+        || filters.iter().any(|filter| {
+            event_type == filter
+                || event_type
+                    .strip_prefix(filter.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+}
+
 /// Start the gRPC daemon server, listening on a platform-specific endpoint.
 ///
 /// - Unix: binds a Unix domain socket
@@ -32,6 +32,16 @@ pub fn build_daemon_service(
     )
 }
 
+#[cfg(unix)]
+fn create_event(event_type: &str) -> moon_daemon_proto::PipelineEvent {
+    moon_daemon_proto::PipelineEvent {
+        pipeline_id: "abc".into(),
+        r#type: event_type.into(),
+        payload: "{}".into(),
+        timestamp: 0,
+    }
+}
+
 /// When another daemon already owns the workspace (holds the ownership lock),
 /// a second server must defer to it and exit cleanly without binding, rather
 /// than racing for the endpoint.
@@ -207,6 +217,59 @@ mod unix_rpc {
         let _ = shutdown_tx.send(());
     }
 
+    #[tokio::test]
+    async fn test_publish_event_rpc_streams_to_subscribers() {
+        let sandbox = create_empty_sandbox();
+        let daemon_dir = sandbox.path().join("daemon");
+        let workspace_root = sandbox.path().to_path_buf();
+
+        fs::create_dir_all(&daemon_dir).unwrap();
+
+        let shutdown_tx = start_test_server(&daemon_dir, &workspace_root).await;
+        let mut client = DaemonClient::connect(&daemon_dir).await.unwrap();
+        let mut subscriber = DaemonClient::connect(&daemon_dir).await.unwrap();
+
+        // Without subscribers, the event is dropped
+        let response = client
+            .publish_event(create_event("task.running"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.subscribers, 0);
+
+        let mut stream = subscriber
+            .subscribe_events(vec!["task".into()])
+            .await
+            .unwrap();
+
+        // Give the stream a moment to register
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        for event_type in ["pipeline.started", "task.running", "task.ran"] {
+            let response = client
+                .publish_event(create_event(event_type))
+                .await
+                .unwrap();
+
+            assert_eq!(response.subscribers, 1);
+        }
+
+        // Filtered by category
+        let event = stream.message().await.unwrap().unwrap();
+
+        assert_eq!(event.r#type, "task.running");
+        assert_eq!(event.pipeline_id, "abc");
+
+        let event = stream.message().await.unwrap().unwrap();
+
+        assert_eq!(event.r#type, "task.ran");
+
+        // Stream ends when the daemon shuts down
+        let _ = shutdown_tx.send(());
+
+        assert!(!matches!(stream.message().await, Ok(Some(_))));
+    }
+
     #[tokio::test]
     async fn test_connect_to_nonexistent_socket_fails() {
         let sandbox = create_empty_sandbox();
@@ -1,6 +1,8 @@
+use clap::ValueEnum;
 use moon_affected::{DownstreamScope, UpstreamScope};
 use moon_common::{cacheable, cacheable_enum};
 use moon_target::TargetLocator;
+use std::path::PathBuf;
 
 cacheable!(
     #[derive(Default)]
@@ -31,6 +33,38 @@ cacheable_enum!(
     }
 );
 
+cacheable_enum!(
+    /// How targets are partitioned across CI jobs.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+    pub enum JobStrategy {
+        /// Split targets into chunks with an equal amount of targets.
+        #[default]
+        Count,
+
+        /// Bin-pack targets by the durations in a timings file, so that
+        /// each job takes roughly the same amount of time.
+        Balanced,
+    }
+);
+
+cacheable_enum!(
+    /// Additional report formats to write when the pipeline completes.
+    #[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+    pub enum ReportFormat {
+        /// JUnit XML, with a test case for each task.
+        Junit,
+
+        /// SARIF, with a result for each failed task.
+        Sarif,
+
+        /// Durations of each task, for the balanced job strategy.
+        Timings,
+
+        /// Chrome trace events of every action, with a track per concurrency slot.
+        Trace,
+    }
+);
+
 cacheable!(
     #[derive(Default)]
     #[serde(default, deny_unknown_fields)]
@@ -42,6 +76,9 @@ cacheable!(
         pub on_failure: Option<OnFailure>,
         pub job: Option<usize>,
         pub job_total: Option<usize>,
+        pub job_strategy: Option<JobStrategy>,
+        pub job_timings: Option<PathBuf>,
+        pub reports: Option<Vec<ReportFormat>>,
     }
 );
 
@@ -0,0 +1,197 @@
+use crate::graph_traits::*;
+use petgraph::Direction;
+use petgraph::Undirected;
+use petgraph::algo::articulation_points::articulation_points;
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use std::hash::Hash;
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphNodeMetrics<K> {
+    pub node: K,
+
+    /// Direct dependents (incoming edges).
+    pub fan_in: usize,
+
+    /// Direct dependencies (outgoing edges).
+    pub fan_out: usize,
+
+    /// All dependents, transitively.
+    pub deep_dependents: usize,
+
+    /// All dependencies, transitively.
+    pub deep_dependencies: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphAnalysis<K> {
+    /// Nodes that would split the graph into disconnected parts if removed.
+    pub articulation_points: Vec<K>,
+
+    /// Strongly connected components that form a cycle, including
+    /// those that were disconnected while building the graph.
+    pub cycles: Vec<Vec<K>>,
+
+    /// The longest chain of dependencies starting from each node
+    /// without dependents, ordered from longest to shortest.
+    pub longest_chains: Vec<Vec<K>>,
+
+    /// Metrics for every node, ordered by the most dependents first.
+    pub nodes: Vec<GraphNodeMetrics<K>>,
+
+    /// Nodes that nothing depends on.
+    pub without_dependents: Vec<K>,
+}
+
+// Edges that point back into the current path form a cycle,
+// so they're skipped to keep the traversal finite
+fn find_longest_chain<E>(
+    graph: &DiGraph<NodeIndex, E>,
+    index: NodeIndex,
+    cache: &mut FxHashMap<NodeIndex, Vec<NodeIndex>>,
+    visiting: &mut FxHashSet<NodeIndex>,
+) -> Vec<NodeIndex> {
+    if let Some(chain) = cache.get(&index) {
+        return chain.clone();
+    }
+
+    visiting.insert(index);
+
+    let mut longest = vec![];
+
+    for dep_index in graph.neighbors_directed(index, Direction::Outgoing) {
+        if visiting.contains(&dep_index) {
+            continue;
+        }
+
+        let chain = find_longest_chain(graph, dep_index, cache, visiting);
+
+        if chain.len() > longest.len() {
+            longest = chain;
+        }
+    }
+
+    visiting.remove(&index);
+
+    let mut chain = vec![index];
+    chain.extend(longest);
+
+    cache.insert(index, chain.clone());
+    chain
+}
+
+pub trait GraphAnalyzer<N, E, K: Clone + Hash + Eq + Ord>: GraphConnections<N, E, K> {
+    /// Return edges (source and target keys) that were disconnected
+    /// while building the graph, because they would have formed a cycle.
+    fn get_cycle_edges(&self) -> Vec<(K, K)> {
+        vec![]
+    }
+
+    /// Compute health metrics for the graph, like fan-in and fan-out per node,
+    /// the longest dependency chains, articulation points, and cycles.
+    fn analyze(&self) -> GraphAnalysis<K> {
+        let graph = self.get_graph();
+        let get_key = |index: NodeIndex| self.get_node_key(self.get_node_by_index(&graph[index]));
+
+        let mut nodes = vec![];
+        let mut without_dependents = vec![];
+
+        for index in graph.node_indices() {
+            let node = self.get_node_by_index(&graph[index]);
+            let key = self.get_node_key(node);
+            let fan_in = self.dependents_of(node).len();
+
+            if fan_in == 0 {
+                without_dependents.push(key.clone());
+            }
+
+            nodes.push(GraphNodeMetrics {
+                node: key,
+                fan_in,
+                fan_out: self.dependencies_of(node).len(),
+                deep_dependents: self.deep_dependents_of(node).len(),
+                deep_dependencies: self.deep_dependencies_of(node).len(),
+            });
+        }
+
+        nodes.sort_by(|a, b| {
+            b.fan_in
+                .cmp(&a.fan_in)
+                .then_with(|| b.deep_dependents.cmp(&a.deep_dependents))
+                .then_with(|| a.node.cmp(&b.node))
+        });
+        without_dependents.sort();
+
+        // Chains
+        let mut cache = FxHashMap::default();
+        let mut visiting = FxHashSet::default();
+        let mut longest_chains = graph
+            .node_indices()
+            .filter(|index| {
+                graph
+                    .neighbors_directed(*index, Direction::Incoming)
+                    .next()
+                    .is_none()
+            })
+            .map(|index| find_longest_chain(graph, index, &mut cache, &mut visiting))
+            .filter(|chain| chain.len() > 1)
+            .map(|chain| chain.into_iter().map(get_key).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        longest_chains.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+        // Articulation points are only defined for undirected graphs
+        let undirected = graph
+            .map(|_, _| (), |_, _| ())
+            .into_edge_type::<Undirected>();
+
+        let mut points = articulation_points(&undirected)
+            .into_iter()
+            .map(get_key)
+            .collect::<Vec<_>>();
+
+        points.sort();
+
+        // Cycles, with the disconnected edges restored,
+        // as the graph itself is acyclic in most cases
+        let key_indexes = graph
+            .node_indices()
+            .map(|index| (get_key(index), index))
+            .collect::<FxHashMap<_, _>>();
+        let mut cycle_graph = graph.map(|_, _| (), |_, _| ());
+
+        for (source, target) in self.get_cycle_edges() {
+            if let (Some(source_index), Some(target_index)) =
+                (key_indexes.get(&source), key_indexes.get(&target))
+            {
+                cycle_graph.update_edge(*source_index, *target_index, ());
+            }
+        }
+
+        let mut cycles = tarjan_scc(&cycle_graph)
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || cycle_graph.contains_edge(component[0], component[0])
+            })
+            .map(|component| {
+                let mut keys = component.into_iter().map(get_key).collect::<Vec<_>>();
+                keys.sort();
+                keys
+            })
+            .collect::<Vec<_>>();
+
+        cycles.sort();
+
+        GraphAnalysis {
+            articulation_points: points,
+            cycles,
+            longest_chains,
+            nodes,
+            without_dependents,
+        }
+    }
+}
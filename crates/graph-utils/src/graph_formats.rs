@@ -6,6 +6,7 @@ use petgraph::visit::{EdgeRef, NodeRef};
 use rustc_hash::FxHashMap;
 use serde::Serialize;
 use starbase_utils::json;
+use std::collections::BTreeSet;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
@@ -68,3 +69,138 @@ pub trait GraphToJson<N: Serialize, E: Serialize, K>: GraphData<N, E, K> {
         )?)
     }
 }
+
+pub trait GraphNodeAttributes<N> {
+    /// Return additional attributes for the provided node, like its layer or type.
+    /// Only rendered by formats that support them, like Mermaid and GraphML.
+    fn get_node_attributes(&self, _node: &N) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+pub trait GraphToMermaid<N: Clone + Display, E: Clone + Display, K: Display + Hash + Eq>:
+    GraphConversions<N, E, K> + GraphNodeAttributes<N>
+{
+    /// Format graph as a Mermaid flowchart, for embedding in Markdown.
+    fn to_mermaid(&self) -> String {
+        let graph = self.get_graph();
+        let mut lines = vec!["flowchart TB".to_owned()];
+
+        for index in graph.node_indices() {
+            let node = self.get_node_by_index(&graph[index]);
+            let mut label = escape_mermaid(&node.to_string());
+            let attributes = self.get_node_attributes(node);
+
+            if !attributes.is_empty() {
+                label.push_str("<br/><small>");
+                label.push_str(
+                    &attributes
+                        .into_iter()
+                        .map(|(key, value)| format!("{key}: {}", escape_mermaid(&value)))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+                label.push_str("</small>");
+            }
+
+            lines.push(format!("    n{}[\"{label}\"]", index.index()));
+        }
+
+        for edge in graph.edge_references() {
+            lines.push(format!(
+                "    n{} -->|\"{}\"| n{}",
+                edge.source().index(),
+                escape_mermaid(&edge.weight().to_string()),
+                edge.target().index()
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+pub trait GraphToGraphMl<N: Clone + Display, E: Clone + Display, K: Display + Hash + Eq>:
+    GraphConversions<N, E, K> + GraphNodeAttributes<N>
+{
+    /// Format graph as a GraphML document, for analysis in tools like yEd or Gephi.
+    fn to_graphml(&self) -> String {
+        let graph = self.get_graph();
+        let mut nodes = vec![];
+        let mut attribute_keys = BTreeSet::new();
+
+        for index in graph.node_indices() {
+            let node = self.get_node_by_index(&graph[index]);
+            let attributes = self.get_node_attributes(node);
+
+            attribute_keys.extend(attributes.iter().map(|(key, _)| *key));
+            nodes.push((index, node.to_string(), attributes));
+        }
+
+        let mut lines = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_owned(),
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#.to_owned(),
+            r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#.to_owned(),
+        ];
+
+        for key in &attribute_keys {
+            lines.push(format!(
+                r#"  <key id="{key}" for="node" attr.name="{key}" attr.type="string"/>"#
+            ));
+        }
+
+        lines.push(
+            r#"  <key id="edge_label" for="edge" attr.name="label" attr.type="string"/>"#
+                .to_owned(),
+        );
+        lines.push(r#"  <graph id="G" edgedefault="directed">"#.to_owned());
+
+        for (index, label, attributes) in nodes {
+            lines.push(format!(r#"    <node id="n{}">"#, index.index()));
+            lines.push(format!(
+                r#"      <data key="label">{}</data>"#,
+                escape_xml(&label)
+            ));
+
+            for (key, value) in attributes {
+                lines.push(format!(
+                    r#"      <data key="{key}">{}</data>"#,
+                    escape_xml(&value)
+                ));
+            }
+
+            lines.push("    </node>".to_owned());
+        }
+
+        for edge in graph.edge_references() {
+            lines.push(format!(
+                r#"    <edge id="e{}" source="n{}" target="n{}">"#,
+                edge.id().index(),
+                edge.source().index(),
+                edge.target().index()
+            ));
+            lines.push(format!(
+                r#"      <data key="edge_label">{}</data>"#,
+                escape_xml(&edge.weight().to_string())
+            ));
+            lines.push("    </edge>".to_owned());
+        }
+
+        lines.push("  </graph>".to_owned());
+        lines.push("</graphml>".to_owned());
+
+        lines.join("\n")
+    }
+}
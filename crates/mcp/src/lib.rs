@@ -1,4 +1,5 @@
 mod mcp;
+pub mod resources;
 pub mod tools;
 
 pub use mcp::*;
@@ -1,25 +1,44 @@
-use crate::tools::action_tools::{SyncProjectsTool, SyncWorkspaceTool};
+use crate::resources::*;
+use crate::tools::action_tools::{RunTasksTool, SyncProjectsTool, SyncWorkspaceTool};
 use crate::tools::codegen_tools::{GenerateTool, GetTemplateTool, GetTemplatesTool};
-use crate::tools::project_tools::{GetProjectTool, GetProjectsTool};
-use crate::tools::task_tools::{GetTaskTool, GetTasksTool};
-use crate::tools::vcs_tools::GetChangedFilesTool;
+use crate::tools::project_tools::{GetProjectTool, GetProjectsTool, QueryProjectsTool};
+use crate::tools::task_tools::{GetTaskTool, GetTasksTool, QueryTasksTool};
+use crate::tools::vcs_tools::{GetAffectedTasksTool, GetChangedFilesTool};
 use async_trait::async_trait;
 use moon_app_context::AppContext;
+use moon_config_loader::ConfigLoader;
 use moon_workspace_graph::WorkspaceGraph;
 use rust_mcp_sdk::error::SdkResult;
 use rust_mcp_sdk::mcp_server::{McpServerOptions, ServerHandler, server_runtime};
 use rust_mcp_sdk::schema::{
     CallToolRequestParams, CallToolResult, Implementation, InitializeResult,
-    LATEST_PROTOCOL_VERSION, ListToolsResult, PaginatedRequestParams, RpcError, ServerCapabilities,
-    ServerCapabilitiesTools, schema_utils::CallToolError,
+    LATEST_PROTOCOL_VERSION, ListResourcesResult, ListToolsResult, PaginatedRequestParams,
+    ReadResourceRequestParams, ReadResourceResult, ResourceUpdatedNotificationParams,
+    Result as EmptyResult, RpcError, ServerCapabilities, ServerCapabilitiesResources,
+    ServerCapabilitiesTools, SubscribeRequestParams, UnsubscribeRequestParams,
+    schema_utils::CallToolError,
 };
 use rust_mcp_sdk::{McpServer, StdioTransport, ToMcpServerHandler, TransportOptions, tool_box};
 use std::env;
 use std::sync::Arc;
+use tokio::sync::{RwLock, mpsc};
+use tracing::{debug, warn};
+
+/// The workspace graph, which is replaced when projects are changed,
+/// so that resources and tools reflect the current workspace.
+pub type AtomicWorkspaceGraph = Arc<RwLock<Arc<WorkspaceGraph>>>;
 
 pub struct MoonMcpHandler {
     app_context: Arc<AppContext>,
-    workspace_graph: Arc<WorkspaceGraph>,
+    config_loader: ConfigLoader,
+    subscriptions: Arc<scc::HashSet<String>>,
+    workspace_graph: AtomicWorkspaceGraph,
+}
+
+impl MoonMcpHandler {
+    async fn get_workspace_graph(&self) -> Arc<WorkspaceGraph> {
+        Arc::clone(&*self.workspace_graph.read().await)
+    }
 }
 
 #[async_trait]
@@ -36,39 +55,146 @@ impl ServerHandler for MoonMcpHandler {
         })
     }
 
+    async fn handle_list_resources_request(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<ListResourcesResult, RpcError> {
+        Ok(ListResourcesResult {
+            meta: None,
+            next_cursor: None,
+            resources: list_resources(&self.get_workspace_graph().await),
+        })
+    }
+
+    async fn handle_read_resource_request(
+        &self,
+        request: ReadResourceRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<ReadResourceResult, RpcError> {
+        Ok(ReadResourceResult {
+            contents: vec![
+                read_resource(
+                    &request.uri,
+                    &self.app_context,
+                    &self.get_workspace_graph().await,
+                    &self.config_loader,
+                )
+                .await?,
+            ],
+            meta: None,
+        })
+    }
+
+    async fn handle_subscribe_request(
+        &self,
+        request: SubscribeRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<EmptyResult, RpcError> {
+        let _ = self.subscriptions.insert_async(request.uri).await;
+
+        Ok(EmptyResult::default())
+    }
+
+    async fn handle_unsubscribe_request(
+        &self,
+        request: UnsubscribeRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<EmptyResult, RpcError> {
+        self.subscriptions.remove_async(&request.uri).await;
+
+        Ok(EmptyResult::default())
+    }
+
     async fn handle_call_tool_request(
         &self,
         request: CallToolRequestParams,
         _runtime: Arc<dyn McpServer>,
-    ) -> std::result::Result<CallToolResult, CallToolError> {
+    ) -> Result<CallToolResult, CallToolError> {
         let tool_params: MoonTools = MoonTools::try_from(request).map_err(CallToolError::new)?;
+        let workspace_graph = self.get_workspace_graph().await;
 
         match tool_params {
             MoonTools::GenerateTool(inner) => inner.call_tool(&self.app_context).await,
+            MoonTools::GetAffectedTasksTool(inner) => {
+                inner.call_tool(&self.app_context, &workspace_graph).await
+            }
             MoonTools::GetChangedFilesTool(inner) => inner.call_tool(&self.app_context).await,
-            MoonTools::GetProjectTool(inner) => inner.call_tool(&self.workspace_graph),
-            MoonTools::GetProjectsTool(inner) => inner.call_tool(&self.workspace_graph),
-            MoonTools::GetTaskTool(inner) => inner.call_tool(&self.workspace_graph),
-            MoonTools::GetTasksTool(inner) => inner.call_tool(&self.workspace_graph),
+            MoonTools::GetProjectTool(inner) => inner.call_tool(&workspace_graph),
+            MoonTools::GetProjectsTool(inner) => inner.call_tool(&workspace_graph),
+            MoonTools::GetTaskTool(inner) => inner.call_tool(&workspace_graph),
+            MoonTools::GetTasksTool(inner) => inner.call_tool(&workspace_graph),
             MoonTools::GetTemplateTool(inner) => inner.call_tool(&self.app_context).await,
             MoonTools::GetTemplatesTool(inner) => inner.call_tool(&self.app_context).await,
+            MoonTools::QueryProjectsTool(inner) => inner.call_tool(&workspace_graph),
+            MoonTools::QueryTasksTool(inner) => inner.call_tool(&workspace_graph),
+            MoonTools::RunTasksTool(inner) => {
+                inner.call_tool(&self.app_context, &workspace_graph).await
+            }
             MoonTools::SyncProjectsTool(inner) => {
-                inner
-                    .call_tool(&self.app_context, &self.workspace_graph)
-                    .await
+                inner.call_tool(&self.app_context, &workspace_graph).await
             }
             MoonTools::SyncWorkspaceTool(inner) => {
-                inner
-                    .call_tool(&self.app_context, &self.workspace_graph)
+                inner.call_tool(&self.app_context, &workspace_graph).await
+            }
+        }
+    }
+}
+
+async fn notify_resource_changes(
+    server: Arc<dyn McpServer>,
+    workspace_graph: AtomicWorkspaceGraph,
+    subscriptions: Arc<scc::HashSet<String>>,
+    mut change_rx: mpsc::Receiver<McpResourceChange>,
+) {
+    while let Some(change) = change_rx.recv().await {
+        debug!(change = ?change, "Workspace changed, notifying MCP client");
+
+        let uri = match change {
+            McpResourceChange::ProjectConfig(source) => {
+                let project = workspace_graph
+                    .read()
                     .await
+                    .get_projects_unexpanded()
+                    .into_iter()
+                    .find(|project| project.source == source);
+
+                match project {
+                    Some(project) => create_project_config_uri(project.id.as_str()),
+                    // The config is not for a known project
+                    None => {
+                        let _ = server.notify_resource_list_changed(None).await;
+                        continue;
+                    }
+                }
+            }
+            McpResourceChange::Projects => {
+                let _ = server.notify_resource_list_changed(None).await;
+                continue;
             }
+            McpResourceChange::TasksConfig => TASKS_CONFIG_URI.to_owned(),
+            McpResourceChange::WorkspaceConfig => WORKSPACE_CONFIG_URI.to_owned(),
+        };
+
+        // Updates are only sent for resources the client subscribed to
+        if !subscriptions.contains_async(&uri).await {
+            continue;
+        }
+
+        if let Err(error) = server
+            .notify_resource_updated(ResourceUpdatedNotificationParams { meta: None, uri })
+            .await
+        {
+            warn!("Failed to notify MCP client of a resource change: {error}");
         }
     }
 }
 
 pub async fn run_mcp(
     app_context: Arc<AppContext>,
-    workspace_graph: Arc<WorkspaceGraph>,
+    workspace_graph: AtomicWorkspaceGraph,
+    config_loader: ConfigLoader,
+    change_rx: Option<mpsc::Receiver<McpResourceChange>>,
 ) -> SdkResult<()> {
     // STEP 1: Define server details and capabilities
     let server_details = InitializeResult {
@@ -82,6 +208,10 @@ pub async fn run_mcp(
             icons: vec![],
         },
         capabilities: ServerCapabilities {
+            resources: Some(ServerCapabilitiesResources {
+                list_changed: Some(change_rx.is_some()),
+                subscribe: Some(change_rx.is_some()),
+            }),
             tools: Some(ServerCapabilitiesTools { list_changed: None }),
             ..Default::default()
         },
@@ -94,9 +224,12 @@ pub async fn run_mcp(
     let transport = StdioTransport::new(TransportOptions::default())?;
 
     // STEP 3: Instantiate our custom handler for handling MCP messages
+    let subscriptions = Arc::new(scc::HashSet::default());
     let handler = MoonMcpHandler {
         app_context,
-        workspace_graph,
+        config_loader,
+        subscriptions: Arc::clone(&subscriptions),
+        workspace_graph: Arc::clone(&workspace_graph),
     };
 
     // STEP 4: Create the MCP runtime
@@ -109,7 +242,17 @@ pub async fn run_mcp(
         message_observer: None,
     });
 
-    // STEP 5: Start the server
+    // STEP 5: Forward workspace changes as resource notifications
+    if let Some(change_rx) = change_rx {
+        tokio::spawn(notify_resource_changes(
+            server.clone(),
+            workspace_graph,
+            subscriptions,
+            change_rx,
+        ));
+    }
+
+    // STEP 6: Start the server
     server.start().await
 }
 
@@ -117,6 +260,7 @@ tool_box!(
     MoonTools,
     [
         GenerateTool,
+        GetAffectedTasksTool,
         GetChangedFilesTool,
         GetProjectTool,
         GetProjectsTool,
@@ -124,6 +268,9 @@ tool_box!(
         GetTasksTool,
         GetTemplateTool,
         GetTemplatesTool,
+        QueryProjectsTool,
+        QueryTasksTool,
+        RunTasksTool,
         SyncProjectsTool,
         SyncWorkspaceTool
     ]
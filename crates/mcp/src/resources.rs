@@ -0,0 +1,203 @@
+use moon_app_context::AppContext;
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_config_loader::ConfigLoader;
+use moon_config_schema::json_schemas::generate_json_schemas;
+use moon_workspace_graph::WorkspaceGraph;
+use rust_mcp_sdk::schema::{ReadResourceContent, Resource, RpcError, TextResourceContents};
+use serde::Serialize;
+use starbase_utils::fs;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+pub const WORKSPACE_CONFIG_URI: &str = "moon://config/workspace";
+pub const TASKS_CONFIG_URI: &str = "moon://config/tasks";
+
+const PROJECT_CONFIG_PREFIX: &str = "moon://projects/";
+const PROJECT_CONFIG_SUFFIX: &str = "/config";
+const SCHEMA_PREFIX: &str = "moon://schemas/";
+
+const SCHEMA_NAMES: [&str; 7] = [
+    "extensions",
+    "project",
+    "tasks",
+    "template",
+    "template-frontmatter",
+    "toolchains",
+    "workspace",
+];
+
+/// A change to the workspace that affects the contents,
+/// or the list of, published resources.
+#[derive(Clone, Debug, PartialEq)]
+pub enum McpResourceChange {
+    /// A project config (`moon.*`) was changed, relative to the project root.
+    ProjectConfig(WorkspaceRelativePathBuf),
+
+    /// A project root was created or removed.
+    Projects,
+
+    /// An inherited tasks config (`.moon/tasks/**/*`) was changed.
+    TasksConfig,
+
+    /// The workspace config (`.moon/workspace.*`) was changed.
+    WorkspaceConfig,
+}
+
+pub fn create_project_config_uri(id: &str) -> String {
+    format!("{PROJECT_CONFIG_PREFIX}{id}{PROJECT_CONFIG_SUFFIX}")
+}
+
+pub fn create_schema_uri(name: &str) -> String {
+    format!("{SCHEMA_PREFIX}{name}")
+}
+
+fn create_resource(uri: String, name: String, description: String) -> Resource {
+    Resource {
+        annotations: None,
+        description: Some(description),
+        icons: vec![],
+        meta: None,
+        mime_type: Some("application/json".into()),
+        name,
+        size: None,
+        title: None,
+        uri,
+    }
+}
+
+fn create_content<T: Serialize>(uri: &str, value: &T) -> Result<ReadResourceContent, RpcError> {
+    let mut content = TextResourceContents::new(
+        serde_json::to_string_pretty(value)
+            .map_err(|error| RpcError::internal_error().with_message(error.to_string()))?,
+        uri.to_owned(),
+    );
+    content.mime_type = Some("application/json".into());
+
+    Ok(ReadResourceContent::TextResourceContents(content))
+}
+
+fn map_miette_error(report: miette::Report) -> RpcError {
+    RpcError::internal_error().with_message(report.to_string())
+}
+
+/// List all resources that can be read: the workspace config, inherited
+/// tasks configs, a config per project, and the JSON schemas for each config.
+pub fn list_resources(workspace_graph: &WorkspaceGraph) -> Vec<Resource> {
+    let mut resources = vec![
+        create_resource(
+            WORKSPACE_CONFIG_URI.into(),
+            "workspace-config".into(),
+            "The resolved workspace configuration.".into(),
+        ),
+        create_resource(
+            TASKS_CONFIG_URI.into(),
+            "tasks-config".into(),
+            "All inherited tasks configurations, keyed by file path.".into(),
+        ),
+    ];
+
+    let mut projects = workspace_graph.get_projects_unexpanded();
+    projects.sort_by(|a, d| a.id.cmp(&d.id));
+
+    for project in projects {
+        resources.push(create_resource(
+            create_project_config_uri(project.id.as_str()),
+            format!("{}-config", project.id),
+            format!("The resolved configuration for the {} project.", project.id),
+        ));
+    }
+
+    for name in SCHEMA_NAMES {
+        resources.push(create_resource(
+            create_schema_uri(name),
+            format!("{name}-schema"),
+            format!("The JSON schema for {name} configuration."),
+        ));
+    }
+
+    resources
+}
+
+/// Read a resource by URI. Configs are loaded from the file system on every
+/// read, so that changes since the server started are reflected.
+pub async fn read_resource(
+    uri: &str,
+    app_context: &AppContext,
+    workspace_graph: &WorkspaceGraph,
+    config_loader: &ConfigLoader,
+) -> Result<ReadResourceContent, RpcError> {
+    let workspace_root = &app_context.workspace_root;
+
+    if uri == WORKSPACE_CONFIG_URI {
+        let config = config_loader
+            .load_workspace_config(workspace_root)
+            .map_err(map_miette_error)?;
+
+        return create_content(uri, &config);
+    }
+
+    if uri == TASKS_CONFIG_URI {
+        let manager = config_loader
+            .load_tasks_manager(workspace_root)
+            .map_err(map_miette_error)?;
+
+        let configs = manager
+            .configs
+            .iter()
+            .map(|entry| (entry.input.as_str(), &entry.config))
+            .collect::<BTreeMap<_, _>>();
+
+        return create_content(uri, &configs);
+    }
+
+    if let Some(id) = uri
+        .strip_prefix(PROJECT_CONFIG_PREFIX)
+        .and_then(|rest| rest.strip_suffix(PROJECT_CONFIG_SUFFIX))
+    {
+        let project = workspace_graph
+            .get_project(id)
+            .map_err(|error| RpcError::invalid_params().with_message(error.to_string()))?;
+        let config = config_loader
+            .load_project_config_from_source(workspace_root, project.source.as_str())
+            .map_err(map_miette_error)?;
+
+        return create_content(uri, &config);
+    }
+
+    if let Some(name) = uri.strip_prefix(SCHEMA_PREFIX)
+        && SCHEMA_NAMES.contains(&name)
+    {
+        let schema_file = load_schema_file(app_context, name)
+            .await
+            .map_err(map_miette_error)?;
+
+        let mut content = TextResourceContents::new(
+            fs::read_file(schema_file).map_err(|error| map_miette_error(error.into()))?,
+            uri.to_owned(),
+        );
+        content.mime_type = Some("application/schema+json".into());
+
+        return Ok(ReadResourceContent::TextResourceContents(content));
+    }
+
+    Err(RpcError::invalid_params().with_message(format!("Unknown resource {uri}.")))
+}
+
+// Schemas are usually generated by the `SyncWorkspace` action,
+// but may not exist yet if the pipeline has never ran
+async fn load_schema_file(app_context: &AppContext, name: &str) -> miette::Result<PathBuf> {
+    let out_dir = app_context.cache_engine.cache_dir.join("schemas");
+    let schema_file = out_dir.join(format!("{name}.json"));
+
+    if !schema_file.exists() {
+        generate_json_schemas(
+            &out_dir,
+            app_context
+                .toolchain_registry
+                .define_toolchain_config_all()
+                .await?,
+        )?;
+    }
+
+    Ok(schema_file)
+}
@@ -1,10 +1,13 @@
 #![allow(clippy::disallowed_types)]
 
 use super::map_miette_error;
-use moon_action::Action;
+use moon_action::{Action, ActionStatus};
 use moon_action_graph::{ActionGraphBuilder, ActionGraphBuilderOptions, RunRequirements};
 use moon_action_pipeline::ActionPipeline;
+use moon_affected::DownstreamScope;
 use moon_app_context::AppContext;
+use moon_console::{Console, MoonReporter, create_console_theme};
+use moon_task::TargetLocator;
 use moon_workspace_graph::WorkspaceGraph;
 use rust_mcp_sdk::{
     macros::{JsonSchema, mcp_tool},
@@ -12,6 +15,7 @@ use rust_mcp_sdk::{
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 
 async fn run_pipeline(
     app_context: Arc<AppContext>,
@@ -20,6 +24,18 @@ async fn run_pipeline(
 ) -> miette::Result<Vec<Action>> {
     let (action_context, action_graph) = action_graph_builder.build();
 
+    // Stdout is the JSON-RPC transport of the MCP server, so task output
+    // (primary targets are streamed) must never be written to it. Quieting
+    // the console mutes stdout, while the output is still captured.
+    let mut console = Console::new(true);
+    console.set_reporter(MoonReporter::default());
+    console.set_theme(create_console_theme());
+
+    let app_context = Arc::new(AppContext {
+        console: Arc::new(console),
+        ..app_context.as_ref().clone()
+    });
+
     let mut pipeline = ActionPipeline::new(app_context, workspace_graph, None);
     pipeline.bail = true;
     pipeline.quiet = true;
@@ -152,3 +168,127 @@ impl SyncProjectsTool {
         )]))
     }
 }
+
+#[mcp_tool(
+    name = "run_tasks",
+    title = "Run tasks",
+    description = "Run one or many moon tasks by `targets`, including their dependencies, and return the status, duration, and captured output of each action."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RunTasksTool {
+    pub targets: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_dependents: Option<bool>,
+}
+
+impl RunTasksTool {
+    pub async fn call_tool(
+        &self,
+        app_context: &Arc<AppContext>,
+        workspace_graph: &Arc<WorkspaceGraph>,
+    ) -> Result<CallToolResult, CallToolError> {
+        let mut locators = vec![];
+
+        for target in &self.targets {
+            locators.push(TargetLocator::parse(target).map_err(map_miette_error)?);
+        }
+
+        let mut action_graph = ActionGraphBuilder::new(
+            Arc::clone(app_context),
+            Arc::clone(workspace_graph),
+            ActionGraphBuilderOptions::default(),
+        )
+        .map_err(map_miette_error)?;
+
+        action_graph
+            .run_tasks(
+                &locators,
+                RunRequirements {
+                    dependents: if self.include_dependents.unwrap_or_default() {
+                        DownstreamScope::Deep
+                    } else {
+                        DownstreamScope::None
+                    },
+                    skip_affected: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(map_miette_error)?;
+
+        let actions = run_pipeline(
+            Arc::clone(app_context),
+            Arc::clone(workspace_graph),
+            action_graph,
+        )
+        .await
+        .map_err(map_miette_error)?;
+
+        let results = actions
+            .iter()
+            .map(RunTaskResult::from_action)
+            .collect::<Vec<_>>();
+
+        Ok(CallToolResult::text_content(vec![TextContent::new(
+            serde_json::to_string_pretty(&RunTasksResponse {
+                passed: actions
+                    .iter()
+                    .all(|action| action.allow_failure || !action.has_failed()),
+                results,
+            })
+            .map_err(CallToolError::new)?,
+            None,
+            None,
+        )]))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunTaskResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Duration>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+
+    pub label: String,
+
+    pub status: ActionStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+}
+
+impl RunTaskResult {
+    pub fn from_action(action: &Action) -> Self {
+        // Retried tasks record an operation per attempt, so use the last one
+        let output = action
+            .operations
+            .get_last_execution()
+            .and_then(|operation| operation.get_exec_output());
+
+        Self {
+            duration: action.duration,
+            error: action.error.clone(),
+            exit_code: output.and_then(|output| output.exit_code),
+            label: action.label.clone(),
+            status: action.status,
+            stderr: output.and_then(|output| output.stderr.as_deref().cloned()),
+            stdout: output.and_then(|output| output.stdout.as_deref().cloned()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RunTasksResponse {
+    pub passed: bool,
+    pub results: Vec<RunTaskResult>,
+}
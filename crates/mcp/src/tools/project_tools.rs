@@ -2,6 +2,7 @@
 
 use super::map_miette_error;
 use moon_project::{Project, ProjectFragment};
+use moon_query::build_query;
 use moon_workspace_graph::WorkspaceGraph;
 use rust_mcp_sdk::{
     macros::{JsonSchema, mcp_tool},
@@ -98,3 +99,38 @@ impl GetProjectsTool {
 pub struct GetProjectsResponse {
     pub projects: Vec<ProjectFragment>,
 }
+
+#[mcp_tool(
+    name = "query_projects",
+    title = "Query projects",
+    description = "Get moon projects that match a query written in MQL (moon query language), for example `language=rust && tag=lib`."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct QueryProjectsTool {
+    pub query: String,
+}
+
+impl QueryProjectsTool {
+    pub fn call_tool(
+        &self,
+        workspace_graph: &WorkspaceGraph,
+    ) -> Result<CallToolResult, CallToolError> {
+        let mut projects = workspace_graph
+            .query_projects(build_query(&self.query).map_err(map_miette_error)?)
+            .map_err(map_miette_error)?;
+
+        projects.sort_by(|a, d| a.id.cmp(&d.id));
+
+        Ok(CallToolResult::text_content(vec![TextContent::new(
+            serde_json::to_string_pretty(&GetProjectsResponse {
+                projects: projects
+                    .into_iter()
+                    .map(|proj| proj.to_fragment())
+                    .collect(),
+            })
+            .map_err(CallToolError::new)?,
+            None,
+            None,
+        )]))
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::disallowed_types)]
 
 use super::map_miette_error;
+use moon_query::build_query;
 use moon_task::{Target, Task, TaskFragment};
 use moon_workspace_graph::WorkspaceGraph;
 use rust_mcp_sdk::{
@@ -108,3 +109,35 @@ impl GetTasksTool {
 pub struct GetTasksResponse {
     pub tasks: Vec<TaskFragment>,
 }
+
+#[mcp_tool(
+    name = "query_tasks",
+    title = "Query tasks",
+    description = "Get moon tasks that match a query written in MQL (moon query language), for example `taskType=build && project~app-*`."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct QueryTasksTool {
+    pub query: String,
+}
+
+impl QueryTasksTool {
+    pub fn call_tool(
+        &self,
+        workspace_graph: &WorkspaceGraph,
+    ) -> Result<CallToolResult, CallToolError> {
+        let mut tasks = workspace_graph
+            .query_tasks(build_query(&self.query).map_err(map_miette_error)?)
+            .map_err(map_miette_error)?;
+
+        tasks.sort_by(|a, d| a.target.cmp(&d.target));
+
+        Ok(CallToolResult::text_content(vec![TextContent::new(
+            serde_json::to_string_pretty(&GetTasksResponse {
+                tasks: tasks.into_iter().map(|task| task.to_fragment()).collect(),
+            })
+            .map_err(CallToolError::new)?,
+            None,
+            None,
+        )]))
+    }
+}
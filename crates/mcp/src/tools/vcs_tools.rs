@@ -1,14 +1,49 @@
 #![allow(clippy::disallowed_types)]
 
 use super::map_miette_error;
+use moon_affected::{AffectedTaskState, AffectedTracker, DownstreamScope, UpstreamScope};
 use moon_app_context::AppContext;
 use moon_common::is_remote;
 use moon_common::path::WorkspaceRelativePathBuf;
+use moon_task::Target;
+use moon_workspace_graph::WorkspaceGraph;
 use rust_mcp_sdk::{
     macros::{JsonSchema, mcp_tool},
     schema::{CallToolResult, TextContent, schema_utils::CallToolError},
 };
+use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+async fn load_changed_files(
+    app_context: &AppContext,
+    base: Option<&str>,
+    head: Option<&str>,
+    remote: Option<bool>,
+) -> miette::Result<FxHashSet<WorkspaceRelativePathBuf>> {
+    let vcs = &app_context.vcs;
+    let default_branch = vcs.get_default_branch().await?;
+    let current_branch = vcs.get_local_branch().await?;
+
+    let check_against_previous =
+        base.is_none() && head.is_none() && vcs.is_default_branch(&current_branch);
+
+    let changed_files = if !remote.unwrap_or(is_remote()) {
+        vcs.get_changed_files().await?
+    } else if check_against_previous {
+        vcs.get_changed_files_against_previous_revision(&default_branch)
+            .await?
+    } else {
+        vcs.get_changed_files_between_revisions(
+            base.unwrap_or(&default_branch),
+            head.unwrap_or("HEAD"),
+        )
+        .await?
+    };
+
+    Ok(changed_files.all().into_iter().cloned().collect())
+}
 
 #[mcp_tool(
     name = "get_changed_files",
@@ -32,32 +67,94 @@ impl GetChangedFilesTool {
         &self,
         app_context: &AppContext,
     ) -> Result<CallToolResult, CallToolError> {
-        let vcs = &app_context.vcs;
-        let default_branch = vcs.get_default_branch().await.map_err(map_miette_error)?;
-        let current_branch = vcs.get_local_branch().await.map_err(map_miette_error)?;
+        let mut files = load_changed_files(
+            app_context,
+            self.base.as_deref(),
+            self.head.as_deref(),
+            self.remote,
+        )
+        .await
+        .map_err(map_miette_error)?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+        files.sort();
+
+        Ok(CallToolResult::text_content(vec![TextContent::new(
+            serde_json::to_string_pretty(&GetChangedFilesResponse { files })
+                .map_err(CallToolError::new)?,
+            None,
+            None,
+        )]))
+    }
+}
+
+#[derive(Serialize)]
+pub struct GetChangedFilesResponse {
+    pub files: Vec<WorkspaceRelativePathBuf>,
+}
+
+#[mcp_tool(
+    name = "get_affected_tasks",
+    title = "Get affected tasks",
+    description = "Get moon tasks affected by changed files between the current head and base."
+)]
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema)]
+pub struct GetAffectedTasksTool {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_dependents: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<bool>,
+}
 
-        let base = self.base.as_deref().unwrap_or(&default_branch);
-        let head = self.head.as_deref().unwrap_or("HEAD");
-        let remote = self.remote.unwrap_or(is_remote());
+impl GetAffectedTasksTool {
+    pub async fn call_tool(
+        &self,
+        app_context: &AppContext,
+        workspace_graph: &Arc<WorkspaceGraph>,
+    ) -> Result<CallToolResult, CallToolError> {
+        let changed_files = load_changed_files(
+            app_context,
+            self.base.as_deref(),
+            self.head.as_deref(),
+            self.remote,
+        )
+        .await
+        .map_err(map_miette_error)?;
 
-        let check_against_previous =
-            self.base.is_none() && self.head.is_none() && vcs.is_default_branch(&current_branch);
+        let mut affected_tracker = AffectedTracker::new(Arc::clone(workspace_graph), changed_files);
+        affected_tracker.set_task_scopes(
+            UpstreamScope::Deep,
+            if self.include_dependents.unwrap_or_default() {
+                DownstreamScope::Deep
+            } else {
+                DownstreamScope::None
+            },
+        );
 
-        let changed_files = if !remote {
-            vcs.get_changed_files().await.map_err(map_miette_error)?
-        } else if check_against_previous {
-            vcs.get_changed_files_against_previous_revision(&default_branch)
+        if app_context
+            .workspace_config
+            .experiments
+            .async_affected_tracking
+        {
+            affected_tracker
+                .track_tasks_async()
                 .await
-                .map_err(map_miette_error)?
+                .map_err(map_miette_error)?;
         } else {
-            vcs.get_changed_files_between_revisions(base, head)
-                .await
-                .map_err(map_miette_error)?
-        };
+            affected_tracker.track_tasks().map_err(map_miette_error)?;
+        }
 
         Ok(CallToolResult::text_content(vec![TextContent::new(
-            serde_json::to_string_pretty(&GetChangedFilesResponse {
-                files: changed_files.all().into_iter().cloned().collect(),
+            serde_json::to_string_pretty(&GetAffectedTasksResponse {
+                tasks: affected_tracker.build().tasks.into_iter().collect(),
             })
             .map_err(CallToolError::new)?,
             None,
@@ -67,6 +164,6 @@ impl GetChangedFilesTool {
 }
 
 #[derive(Serialize)]
-pub struct GetChangedFilesResponse {
-    pub files: Vec<WorkspaceRelativePathBuf>,
+pub struct GetAffectedTasksResponse {
+    pub tasks: BTreeMap<Target, AffectedTaskState>,
 }
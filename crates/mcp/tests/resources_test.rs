@@ -0,0 +1,116 @@
+use moon_mcp::resources::*;
+use moon_test_utils::WorkspaceMocker;
+use rust_mcp_sdk::schema::ReadResourceContent;
+use starbase_sandbox::{Sandbox, create_empty_sandbox};
+
+fn create_sandbox() -> Sandbox {
+    let sandbox = create_empty_sandbox();
+    sandbox.create_file("a/moon.yml", "language: rust");
+    sandbox.create_file("b/moon.yml", "language: javascript");
+    sandbox.create_file(".moon/tasks/all.yml", "tasks:\n  lint:\n    command: lint");
+    sandbox
+}
+
+fn get_text(content: ReadResourceContent) -> String {
+    match content {
+        ReadResourceContent::TextResourceContents(inner) => inner.text,
+        _ => panic!("Expected text content"),
+    }
+}
+
+mod resources {
+    use super::*;
+
+    #[tokio::test]
+    async fn lists_configs_for_each_project() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+
+        let uris = list_resources(&workspace_graph)
+            .into_iter()
+            .map(|resource| resource.uri)
+            .collect::<Vec<_>>();
+
+        assert!(uris.contains(&WORKSPACE_CONFIG_URI.to_owned()));
+        assert!(uris.contains(&TASKS_CONFIG_URI.to_owned()));
+        assert!(uris.contains(&create_project_config_uri("a")));
+        assert!(uris.contains(&create_project_config_uri("b")));
+        assert!(uris.contains(&create_schema_uri("project")));
+    }
+
+    #[tokio::test]
+    async fn reads_project_config_from_disk() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+        let app_context = mocker.mock_app_context();
+
+        // Changed after the graph was built
+        sandbox.create_file("a/moon.yml", "language: python");
+
+        let text = get_text(
+            read_resource(
+                &create_project_config_uri("a"),
+                &app_context,
+                &workspace_graph,
+                &mocker.config_loader,
+            )
+            .await
+            .unwrap(),
+        );
+
+        assert!(text.contains("\"language\": \"python\""));
+    }
+
+    #[tokio::test]
+    async fn reads_inherited_tasks_configs() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+        let app_context = mocker.mock_app_context();
+
+        let text = get_text(
+            read_resource(
+                TASKS_CONFIG_URI,
+                &app_context,
+                &workspace_graph,
+                &mocker.config_loader,
+            )
+            .await
+            .unwrap(),
+        );
+
+        assert!(text.contains(".moon/tasks/all.yml"));
+        assert!(text.contains("\"lint\""));
+    }
+
+    #[tokio::test]
+    async fn errors_for_unknown_resources() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+        let app_context = mocker.mock_app_context();
+
+        assert!(
+            read_resource(
+                "moon://projects/unknown/config",
+                &app_context,
+                &workspace_graph,
+                &mocker.config_loader,
+            )
+            .await
+            .is_err()
+        );
+        assert!(
+            read_resource(
+                "moon://unknown",
+                &app_context,
+                &workspace_graph,
+                &mocker.config_loader,
+            )
+            .await
+            .is_err()
+        );
+    }
+}
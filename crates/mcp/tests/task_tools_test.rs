@@ -0,0 +1,258 @@
+use moon_action::{Action, ActionNode, ActionStatus, Operation, RunTaskNode};
+use moon_mcp::tools::action_tools::{RunTaskResult, RunTasksTool};
+use moon_mcp::tools::project_tools::QueryProjectsTool;
+use moon_mcp::tools::task_tools::QueryTasksTool;
+use moon_mcp::tools::vcs_tools::GetAffectedTasksTool;
+use moon_task::Target;
+use moon_test_utils::WorkspaceMocker;
+use rust_mcp_sdk::schema::CallToolResult;
+use serde_json::Value;
+use starbase_sandbox::{Sandbox, create_empty_sandbox};
+use std::sync::Arc;
+
+fn create_validator(schema: &Value) -> jsonschema::Validator {
+    assert!(jsonschema::draft4::new(schema).is_ok());
+    assert!(jsonschema::draft7::new(schema).is_ok());
+
+    jsonschema::draft202012::new(schema).unwrap()
+}
+
+fn create_sandbox() -> Sandbox {
+    let sandbox = create_empty_sandbox();
+    sandbox.create_file(
+        "a/moon.yml",
+        "language: rust\ntasks:\n  build:\n    command: build",
+    );
+    sandbox.create_file(
+        "b/moon.yml",
+        "language: javascript\ntasks:\n  lint:\n    command: lint",
+    );
+    sandbox
+}
+
+fn get_json(result: CallToolResult) -> Value {
+    let value = serde_json::to_value(result).unwrap();
+
+    serde_json::from_str(value["content"][0]["text"].as_str().unwrap()).unwrap()
+}
+
+fn create_execution(exit_code: i32, stdout: &str, status: ActionStatus) -> Operation {
+    let mut operation = Operation::task_execution("build");
+
+    let output = operation.get_exec_output_mut().unwrap();
+    output.exit_code = Some(exit_code);
+    output.set_stdout(stdout.into());
+
+    operation.finish(status);
+    operation
+}
+
+mod run_tasks {
+    use super::*;
+
+    #[test]
+    fn accepts_targets() {
+        let schema = serde_json::to_value(RunTasksTool::tool().input_schema).unwrap();
+        let validator = create_validator(&schema);
+
+        assert!(
+            validator
+                .validate(&serde_json::json!({
+                    "targets": ["app:build", ":lint"],
+                    "include_dependents": true
+                }))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_missing_targets() {
+        let schema = serde_json::to_value(RunTasksTool::tool().input_schema).unwrap();
+        let validator = create_validator(&schema);
+
+        assert!(
+            validator
+                .validate(&serde_json::json!({ "include_dependents": true }))
+                .is_err()
+        );
+    }
+}
+
+mod run_task_result {
+    use super::*;
+
+    #[test]
+    fn uses_output_of_last_attempt() {
+        let mut action = Action::new(ActionNode::run_task(RunTaskNode::new(
+            Target::parse("a:build").unwrap(),
+        )));
+        action
+            .operations
+            .push(create_execution(1, "first", ActionStatus::Failed));
+        action
+            .operations
+            .push(create_execution(0, "second", ActionStatus::Passed));
+        action.finish(ActionStatus::Passed);
+
+        let result = RunTaskResult::from_action(&action);
+
+        assert_eq!(result.label, "RunTask(a:build)");
+        assert_eq!(result.status, ActionStatus::Passed);
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(result.stdout.as_deref(), Some("second"));
+        assert_eq!(result.stderr, None);
+        assert_eq!(result.error, None);
+    }
+
+    #[test]
+    fn includes_error_without_execution() {
+        let mut action = Action::new(ActionNode::run_task(RunTaskNode::new(
+            Target::parse("a:build").unwrap(),
+        )));
+        action.fail(miette::miette!("Task failed to start"));
+        action.finish(ActionStatus::Failed);
+
+        let result = RunTaskResult::from_action(&action);
+
+        assert_eq!(result.status, ActionStatus::Failed);
+        assert_eq!(result.error.as_deref(), Some("Task failed to start"));
+        assert_eq!(result.exit_code, None);
+        assert_eq!(result.stdout, None);
+    }
+}
+
+mod get_affected_tasks {
+    use super::*;
+
+    #[test]
+    fn accepts_empty_input() {
+        let schema = serde_json::to_value(GetAffectedTasksTool::tool().input_schema).unwrap();
+        let validator = create_validator(&schema);
+
+        assert!(validator.validate(&serde_json::json!({})).is_ok());
+    }
+
+    #[test]
+    fn accepts_revisions() {
+        let schema = serde_json::to_value(GetAffectedTasksTool::tool().input_schema).unwrap();
+        let validator = create_validator(&schema);
+
+        assert!(
+            validator
+                .validate(&serde_json::json!({
+                    "base": "master",
+                    "head": "HEAD",
+                    "remote": true
+                }))
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_tasks_affected_by_local_changes() {
+        let sandbox = create_sandbox();
+        sandbox.enable_git();
+        sandbox.create_file("a/src/lib.rs", "");
+
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let app_context = mocker.mock_app_context();
+        let workspace_graph = Arc::new(mocker.mock_workspace_graph().await);
+
+        let tool = GetAffectedTasksTool {
+            remote: Some(false),
+            ..Default::default()
+        };
+        let json = get_json(
+            tool.call_tool(&app_context, &workspace_graph)
+                .await
+                .unwrap(),
+        );
+        let tasks = json["tasks"].as_object().unwrap();
+
+        assert!(tasks.contains_key("a:build"));
+        assert!(!tasks.contains_key("b:lint"));
+    }
+}
+
+mod query_tools {
+    use super::*;
+
+    #[test]
+    fn projects_requires_query() {
+        let schema = serde_json::to_value(QueryProjectsTool::tool().input_schema).unwrap();
+        let validator = create_validator(&schema);
+
+        assert!(
+            validator
+                .validate(&serde_json::json!({ "query": "language=rust" }))
+                .is_ok()
+        );
+        assert!(validator.validate(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn tasks_requires_query() {
+        let schema = serde_json::to_value(QueryTasksTool::tool().input_schema).unwrap();
+        let validator = create_validator(&schema);
+
+        assert!(
+            validator
+                .validate(&serde_json::json!({ "query": "taskType=build" }))
+                .is_ok()
+        );
+        assert!(validator.validate(&serde_json::json!({})).is_err());
+    }
+
+    #[tokio::test]
+    async fn returns_matching_projects() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+
+        let json = get_json(
+            QueryProjectsTool {
+                query: "language=rust".into(),
+            }
+            .call_tool(&workspace_graph)
+            .unwrap(),
+        );
+        let projects = json["projects"].as_array().unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0]["id"], "a");
+    }
+
+    #[tokio::test]
+    async fn returns_matching_tasks() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+
+        let json = get_json(
+            QueryTasksTool {
+                query: "task=lint".into(),
+            }
+            .call_tool(&workspace_graph)
+            .unwrap(),
+        );
+        let tasks = json["tasks"].as_array().unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["target"], "b:lint");
+    }
+
+    #[tokio::test]
+    async fn errors_on_invalid_query() {
+        let sandbox = create_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_default_projects();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+
+        assert!(
+            QueryProjectsTool {
+                query: "unknown=value".into(),
+            }
+            .call_tool(&workspace_graph)
+            .is_err()
+        );
+    }
+}
@@ -9,6 +9,7 @@ use std::ffi::{OsStr, OsString};
 use std::hash::Hasher;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedSender;
 
 #[derive(Debug, PartialEq)]
 pub enum Env {
@@ -156,6 +157,9 @@ pub struct Command {
     /// Values to pass to stdin
     pub input: Vec<OsString>,
 
+    /// Receives each line of streamed output
+    pub output_listener: Option<UnboundedSender<String>>,
+
     /// Paths to prepend to `PATH`
     pub paths: VecDeque<OsString>,
 
@@ -186,6 +190,7 @@ impl Command {
             }),
             error_on_nonzero: true,
             input: vec![],
+            output_listener: None,
             paths: VecDeque::new(),
             prefix: None,
             print_command: false,
@@ -578,6 +583,11 @@ impl Command {
         self
     }
 
+    pub fn set_output_listener(&mut self, listener: UnboundedSender<String>) -> &mut Self {
+        self.output_listener = Some(listener);
+        self
+    }
+
     pub fn set_prefix(&mut self, prefix: &str) -> &mut Self {
         self.prefix = Some(prefix.to_owned());
         self
@@ -20,6 +20,7 @@ use std::process::{Command as StdCommand, Stdio};
 use std::time::Instant;
 use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio::process::{Child, Command as TokioCommand};
+use tokio::sync::mpsc::UnboundedSender;
 use tokio::task::{self, JoinHandle};
 use tracing::{debug, enabled};
 
@@ -192,6 +193,17 @@ impl Command {
         let instant = Instant::now();
         let mut command = self.create_async_command()?;
 
+        // An output listener can only observe lines that pass through us,
+        // so pipe and relay stdout/stderr instead of inheriting them
+        let relay = match (&self.output_listener, &self.console) {
+            (Some(listener), Some(console)) => Some((listener.clone(), console.clone())),
+            _ => None,
+        };
+
+        if relay.is_some() {
+            command.stderr(Stdio::piped()).stdout(Stdio::piped());
+        }
+
         let child = if self.should_pass_stdin() {
             command.stdin(Stdio::piped());
 
@@ -214,6 +226,30 @@ impl Command {
 
         self.pre_log_command(&shared_child);
 
+        if let Some((listener, console)) = relay {
+            let prefix = self.get_prefix().map(|prefix| prefix.to_owned());
+
+            let stderr_handle = spawn_stream_capture_lines(
+                shared_child.take_stderr().await,
+                console.stderr(),
+                prefix.clone(),
+                Some(listener.clone()),
+                "stderr",
+            );
+            let stdout_handle = spawn_stream_capture_lines(
+                shared_child.take_stdout().await,
+                console.stdout(),
+                prefix,
+                Some(listener),
+                "stdout",
+            );
+
+            // Wait for the pipes to hit EOF before waiting on the child,
+            // otherwise output may be lost
+            let _ = stderr_handle.await;
+            let _ = stdout_handle.await;
+        }
+
         let result = shared_child
             .wait()
             .await
@@ -285,12 +321,14 @@ impl Command {
             shared_child.take_stderr().await,
             console.stderr(),
             prefix.clone(),
+            self.output_listener.clone(),
             "stderr",
         );
         let stdout_handle = spawn_stream_capture_lines(
             shared_child.take_stdout().await,
             console.stdout(),
             prefix,
+            self.output_listener.clone(),
             "stdout",
         );
 
@@ -393,12 +431,14 @@ impl Command {
             shared_child.take_stderr().await,
             console.stderr(),
             prefix.clone(),
+            self.output_listener.clone(),
             "stderr",
         );
         let stdout_handle = spawn_stream_capture_bytes(
             shared_child.take_stdout().await,
             console.stdout(),
             prefix,
+            self.output_listener.clone(),
             "stdout",
         );
 
@@ -665,6 +705,7 @@ fn spawn_stream_capture_bytes<R>(
     reader: Option<R>,
     stream: ConsoleStream,
     prefix: Option<String>,
+    listener: Option<UnboundedSender<String>>,
     label: &'static str,
 ) -> JoinHandle<Vec<u8>>
 where
@@ -679,6 +720,7 @@ where
 
         let mut buf = [0u8; 8192];
         let mut at_line_start = true;
+        let mut line_start = 0;
 
         loop {
             match reader.read(&mut buf).await {
@@ -710,6 +752,24 @@ where
                     });
 
                     captured.extend_from_slice(chunk);
+
+                    // Notify the listener of each complete line
+                    if let Some(listener) = &listener {
+                        while let Some(offset) = captured[line_start..]
+                            .iter()
+                            .position(|byte| *byte == b'\n')
+                        {
+                            let line = &captured[line_start..line_start + offset];
+
+                            let _ = listener.send(
+                                String::from_utf8_lossy(line)
+                                    .trim_end_matches('\r')
+                                    .to_owned(),
+                            );
+
+                            line_start += offset + 1;
+                        }
+                    }
                 }
                 Err(error) if error.kind() == io::ErrorKind::Interrupted => {
                     continue;
@@ -763,6 +823,7 @@ fn spawn_stream_capture_lines<R>(
     reader: Option<R>,
     stream: ConsoleStream,
     prefix: Option<String>,
+    listener: Option<UnboundedSender<String>>,
     label: &'static str,
 ) -> JoinHandle<Vec<String>>
 where
@@ -786,6 +847,10 @@ where
                         stream.write_line(&line)
                     };
 
+                    if let Some(listener) = &listener {
+                        let _ = listener.send(line.clone());
+                    }
+
                     captured_lines.push(line);
                 }
                 Ok(None) => break,
@@ -3,6 +3,7 @@
 use moon_console::Console;
 use moon_process::{ChildExit, Command, ProcessError};
 use std::sync::Arc;
+use tokio::sync::mpsc;
 
 fn create_command(script: &str) -> Command {
     let mut command = Command::new("bash");
@@ -148,6 +149,29 @@ mod exec_stream_output {
             ProcessError::ExitNonZero { .. }
         ));
     }
+
+    #[tokio::test]
+    async fn notifies_output_listener() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let mut command = create_command("echo 'a'; echo 'b' 1>&2");
+        command.set_output_listener(sender);
+
+        let output = command.exec_stream_output().await.unwrap();
+
+        assert!(output.success());
+        assert!(output.stdout.is_empty());
+
+        let mut lines = vec![];
+
+        while let Ok(line) = receiver.try_recv() {
+            lines.push(line);
+        }
+
+        lines.sort();
+
+        assert_eq!(lines, ["a", "b"]);
+    }
 }
 
 mod exec_stream_and_capture_output {
@@ -164,6 +188,18 @@ mod exec_stream_and_capture_output {
         assert_eq!(output.stdout.as_ref(), b"a\nb\n");
         assert_eq!(output.stderr.as_ref(), b"err");
     }
+
+    #[tokio::test]
+    async fn notifies_output_listener() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let mut command = create_command(r"printf 'a\nb\n'");
+        command.set_output_listener(sender);
+        command.exec_stream_and_capture_output().await.unwrap();
+
+        assert_eq!(receiver.try_recv().unwrap(), "a");
+        assert_eq!(receiver.try_recv().unwrap(), "b");
+    }
 }
 
 mod child_env {
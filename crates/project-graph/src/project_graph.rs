@@ -73,6 +73,10 @@ pub struct ProjectGraph {
     /// Map of aliases to project IDs.
     pub aliases: FxHashMap<String, Id>,
 
+    /// Dependency edges that were disconnected while building,
+    /// because they would have formed a cycle.
+    pub cycle_edges: Vec<(Id, Id)>,
+
     /// ID of the default project.
     pub default_id: Option<Id>,
 
@@ -542,12 +546,47 @@ impl GraphConnections<Project, DependencyScope, Id> for ProjectGraph {
     }
 }
 
+impl GraphAnalyzer<Project, DependencyScope, Id> for ProjectGraph {
+    fn get_cycle_edges(&self) -> Vec<(Id, Id)> {
+        self.cycle_edges.clone()
+    }
+}
+
 impl GraphConversions<Project, DependencyScope, Id> for ProjectGraph {}
 
+impl GraphNodeAttributes<Project> for ProjectGraph {
+    fn get_node_attributes(&self, project: &Project) -> Vec<(&'static str, String)> {
+        let mut attributes = vec![
+            ("language", project.language.to_string()),
+            ("layer", project.layer.to_string()),
+            ("stack", project.stack.to_string()),
+        ];
+
+        if !project.config.tags.is_empty() {
+            attributes.push((
+                "tags",
+                project
+                    .config
+                    .tags
+                    .iter()
+                    .map(|tag| tag.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ));
+        }
+
+        attributes
+    }
+}
+
 impl GraphToDot<Project, DependencyScope, Id> for ProjectGraph {}
 
+impl GraphToGraphMl<Project, DependencyScope, Id> for ProjectGraph {}
+
 impl GraphToJson<Project, DependencyScope, Id> for ProjectGraph {}
 
+impl GraphToMermaid<Project, DependencyScope, Id> for ProjectGraph {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
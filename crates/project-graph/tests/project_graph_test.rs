@@ -2307,6 +2307,99 @@ mod project_graph {
             assert_eq!(get_ids_from_projects(projects), vec!["a"]);
         }
 
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_task_variant() {
+            let (_sandbox, graph) = build_graph_from_fixture("query").await;
+
+            let projects = graph
+                .query_projects(build_query("taskVariant=e2e").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["c"]);
+
+            let projects = graph
+                .query_projects(build_query("has:taskVariant").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["c"]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_project_owner() {
+            let (_sandbox, graph) = build_graph_from_fixture("query").await;
+
+            let projects = graph
+                .query_projects(build_query("projectOwner=@team/web").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a"]);
+
+            let projects = graph
+                .query_projects(build_query("projectOwner~@team/*").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a", "b"]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_project_metadata() {
+            let (_sandbox, graph) = build_graph_from_fixture("query").await;
+
+            let projects = graph
+                .query_projects(build_query("projectMetadata.tier=critical").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a"]);
+
+            let projects = graph
+                .query_projects(build_query("projectMetadata.tier=internal").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["b"]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_task_input_and_output() {
+            let (_sandbox, graph) = build_graph_from_fixture("query").await;
+
+            let projects = graph
+                .query_projects(build_query("taskInput~**/*.graphql").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a"]);
+
+            let projects = graph
+                .query_projects(build_query("taskOutput~dist*").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a", "d"]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_existence() {
+            let (_sandbox, graph) = build_graph_from_fixture("query").await;
+
+            let projects = graph
+                .query_projects(build_query("has:projectOwner").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a", "b"]);
+
+            let projects = graph
+                .query_projects(build_query("!has:projectOwner").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["c", "d"]);
+
+            let projects = graph
+                .query_projects(
+                    build_query("has:projectMetadata.tier && !has:projectChannel").unwrap(),
+                )
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a"]);
+        }
+
         #[tokio::test(flavor = "multi_thread")]
         async fn with_and_conditions() {
             let (_sandbox, graph) = build_graph_from_fixture("query").await;
@@ -2342,6 +2435,163 @@ mod project_graph {
 
             assert_eq!(get_ids_from_projects(projects), vec!["b", "d"]);
         }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_depends_on() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+
+            let projects = graph
+                .query_projects(build_query("dependsOn=c").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["b", "d"]);
+
+            let projects = graph
+                .query_projects(build_query("deepDependsOn=c").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a", "b", "d"]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_depends_on_negated() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+
+            let projects = graph
+                .query_projects(build_query("dependsOn!=c").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a", "c"]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn by_dependency_of() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+
+            let projects = graph
+                .query_projects(build_query("dependencyOf=a").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["b"]);
+
+            let projects = graph
+                .query_projects(build_query("deepDependencyOf~{a,b}").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["b", "c"]);
+        }
+    }
+
+    mod analyze {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn computes_metrics() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+            let analysis = graph.projects.analyze();
+
+            assert_eq!(
+                analysis
+                    .nodes
+                    .iter()
+                    .map(|metrics| (metrics.node.as_str(), metrics.fan_in, metrics.fan_out))
+                    .collect::<Vec<_>>(),
+                [("c", 2, 0), ("b", 2, 1), ("a", 1, 1), ("d", 0, 3)]
+            );
+            assert_eq!(analysis.nodes[0].deep_dependents, 3);
+            assert_eq!(analysis.without_dependents, [Id::raw("d")]);
+            assert_eq!(
+                analysis.longest_chains,
+                [[Id::raw("d"), Id::raw("a"), Id::raw("b"), Id::raw("c")]]
+            );
+            assert!(analysis.articulation_points.is_empty());
+            assert!(analysis.cycles.is_empty());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn finds_articulation_points() {
+            let (_sandbox, graph) = build_graph_from_fixture("cycle").await;
+            let analysis = graph.projects.analyze();
+
+            // a -> b -> c, as the cycle is disconnected
+            assert_eq!(analysis.articulation_points, [Id::raw("b")]);
+            assert_eq!(analysis.without_dependents, [Id::raw("a")]);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn finds_disconnected_cycles() {
+            let (_sandbox, graph) = build_graph_from_fixture("cycle").await;
+            let analysis = graph.projects.analyze();
+
+            assert_eq!(
+                analysis.cycles,
+                [[Id::raw("a"), Id::raw("b"), Id::raw("c")]]
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn finds_disconnected_self_cycles() {
+            let (_sandbox, graph) = build_graph_from_fixture_for_builder("self-loop", false).await;
+            let analysis = graph.projects.analyze();
+
+            assert_eq!(analysis.cycles, [[Id::raw("a")]]);
+        }
+    }
+
+    mod to_mermaid {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn renders_full() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+            let output = graph.projects.to_mermaid();
+
+            assert!(output.starts_with("flowchart TB\n"));
+            assert!(output.contains("[\"a<br/><small>language: "));
+            assert!(output.contains(" -->|\"development\"| "));
+            assert!(output.contains(" -->|\"peer\"| "));
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn renders_focused() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+            let focused = graph.projects.focus_for(&Id::raw("b"), false).unwrap();
+            let output = focused.to_mermaid();
+
+            assert!(output.contains("[\"b<br/>"));
+            assert!(output.contains("[\"c<br/>"));
+            assert!(!output.contains("[\"a<br/>"));
+            assert_eq!(output.matches("-->").count(), 1);
+        }
+    }
+
+    mod to_graphml {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn renders_full() {
+            let (_sandbox, graph) = build_graph_from_fixture("query").await;
+            let output = graph.projects.to_graphml();
+
+            assert!(
+                output.contains(
+                    r#"<key id="layer" for="node" attr.name="layer" attr.type="string"/>"#
+                )
+            );
+            assert!(output.contains(r#"<data key="label">a</data>"#));
+            assert!(output.contains(r#"<data key="layer">application</data>"#));
+            assert!(output.contains(r#"<data key="tags">two,three,five</data>"#));
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn renders_edges() {
+            let (_sandbox, graph) = build_graph_from_fixture("dependencies").await;
+            let output = graph.projects.to_graphml();
+
+            assert_eq!(output.matches("<node ").count(), 4);
+            assert_eq!(output.matches("<edge ").count(), 5);
+            assert!(output.contains(r#"<data key="edge_label">build</data>"#));
+        }
     }
 
     mod to_dot {
@@ -10,20 +10,37 @@ use tracing::instrument;
 pub type FieldValue<'l> = Cow<'l, str>;
 pub type FieldValues<'l> = Vec<FieldValue<'l>>;
 
+const METADATA_PREFIX: &str = "projectMetadata.";
+
 #[derive(Debug, PartialEq)]
 pub enum Field<'l> {
+    DeepDependencyOf(FieldValues<'l>),
+    DeepDependsOn(FieldValues<'l>),
+    DeepTaskDependencyOf(FieldValues<'l>),
+    DeepTaskDependsOn(FieldValues<'l>),
+    DependencyOf(FieldValues<'l>),
+    DependsOn(FieldValues<'l>),
     Language(Vec<LanguageType>),
     Project(FieldValues<'l>),
     ProjectAlias(FieldValues<'l>),
+    ProjectChannel(FieldValues<'l>),
     ProjectId(FieldValues<'l>),
     ProjectLayer(Vec<LayerType>),
+    ProjectMaintainer(FieldValues<'l>),
+    ProjectMetadata(FieldValue<'l>, FieldValues<'l>),
+    ProjectOwner(FieldValues<'l>),
     ProjectSource(FieldValues<'l>),
     ProjectStack(Vec<StackType>),
     ProjectTag(FieldValues<'l>),
     Task(FieldValues<'l>),
+    TaskDependencyOf(FieldValues<'l>),
+    TaskDependsOn(FieldValues<'l>),
+    TaskInput(FieldValues<'l>),
+    TaskOutput(FieldValues<'l>),
     TaskTag(FieldValues<'l>),
     TaskToolchain(FieldValues<'l>),
     TaskType(Vec<TaskType>),
+    TaskVariant(FieldValues<'l>),
 }
 
 #[derive(Debug, PartialEq)]
@@ -35,6 +52,11 @@ pub enum Condition<'l> {
     Criteria {
         criteria: Criteria<'l>,
     },
+    /// Field exists and is not empty, using the `has:` (or `!has:`) operator.
+    Exists {
+        field: Field<'l>,
+        negated: bool,
+    },
 }
 
 impl Condition<'_> {
@@ -52,7 +74,7 @@ impl Condition<'_> {
                 ComparisonOperator::Like => GlobSet::new(haystack)?.is_included(needle),
                 ComparisonOperator::NotLike => !GlobSet::new(haystack)?.is_included(needle),
             },
-            Condition::Criteria { .. } => false,
+            Condition::Criteria { .. } | Condition::Exists { .. } => false,
         })
     }
 
@@ -70,6 +92,38 @@ impl Condition<'_> {
         Ok(false)
     }
 
+    /// Match against the keys of related graph nodes (dependencies or dependents).
+    /// Unlike [`Condition::matches_list`], negated operators require that none
+    /// of the related keys match, so `dependsOn!=a` excludes anything depending on `a`.
+    pub fn matches_related<T: AsRef<str>>(
+        &self,
+        haystack: &FieldValues,
+        needles: &[T],
+    ) -> miette::Result<bool> {
+        let Condition::Field { op, .. } = self else {
+            return Ok(false);
+        };
+
+        let globs = match op {
+            ComparisonOperator::Like | ComparisonOperator::NotLike => Some(GlobSet::new(haystack)?),
+            _ => None,
+        };
+
+        let matched = needles.iter().any(|needle| {
+            let needle = needle.as_ref();
+
+            match &globs {
+                Some(globs) => globs.is_included(needle),
+                None => haystack.contains(&Cow::Borrowed(needle)),
+            }
+        });
+
+        Ok(match op {
+            ComparisonOperator::Equal | ComparisonOperator::Like => matched,
+            ComparisonOperator::NotEqual | ComparisonOperator::NotLike => !matched,
+        })
+    }
+
     pub fn matches_enum<T: PartialEq>(&self, haystack: &[T], needle: &T) -> miette::Result<bool> {
         Ok(match self {
             Condition::Field { op, .. } => match op {
@@ -78,7 +132,7 @@ impl Condition<'_> {
                 // Like and NotLike are not supported for enums
                 _ => false,
             },
-            Condition::Criteria { .. } => false,
+            Condition::Criteria { .. } | Condition::Exists { .. } => false,
         })
     }
 }
@@ -118,6 +172,79 @@ fn build_criteria_enum<T: FromStr>(
     Ok(result)
 }
 
+fn build_field<'l>(
+    field: Cow<'l, str>,
+    op: &ComparisonOperator,
+    value: FieldValues<'l>,
+) -> miette::Result<Field<'l>> {
+    if field.starts_with(METADATA_PREFIX) {
+        let key = match field {
+            Cow::Borrowed(inner) => Cow::Borrowed(&inner[METADATA_PREFIX.len()..]),
+            Cow::Owned(inner) => Cow::Owned(inner[METADATA_PREFIX.len()..].to_owned()),
+        };
+
+        return Ok(Field::ProjectMetadata(key, value));
+    }
+
+    Ok(match field.as_ref() {
+        "deepDependencyOf" | "deepDependentOf" => Field::DeepDependencyOf(value),
+        "deepDependsOn" => Field::DeepDependsOn(value),
+        "deepTaskDependencyOf" | "deepTaskDependentOf" => Field::DeepTaskDependencyOf(value),
+        "deepTaskDependsOn" => Field::DeepTaskDependsOn(value),
+        "dependencyOf" | "dependentOf" => Field::DependencyOf(value),
+        "dependsOn" => Field::DependsOn(value),
+        "language" => Field::Language(build_criteria_enum::<LanguageType>(&field, op, value)?),
+        "project" => Field::Project(value),
+        "projectAlias" | "projectAliases" => Field::ProjectAlias(value),
+        "projectChannel" => Field::ProjectChannel(value),
+        "projectLayer" => Field::ProjectLayer(build_criteria_enum::<LayerType>(&field, op, value)?),
+        "projectId" => Field::ProjectId(value),
+        "projectMaintainer" | "projectMaintainers" => Field::ProjectMaintainer(value),
+        "projectOwner" => Field::ProjectOwner(value),
+        "projectSource" => Field::ProjectSource(value),
+        "projectStack" => Field::ProjectStack(build_criteria_enum::<StackType>(&field, op, value)?),
+        "projectTag" | "projectTags" | "tag" => Field::ProjectTag(value),
+        "task" => Field::Task(value),
+        "taskDependencyOf" | "taskDependentOf" => Field::TaskDependencyOf(value),
+        "taskDependsOn" => Field::TaskDependsOn(value),
+        "taskInput" | "taskInputs" => Field::TaskInput(value),
+        "taskOutput" | "taskOutputs" => Field::TaskOutput(value),
+        "taskTag" | "taskTags" => Field::TaskTag(value),
+        "taskToolchain" | "taskToolchains" => Field::TaskToolchain(value),
+        "taskType" => Field::TaskType(build_criteria_enum::<TaskType>(&field, op, value)?),
+        "taskVariant" => Field::TaskVariant(value),
+        _ => {
+            return Err(QueryError::UnknownField(field.to_string()).into());
+        }
+    })
+}
+
+fn build_exists_field(field: Cow<'_, str>) -> miette::Result<Field<'_>> {
+    let name = field.to_string();
+    let field = build_field(field, &ComparisonOperator::Equal, vec![])?;
+
+    // Only fields that may be empty or missing can be checked for existence
+    if !matches!(
+        field,
+        Field::ProjectAlias(_)
+            | Field::ProjectChannel(_)
+            | Field::ProjectMaintainer(_)
+            | Field::ProjectMetadata(..)
+            | Field::ProjectOwner(_)
+            | Field::ProjectTag(_)
+            | Field::Task(_)
+            | Field::TaskInput(_)
+            | Field::TaskOutput(_)
+            | Field::TaskTag(_)
+            | Field::TaskToolchain(_)
+            | Field::TaskVariant(_)
+    ) {
+        return Err(QueryError::UnsupportedExistsOperator(name).into());
+    }
+
+    Ok(field)
+}
+
 fn build_criteria(ast: Vec<AstNode<'_>>) -> miette::Result<Criteria<'_>> {
     let mut op = None;
     let mut conditions = vec![];
@@ -125,34 +252,16 @@ fn build_criteria(ast: Vec<AstNode<'_>>) -> miette::Result<Criteria<'_>> {
     for node in ast {
         match node {
             AstNode::Comparison { field, op, value } => {
-                let field = match field.as_ref() {
-                    "language" => {
-                        Field::Language(build_criteria_enum::<LanguageType>(&field, &op, value)?)
-                    }
-                    "project" => Field::Project(value),
-                    "projectAlias" | "projectAliases" => Field::ProjectAlias(value),
-                    "projectLayer" => {
-                        Field::ProjectLayer(build_criteria_enum::<LayerType>(&field, &op, value)?)
-                    }
-                    "projectId" => Field::ProjectId(value),
-                    "projectSource" => Field::ProjectSource(value),
-                    "projectStack" => {
-                        Field::ProjectStack(build_criteria_enum::<StackType>(&field, &op, value)?)
-                    }
-                    "projectTag" | "projectTags" | "tag" => Field::ProjectTag(value),
-                    "task" => Field::Task(value),
-                    "taskTag" | "taskTags" => Field::TaskTag(value),
-                    "taskToolchain" | "taskToolchains" => Field::TaskToolchain(value),
-                    "taskType" => {
-                        Field::TaskType(build_criteria_enum::<TaskType>(&field, &op, value)?)
-                    }
-                    _ => {
-                        return Err(QueryError::UnknownField(field.to_string()).into());
-                    }
-                };
+                let field = build_field(field, &op, value)?;
 
                 conditions.push(Condition::Field { field, op });
             }
+            AstNode::Existence { field, negated } => {
+                conditions.push(Condition::Exists {
+                    field: build_exists_field(field)?,
+                    negated,
+                });
+            }
             AstNode::Op { op: next_op } => {
                 if let Some(current_op) = &op {
                     if &next_op != current_op {
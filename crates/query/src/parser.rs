@@ -34,6 +34,10 @@ pub enum AstNode<'l> {
         op: ComparisonOperator,
         value: Vec<Cow<'l, str>>,
     },
+    Existence {
+        field: Cow<'l, str>,
+        negated: bool,
+    },
     Op {
         op: LogicalOperator,
     },
@@ -73,6 +77,16 @@ fn parse_ast_node(pair: Pair<Rule>) -> Result<Option<AstNode>, Box<Error<Rule>>>
                 },
             })
         }
+        Rule::existence => {
+            let mut inner = pair.into_inner();
+            let op = inner.next().expect("Missing existence operator.");
+            let field = inner.next().expect("Missing field name.");
+
+            Some(AstNode::Existence {
+                field: Cow::Borrowed(field.as_str()),
+                negated: matches!(op.as_rule(), Rule::nhas),
+            })
+        }
         Rule::expr_group => Some(AstNode::Group {
             nodes: parse_ast(pair.into_inner())?,
         }),
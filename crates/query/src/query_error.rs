@@ -24,6 +24,10 @@ pub enum QueryError {
     #[error("Like operators (~ and !~) are not supported for field {}.", .0.style(Style::Id))]
     UnsupportedLikeOperator(String),
 
+    #[diagnostic(code(query::operator::unsupported_exists))]
+    #[error("Existence operators (has: and !has:) are not supported for field {}.", .0.style(Style::Id))]
+    UnsupportedExistsOperator(String),
+
     #[diagnostic(code(query::parse::failed))]
     #[error("Failed to parse query:\n\n{}", .0.style(Style::MutedLight))]
     ParseFailure(String),
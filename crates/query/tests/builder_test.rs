@@ -254,6 +254,138 @@ mod mql_build {
         // }
     }
 
+    mod dependencies {
+        use super::*;
+
+        #[test]
+        fn depends_on() {
+            assert_eq!(
+                build_query("dependsOn=app").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::DependsOn(value_list(["app"])),
+                        op: ComparisonOperator::Equal,
+                    }],
+                    input: Some("dependsOn=app".into())
+                }
+            );
+        }
+
+        #[test]
+        fn deep_dependent_of_like() {
+            assert_eq!(
+                build_query("deepDependencyOf~app-*").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::DeepDependencyOf(value_list(["app-*"])),
+                        op: ComparisonOperator::Like,
+                    }],
+                    input: Some("deepDependencyOf~app-*".into())
+                }
+            );
+        }
+
+        #[test]
+        fn task_depends_on_target() {
+            assert_eq!(
+                build_query("taskDependsOn!=[app:build,lib:build]").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::TaskDependsOn(value_list(["app:build", "lib:build"])),
+                        op: ComparisonOperator::NotEqual,
+                    }],
+                    input: Some("taskDependsOn!=[app:build,lib:build]".into())
+                }
+            );
+        }
+
+        #[test]
+        fn supports_dependent_of_aliases() {
+            assert_eq!(
+                build_query("dependentOf=app").unwrap().conditions,
+                build_query("dependencyOf=app").unwrap().conditions
+            );
+            assert_eq!(
+                build_query("deepDependentOf=app").unwrap().conditions,
+                build_query("deepDependencyOf=app").unwrap().conditions
+            );
+            assert_eq!(
+                build_query("taskDependentOf=app:build").unwrap().conditions,
+                build_query("taskDependencyOf=app:build")
+                    .unwrap()
+                    .conditions
+            );
+            assert_eq!(
+                build_query("deepTaskDependentOf=app:build")
+                    .unwrap()
+                    .conditions,
+                build_query("deepTaskDependencyOf=app:build")
+                    .unwrap()
+                    .conditions
+            );
+        }
+
+        #[test]
+        fn deep_task_dependent_of_glob() {
+            assert_eq!(
+                build_query("deepTaskDependencyOf~app:*").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::DeepTaskDependencyOf(value_list(["app:*"])),
+                        op: ComparisonOperator::Like,
+                    }],
+                    input: Some("deepTaskDependencyOf~app:*".into())
+                }
+            );
+        }
+    }
+
+    mod exists {
+        use super::*;
+
+        #[test]
+        fn has_field() {
+            assert_eq!(
+                build_query("has:projectOwner").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Exists {
+                        field: Field::ProjectOwner(vec![]),
+                        negated: false,
+                    }],
+                    input: Some("has:projectOwner".into())
+                }
+            );
+        }
+
+        #[test]
+        fn not_has_metadata() {
+            assert_eq!(
+                build_query("!has:projectMetadata.tier").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Exists {
+                        field: Field::ProjectMetadata("tier".into(), vec![]),
+                        negated: true,
+                    }],
+                    input: Some("!has:projectMetadata.tier".into())
+                }
+            );
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Existence operators (has: and !has:) are not supported for field language."
+        )]
+        fn errors_for_unsupported_field() {
+            build_query("has:language").unwrap();
+        }
+    }
+
     mod language {
         use super::*;
 
@@ -387,6 +519,43 @@ mod mql_build {
         }
     }
 
+    mod project_metadata {
+        use super::*;
+
+        #[test]
+        fn owner_eq() {
+            assert_eq!(
+                build_query("projectOwner=@team/web").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::ProjectOwner(value_list(["@team/web"])),
+                        op: ComparisonOperator::Equal,
+                    }],
+                    input: Some("projectOwner=@team/web".into())
+                }
+            );
+        }
+
+        #[test]
+        fn custom_field() {
+            assert_eq!(
+                build_query("projectMetadata.tier=[critical,high]").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::ProjectMetadata(
+                            "tier".into(),
+                            value_list(["critical", "high"])
+                        ),
+                        op: ComparisonOperator::Equal,
+                    }],
+                    input: Some("projectMetadata.tier=[critical,high]".into())
+                }
+            );
+        }
+    }
+
     mod project_source {
         use super::*;
 
@@ -616,6 +785,40 @@ mod mql_build {
         }
     }
 
+    mod task_files {
+        use super::*;
+
+        #[test]
+        fn input_like() {
+            assert_eq!(
+                build_query("taskInput~**/*.graphql").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::TaskInput(value_list(["**/*.graphql"])),
+                        op: ComparisonOperator::Like,
+                    }],
+                    input: Some("taskInput~**/*.graphql".into())
+                }
+            );
+        }
+
+        #[test]
+        fn output_not_like() {
+            assert_eq!(
+                build_query("taskOutput!~dist/**").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::TaskOutput(value_list(["dist/**"])),
+                        op: ComparisonOperator::NotLike,
+                    }],
+                    input: Some("taskOutput!~dist/**".into())
+                }
+            );
+        }
+    }
+
     mod task_tag {
         use super::*;
 
@@ -739,4 +942,38 @@ mod mql_build {
             build_query("taskType!~node").unwrap();
         }
     }
+
+    mod task_variant {
+        use super::*;
+
+        #[test]
+        fn valid_value() {
+            assert_eq!(
+                build_query("taskVariant=linux").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::TaskVariant(value_list(["linux"])),
+                        op: ComparisonOperator::Equal,
+                    }],
+                    input: Some("taskVariant=linux".into())
+                }
+            );
+        }
+
+        #[test]
+        fn valid_like() {
+            assert_eq!(
+                build_query("taskVariant~node-*").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::TaskVariant(value_list(["node-*"])),
+                        op: ComparisonOperator::Like,
+                    }],
+                    input: Some("taskVariant~node-*".into())
+                }
+            );
+        }
+    }
 }
@@ -348,6 +348,40 @@ mod mql_parse {
         assert!(parse_query("key=id_underscore").is_ok());
         assert!(parse_query("key=id/slash").is_ok());
         assert!(parse_query("key=id.period").is_ok());
+        assert!(parse_query("key=project:task").is_ok());
+        assert!(parse_query("key=@scope/project:task").is_ok());
+    }
+
+    #[test]
+    fn metadata_keys() {
+        assert_eq!(
+            parse_query("projectMetadata.tier=critical").unwrap(),
+            vec![AstNode::Comparison {
+                field: "projectMetadata.tier".into(),
+                op: ComparisonOperator::Equal,
+                value: vec!["critical".into()],
+            }],
+        );
+    }
+
+    #[test]
+    fn existence() {
+        assert_eq!(
+            parse_query("has:key && !has:other").unwrap(),
+            vec![
+                AstNode::Existence {
+                    field: "key".into(),
+                    negated: false,
+                },
+                AstNode::Op {
+                    op: LogicalOperator::And,
+                },
+                AstNode::Existence {
+                    field: "other".into(),
+                    negated: true,
+                },
+            ],
+        );
     }
 
     #[test]
@@ -9,9 +9,9 @@ use moon_common::{
 use moon_config::{
     EnvMap, InheritedTasksConfig, Input, MergeStrategy, ProjectConfig, ProjectDependencyConfig,
     ProjectInput, ProjectWorkspaceInheritedTasksConfig, TaskArgs, TaskConfig, TaskDependency,
-    TaskDependencyConfig, TaskOptionAffectedFilesEntry, TaskOptionCache, TaskOptionRunInCI,
-    TaskOptionsConfig, TaskOutputStyle, TaskPreset, TaskPriority, TaskType, ToolchainsConfig,
-    is_glob_like, merge_index_map, merge_vec,
+    TaskDependencyConfig, TaskMatrixVariantConfig, TaskOptionAffectedFilesEntry, TaskOptionCache,
+    TaskOptionRunInCI, TaskOptionsConfig, TaskOutputStyle, TaskPreset, TaskPriority, TaskType,
+    ToolchainsConfig, is_glob_like, merge_index_map, merge_vec,
 };
 use moon_config_loader::ConfigLoader;
 use moon_env_var::contains_env_var;
@@ -272,14 +272,116 @@ impl<'proj> TasksBuilder<'proj> {
     #[instrument(name = "build_tasks", skip_all)]
     pub async fn build(self) -> miette::Result<BTreeMap<Id, Task>> {
         let mut tasks = BTreeMap::new();
+        let mut variant_ids = FxHashSet::default();
 
         for id in &self.task_ids {
-            tasks.insert((*id).to_owned(), self.build_task(id).await?);
+            let mut task = self.build_task(id).await?;
+
+            if let Some(matrix) = self.get_config_matrix(id)? {
+                for variant in self.build_task_variants(&mut task, matrix, &mut variant_ids)? {
+                    tasks.insert(variant.id.clone(), variant);
+                }
+            }
+
+            tasks.insert((*id).to_owned(), task);
         }
 
         Ok(tasks)
     }
 
+    #[instrument(skip(self, task, matrix, variant_ids))]
+    fn build_task_variants(
+        &self,
+        task: &mut Task,
+        matrix: &BTreeMap<Id, TaskMatrixVariantConfig>,
+        variant_ids: &mut FxHashSet<Id>,
+    ) -> miette::Result<Vec<Task>> {
+        let mut variants = vec![];
+        let mut variant_deps = vec![];
+
+        for (variant_key, variant_config) in matrix {
+            let variant_id = Id::new(format!("{}-{variant_key}", task.id))?;
+
+            // Variants of different tasks may also conflict,
+            // for example `a` + `b-c` and `a-b` + `c`
+            if self.task_ids.contains(&variant_id) || !variant_ids.insert(variant_id.clone()) {
+                return Err(TasksBuilderError::MatrixVariantConflict {
+                    task: task.target.clone(),
+                    variant: variant_key.to_string(),
+                    variant_id: variant_id.to_string(),
+                }
+                .into());
+            }
+
+            let target = Target::new(self.project_id, &variant_id)?;
+
+            trace!(
+                task_target = task.target.as_str(),
+                variant_target = target.as_str(),
+                "Expanding matrix variant {}",
+                color::id(variant_key),
+            );
+
+            let mut variant = task.clone();
+            let mut requires_shell = false;
+
+            // Scripts do not support arguments, so only apply them to commands
+            if variant.script.is_none() && variant_config.args != TaskArgs::Noop {
+                let parse_result = self.parse_command_line(&target, &variant_config.args)?;
+
+                if let Some(args) = parse_result.args {
+                    variant.args.extend(args);
+                }
+
+                if let Some(env) = parse_result.env {
+                    variant.env.extend(env);
+                }
+
+                requires_shell = parse_result.requires_shell
+                    || variant.args.iter().any(|arg| is_glob_like(arg))
+                    || variant.args.iter().any(contains_env_var);
+            }
+
+            if let Some(env) = &variant_config.env {
+                variant.env.extend(env.to_owned());
+            }
+
+            if requires_shell && !variant.state.shell_disabled {
+                variant.options.shell = Some(true);
+            }
+
+            variant.id = variant_id;
+            variant.target = target;
+            variant.variant = Some(variant_key.to_owned());
+
+            variant_deps.push(TaskDependencyConfig::new(variant.target.clone()));
+            variants.push(variant);
+        }
+
+        // The original task becomes an aggregate of all variants,
+        // while the variants inherit its dependencies. The persistent
+        // option is preserved, as non-persistent tasks can't depend on
+        // persistent tasks, but resources are released for the variants
+        task.command = TaskArg::new_unquoted("noop");
+        task.args.clear();
+        task.checks.clear();
+        task.deps = variant_deps;
+        task.inputs.clear();
+        task.input_env.clear();
+        task.input_files.clear();
+        task.input_globs.clear();
+        task.options.resources.clear();
+        task.outputs.clear();
+        task.output_files.clear();
+        task.output_globs.clear();
+        task.readiness = None;
+        task.script = None;
+        task.state.default_inputs = false;
+        task.state.empty_inputs = true;
+
+        Ok(variants)
+    }
+
     #[instrument(skip(self))]
     async fn build_task(&self, id: &Id) -> miette::Result<Task> {
         let target = Target::new(self.project_id, id)?;
@@ -366,6 +468,10 @@ impl<'proj> TasksBuilder<'proj> {
                 task.script = config.script.clone();
             }
 
+            if config.readiness.is_some() {
+                task.readiness = config.readiness.clone();
+            }
+
             if let Some(checks) = &config.checks {
                 task.checks = merge_vec(
                     task.checks,
@@ -750,6 +856,14 @@ impl<'proj> TasksBuilder<'proj> {
                 options.priority = *priority;
             }
 
+            if let Some(resources) = &config.resources {
+                options.resources.extend(
+                    resources
+                        .iter()
+                        .map(|(pool, weight)| (pool.to_owned(), *weight)),
+                );
+            }
+
             if let Some(retry_count) = &config.retry_count {
                 options.retry_count = *retry_count;
             }
@@ -1203,6 +1317,20 @@ impl<'proj> TasksBuilder<'proj> {
         Ok(Some(command_line))
     }
 
+    fn get_config_matrix(
+        &self,
+        id: &Id,
+    ) -> miette::Result<Option<&BTreeMap<Id, TaskMatrixVariantConfig>>> {
+        let matrix = self
+            .get_config_inherit_chain(id)?
+            .into_iter()
+            .rev()
+            .find_map(|link| link.config.matrix.as_ref())
+            .filter(|matrix| !matrix.is_empty());
+
+        Ok(matrix)
+    }
+
     fn get_config_inherit_chain(&self, id: &Id) -> miette::Result<Vec<ConfigChain<'_>>> {
         let stack = extract_config(id, &self.local_tasks, &self.global_tasks)?;
 
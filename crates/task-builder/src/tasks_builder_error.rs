@@ -49,6 +49,22 @@ pub enum TasksBuilderError {
         target_id: String,
     },
 
+    #[diagnostic(
+        code(task_builder::matrix_variant_conflict),
+        help = "Rename the matrix variant or the conflicting task."
+    )]
+    #[error(
+        "Unable to expand matrix variant {} for task {}, as a task with the identifier {} already exists.",
+        .variant.style(Style::Id),
+        .task.style(Style::Label),
+        .variant_id.style(Style::Id),
+    )]
+    MatrixVariantConflict {
+        task: Target,
+        variant: String,
+        variant_id: String,
+    },
+
     #[diagnostic(code(task_builder::unknown_target))]
     #[error(
         "Invalid dependency {} for task {}, target does not exist.",
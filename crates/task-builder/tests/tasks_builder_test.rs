@@ -5,6 +5,7 @@ use moon_config::{test_utils::*, *};
 use moon_target::Target;
 use moon_task::{TaskArg, TaskOptionAffectedFiles};
 use starbase_sandbox::create_sandbox;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use utils::TasksBuilderContainer;
@@ -138,6 +139,156 @@ mod tasks_builder {
         }
     }
 
+    mod resources {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn merges_inherited_resources() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+
+            let mut tasks = container.build_tasks("inheritance").await;
+            let task = tasks.remove("build").unwrap();
+
+            assert_eq!(
+                task.options.resources,
+                BTreeMap::from_iter([(Id::raw("cpu"), 2), (Id::raw("db"), 2)])
+            );
+        }
+    }
+
+    mod matrix {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn expands_variants() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+            let tasks = container.build_tasks("matrix").await;
+
+            assert_eq!(
+                tasks
+                    .keys()
+                    .map(|id| id.as_str())
+                    .filter(|id| !id.starts_with("global-"))
+                    .collect::<Vec<_>>(),
+                vec![
+                    "dep",
+                    "script",
+                    "script-a",
+                    "test",
+                    "test-node18",
+                    "test-node20",
+                    "test-node22"
+                ]
+            );
+
+            let task = tasks.get("test-node18").unwrap();
+
+            assert_eq!(task.id, Id::raw("test-node18"));
+            assert_eq!(task.target, Target::parse("matrix:test-node18").unwrap());
+            assert_eq!(task.variant, Some(Id::raw("node18")));
+            assert_eq!(
+                task.options.resources,
+                BTreeMap::from_iter([(Id::raw("cpu"), 2)])
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn appends_variant_args_and_env() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+            let tasks = container.build_tasks("matrix").await;
+
+            let task = tasks.get("test-node18").unwrap();
+
+            assert_eq!(task.command, "jest");
+            assert_eq!(task.args, vec!["--ci", "--runInBand"]);
+            assert_eq!(task.env.get("NODE_ENV").unwrap().as_deref(), Some("test"));
+            assert_eq!(task.env.get("NODE_VERSION").unwrap().as_deref(), Some("18"));
+
+            let task = tasks.get("test-node20").unwrap();
+
+            assert_eq!(task.args, vec!["--ci", "--shard", "1/2"]);
+            assert_eq!(task.env.get("NODE_VERSION").unwrap().as_deref(), Some("20"));
+
+            let task = tasks.get("test-node22").unwrap();
+
+            assert_eq!(task.args, vec!["--ci"]);
+            assert!(task.env.get("NODE_VERSION").is_none());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn variants_inherit_deps_and_inputs() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+            let tasks = container.build_tasks("matrix").await;
+
+            let task = tasks.get("test-node20").unwrap();
+
+            assert_eq!(
+                task.deps,
+                vec![TaskDependencyConfig::new(Target::parse("dep").unwrap())]
+            );
+            assert!(task.inputs.contains(&Input::parse("src/**/*").unwrap()));
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn converts_parent_to_aggregate() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+            let tasks = container.build_tasks("matrix").await;
+
+            let task = tasks.get("test").unwrap();
+
+            assert_eq!(task.command, "noop");
+            assert!(task.args.is_empty());
+            assert!(task.inputs.is_empty());
+            assert!(task.state.empty_inputs);
+            assert!(task.options.resources.is_empty());
+            assert!(task.variant.is_none());
+            assert_eq!(
+                task.deps,
+                vec![
+                    TaskDependencyConfig::new(Target::parse("matrix:test-node18").unwrap()),
+                    TaskDependencyConfig::new(Target::parse("matrix:test-node20").unwrap()),
+                    TaskDependencyConfig::new(Target::parse("matrix:test-node22").unwrap()),
+                ]
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn ignores_args_for_scripts() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+            let tasks = container.build_tasks("matrix").await;
+
+            let task = tasks.get("script-a").unwrap();
+
+            assert_eq!(task.script.as_deref(), Some("jest --ci"));
+            assert!(task.args.is_empty());
+            assert_eq!(task.env.get("VARIANT").unwrap().as_deref(), Some("a"));
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[should_panic(expected = "Unable to expand matrix variant")]
+        async fn errors_for_conflicting_ids() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+
+            container.build_tasks("matrix-conflict").await;
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[should_panic(expected = "Unable to expand matrix variant")]
+        async fn errors_for_conflicting_variant_ids() {
+            let sandbox = create_sandbox("builder");
+            let container = TasksBuilderContainer::new(sandbox.path());
+
+            container.build_tasks("matrix-variant-conflict").await;
+        }
+    }
+
     mod defaults {
         use super::*;
 
@@ -23,6 +23,10 @@ pub struct TaskNode {
 pub struct TaskGraph {
     pub context: GraphExpanderContext,
 
+    /// Dependency edges that were disconnected while building,
+    /// because they would have formed a cycle.
+    pub cycle_edges: Vec<(Target, Target)>,
+
     /// Directed-acyclic graph (DAG) of non-expanded tasks and their relationships.
     pub graph: Dag<NodeIndex, TaskDependencyType>,
 
@@ -212,8 +216,48 @@ impl GraphConnections<Task, TaskDependencyType, Target> for TaskGraph {
     }
 }
 
+impl GraphAnalyzer<Task, TaskDependencyType, Target> for TaskGraph {
+    fn get_cycle_edges(&self) -> Vec<(Target, Target)> {
+        self.cycle_edges.clone()
+    }
+}
+
 impl GraphConversions<Task, TaskDependencyType, Target> for TaskGraph {}
 
+impl GraphNodeAttributes<Task> for TaskGraph {
+    fn get_node_attributes(&self, task: &Task) -> Vec<(&'static str, String)> {
+        let mut attributes = vec![("type", task.type_of.to_string())];
+
+        if !task.toolchains.is_empty() {
+            attributes.push((
+                "toolchains",
+                task.toolchains
+                    .iter()
+                    .map(|id| id.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ));
+        }
+
+        if !task.tags.is_empty() {
+            attributes.push((
+                "tags",
+                task.tags
+                    .iter()
+                    .map(|id| id.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ));
+        }
+
+        attributes
+    }
+}
+
 impl GraphToDot<Task, TaskDependencyType, Target> for TaskGraph {}
 
+impl GraphToGraphMl<Task, TaskDependencyType, Target> for TaskGraph {}
+
 impl GraphToJson<Task, TaskDependencyType, Target> for TaskGraph {}
+
+impl GraphToMermaid<Task, TaskDependencyType, Target> for TaskGraph {}
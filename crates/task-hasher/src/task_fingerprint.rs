@@ -47,6 +47,10 @@ fingerprint!(
         // Task `toolchains`
         pub toolchains: Vec<&'task Id>,
 
+        // Task matrix variant
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub variant: Option<&'task Id>,
+
         // Bump this to invalidate all caches
         pub version: String,
     }
@@ -71,6 +75,7 @@ impl<'task> TaskFingerprint<'task> {
             script: task.script.as_deref(),
             target: &task.target,
             toolchains: task.toolchains.iter().collect(),
+            variant: task.variant.as_ref(),
             // 1 - Original implementation
             // 2 - New task runner crate, tarball structure changed
             // 3 - New action pipeline
@@ -0,0 +1,356 @@
+use miette::IntoDiagnostic;
+use moon_action::OperationMetaFileAudit;
+use moon_app_context::AppContext;
+use moon_common::path::{WorkspaceRelativePathBuf, clean_components, encode_component};
+use moon_config::PipelineAuditTaskFiles;
+use moon_process::{Command, CommandExecutable, find_command_on_path};
+use moon_project::Project;
+use moon_task::Task;
+use rustc_hash::FxHashSet;
+use starbase_utils::{fs, glob::GlobSet};
+use std::collections::BTreeMap;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+use tracing::{debug, instrument};
+
+/// Name of the mutex that audited tasks acquire while running.
+pub const FILE_AUDIT_MUTEX: &str = "moon:file-audit";
+
+type FileSnapshot = BTreeMap<WorkspaceRelativePathBuf, String>;
+
+/// Audits the files that a task reads and writes while running, by comparing
+/// snapshots of the file tree before and after, and optionally tracing file
+/// opens with `strace` (Linux only). Files that were touched but are not
+/// declared as `inputs` or `outputs` are reported as undeclared.
+pub struct FileAuditor<'task> {
+    app_context: &'task Arc<AppContext>,
+    task: &'task Arc<Task>,
+
+    mode: PipelineAuditTaskFiles,
+    snapshot: FileSnapshot,
+    trace_file: Option<PathBuf>,
+    working_dir: PathBuf,
+}
+
+impl<'task> FileAuditor<'task> {
+    pub fn new(
+        app_context: &'task Arc<AppContext>,
+        project: &'task Arc<Project>,
+        task: &'task Arc<Task>,
+    ) -> Self {
+        Self {
+            mode: app_context
+                .workspace_config
+                .pipeline
+                .audit_task_files
+                .clone(),
+            snapshot: FileSnapshot::default(),
+            trace_file: None,
+            working_dir: if task.options.run_from_workspace_root {
+                app_context.workspace_root.clone()
+            } else {
+                project.root.clone()
+            },
+            app_context,
+            task,
+        }
+    }
+
+    /// Snapshot the file tree of the task's working directory (the project,
+    /// or workspace when running from the root), before the task has ran.
+    #[instrument(skip(self))]
+    pub async fn snapshot(&mut self) -> miette::Result<()> {
+        debug!(
+            task_target = self.task.target.as_str(),
+            "Snapshotting file tree before running task"
+        );
+
+        self.snapshot = self.create_snapshot().await?;
+
+        Ok(())
+    }
+
+    /// Wrap the command with `strace` so that all file opens are traced,
+    /// but only when tracing is enabled and `strace` is available.
+    pub fn trace_command(&mut self, command: &mut Command) {
+        if !self.mode.is_tracing() {
+            return;
+        }
+
+        if !cfg!(target_os = "linux") {
+            debug!(
+                task_target = self.task.target.as_str(),
+                "File tracing is only supported on Linux, falling back to snapshots"
+            );
+
+            return;
+        }
+
+        let Some(strace) = find_command_on_path("strace") else {
+            debug!(
+                task_target = self.task.target.as_str(),
+                "Unable to find strace on PATH, falling back to snapshots"
+            );
+
+            return;
+        };
+
+        // Scripts are ran in a shell, so there's no binary to wrap
+        if !matches!(command.exe, CommandExecutable::Binary(_)) {
+            debug!(
+                task_target = self.task.target.as_str(),
+                "Unable to trace file opens for scripts, falling back to snapshots"
+            );
+
+            return;
+        }
+
+        let trace_file = self.app_context.cache_engine.temp_dir.join(format!(
+            "{}.strace",
+            encode_component(self.task.target.as_str())
+        ));
+
+        debug!(
+            task_target = self.task.target.as_str(),
+            trace_file = ?trace_file,
+            "Tracing file opens with strace"
+        );
+
+        let bin = mem::replace(
+            &mut command.exe,
+            CommandExecutable::Binary(strace.as_path().into()),
+        );
+
+        if let CommandExecutable::Binary(bin) = bin {
+            command.args.push_front(bin);
+        }
+
+        for arg in [
+            "--".into(),
+            trace_file.as_path().into(),
+            "-o".into(),
+            "trace=open,openat,openat2,creat".into(),
+            "-e".into(),
+            "-qq".into(),
+            "-f".into(),
+        ] {
+            command.args.push_front(arg);
+        }
+
+        self.trace_file = Some(trace_file);
+    }
+
+    /// Snapshot the file tree again after the task has ran, and compare
+    /// the changes (and traced opens) against the task's inputs and outputs.
+    #[instrument(skip(self))]
+    pub async fn audit(self) -> miette::Result<OperationMetaFileAudit> {
+        debug!(
+            task_target = self.task.target.as_str(),
+            "Auditing files touched by task"
+        );
+
+        let after = self.create_snapshot().await?;
+        let mut read_files = FxHashSet::default();
+        let mut written_files = FxHashSet::default();
+
+        // Files that were created or modified
+        for (file, hash) in &after {
+            if self.snapshot.get(file).is_none_or(|prev| prev != hash) {
+                written_files.insert(file.clone());
+            }
+        }
+
+        // Files that were removed
+        for file in self.snapshot.keys() {
+            if !after.contains_key(file) {
+                written_files.insert(file.clone());
+            }
+        }
+
+        if let Some(trace_file) = &self.trace_file
+            && trace_file.exists()
+        {
+            self.parse_trace(
+                &fs::read_file(trace_file)?,
+                &mut read_files,
+                &mut written_files,
+            );
+
+            fs::remove_file(trace_file)?;
+        }
+
+        let output_globset = GlobSet::new_owned(self.task.output_globs.keys())?;
+        let input_globset = self.task.create_globset()?;
+
+        let is_output = |file: &WorkspaceRelativePathBuf| {
+            self.task
+                .output_files
+                .keys()
+                .any(|output| file == output || file.starts_with(output))
+                || output_globset.matches(file.as_str())
+        };
+
+        let is_input = |file: &WorkspaceRelativePathBuf| {
+            self.task.input_files.contains_key(file) || input_globset.matches(file.as_str())
+        };
+
+        let mut report = OperationMetaFileAudit::default();
+
+        for file in &written_files {
+            if !is_output(file) {
+                report.undeclared_outputs.push(PathBuf::from(file.as_str()));
+            }
+        }
+
+        for file in &read_files {
+            if !written_files.contains(file) && !is_input(file) && !is_output(file) {
+                report.undeclared_inputs.push(PathBuf::from(file.as_str()));
+            }
+        }
+
+        report.undeclared_inputs.sort();
+        report.undeclared_outputs.sort();
+
+        debug!(
+            task_target = self.task.target.as_str(),
+            undeclared_inputs = report.undeclared_inputs.len(),
+            undeclared_outputs = report.undeclared_outputs.len(),
+            "Audited files touched by task"
+        );
+
+        Ok(report)
+    }
+
+    // Walk the working directory directly, instead of using the VCS file
+    // tree, as tasks commonly write to ignored files (build artifacts, logs,
+    // etc) that must be audited as well. Files are fingerprinted by their
+    // size and modification time, as hashing every file would be too slow.
+    async fn create_snapshot(&self) -> miette::Result<FileSnapshot> {
+        let workspace_root = self.app_context.workspace_root.clone();
+        let cache_dir = self.app_context.cache_engine.cache_dir.clone();
+        let working_dir = self.working_dir.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut snapshot = FileSnapshot::default();
+            let mut queue = vec![working_dir];
+
+            while let Some(dir) = queue.pop() {
+                let Ok(entries) = std::fs::read_dir(&dir) else {
+                    continue;
+                };
+
+                for entry in entries.flatten() {
+                    let path = entry.path();
+
+                    // Ignore our own cache, which is written to by the runner
+                    if path.starts_with(&cache_dir) || entry.file_name() == ".git" {
+                        continue;
+                    }
+
+                    let Ok(metadata) = entry.metadata() else {
+                        continue;
+                    };
+
+                    if metadata.is_dir() {
+                        queue.push(path);
+                        continue;
+                    }
+
+                    let Ok(rel_path) = path.strip_prefix(&workspace_root) else {
+                        continue;
+                    };
+
+                    let Ok(file) = WorkspaceRelativePathBuf::from_path(rel_path) else {
+                        continue;
+                    };
+
+                    let modified = metadata
+                        .modified()
+                        .ok()
+                        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                        .map(|duration| duration.as_nanos())
+                        .unwrap_or_default();
+
+                    snapshot.insert(file, format!("{}:{modified}", metadata.len()));
+                }
+            }
+
+            snapshot
+        })
+        .await
+        .into_diagnostic()
+    }
+
+    /// Parse the output of `strace`, and categorize opened files within
+    /// the workspace as either read or written.
+    pub fn parse_trace(
+        &self,
+        trace: &str,
+        read_files: &mut FxHashSet<WorkspaceRelativePathBuf>,
+        written_files: &mut FxHashSet<WorkspaceRelativePathBuf>,
+    ) {
+        for line in trace.lines() {
+            // Opens that failed (file doesn't exist, etc)
+            if line.contains(" = -1 ") {
+                continue;
+            }
+
+            let Some(path) = extract_traced_path(line) else {
+                continue;
+            };
+
+            let Some(file) = self.to_workspace_file(&path) else {
+                continue;
+            };
+
+            if line.starts_with("creat(")
+                || line.contains(" creat(")
+                || line.contains("O_WRONLY")
+                || line.contains("O_RDWR")
+                || line.contains("O_CREAT")
+            {
+                written_files.insert(file);
+            } else {
+                read_files.insert(file);
+            }
+        }
+    }
+
+    fn to_workspace_file(&self, path: &Path) -> Option<WorkspaceRelativePathBuf> {
+        let abs_path = if path.is_absolute() {
+            clean_components(path)
+        } else {
+            clean_components(self.working_dir.join(path))
+        };
+
+        let workspace_root = &self.app_context.workspace_root;
+
+        if !abs_path.starts_with(workspace_root)
+            || abs_path.starts_with(&self.app_context.cache_engine.cache_dir)
+            || !abs_path.is_file()
+            || abs_path.components().any(|part| part.as_os_str() == ".git")
+        {
+            return None;
+        }
+
+        let rel_path = abs_path.strip_prefix(workspace_root).ok()?;
+
+        WorkspaceRelativePathBuf::from_path(rel_path).ok()
+    }
+}
+
+/// Extract the first quoted path from an `strace` line, for example:
+/// `1234  openat(AT_FDCWD, "src/index.ts", O_RDONLY|O_CLOEXEC) = 3`.
+pub fn extract_traced_path(line: &str) -> Option<PathBuf> {
+    let start = line.find('"')? + 1;
+    let end = start + line[start..].find('"')?;
+    let path = &line[start..end];
+
+    if path.is_empty() {
+        return None;
+    }
+
+    Some(PathBuf::from(path))
+}
@@ -2,8 +2,10 @@
 mod check_executor;
 mod checks_runner;
 pub mod command_builder;
+pub mod file_auditor;
 pub mod output_archiver;
 pub mod output_hydrater;
+pub mod readiness_prober;
 mod run_state;
 pub mod task_executor;
 mod task_runner;
@@ -0,0 +1,139 @@
+use moon_task::{Task, TaskReadinessConfig};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::time::sleep;
+use tracing::{debug, instrument};
+
+/// Polls the readiness probes of a persistent task on an interval,
+/// until all of them have passed, or the timeout has been reached.
+pub struct ReadinessProber<'task> {
+    config: &'task TaskReadinessConfig,
+    task: &'task Task,
+    working_dir: PathBuf,
+
+    client: Option<reqwest::Client>,
+    log_receiver: Option<UnboundedReceiver<String>>,
+
+    // States
+    file_ready: bool,
+    http_ready: bool,
+    log_ready: bool,
+    port_ready: bool,
+}
+
+impl<'task> ReadinessProber<'task> {
+    pub fn new(
+        task: &'task Task,
+        config: &'task TaskReadinessConfig,
+        working_dir: PathBuf,
+    ) -> Self {
+        Self {
+            client: config.http.as_ref().map(|_| {
+                reqwest::Client::builder()
+                    .timeout(Duration::from_millis(config.interval.max(1000)))
+                    .build()
+                    .unwrap_or_default()
+            }),
+            file_ready: config.file.is_none(),
+            http_ready: config.http.is_none(),
+            log_ready: config.log.is_none(),
+            log_receiver: None,
+            port_ready: config.port.is_none(),
+            config,
+            task,
+            working_dir,
+        }
+    }
+
+    /// Create a channel that the task's command will send
+    /// each line of output to, for matching the log probe.
+    pub fn create_log_listener(&mut self) -> Option<mpsc::UnboundedSender<String>> {
+        if self.log_ready {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        self.log_receiver = Some(receiver);
+
+        Some(sender)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn probe(mut self) -> bool {
+        let interval = Duration::from_millis(self.config.interval);
+        let timeout = Duration::from_secs(self.config.timeout);
+        let instant = Instant::now();
+
+        debug!(
+            task_target = self.task.target.as_str(),
+            timeout = self.config.timeout,
+            "Probing task for readiness",
+        );
+
+        loop {
+            if self.is_ready().await {
+                debug!(
+                    task_target = self.task.target.as_str(),
+                    "Task is ready, starting dependents",
+                );
+
+                return true;
+            }
+
+            if instant.elapsed() >= timeout {
+                debug!(
+                    task_target = self.task.target.as_str(),
+                    file = self.file_ready,
+                    http = self.http_ready,
+                    log = self.log_ready,
+                    port = self.port_ready,
+                    "Task did not become ready in time",
+                );
+
+                return false;
+            }
+
+            sleep(interval).await;
+        }
+    }
+
+    async fn is_ready(&mut self) -> bool {
+        if !self.log_ready
+            && let (Some(pattern), Some(receiver)) = (&self.config.log, &mut self.log_receiver)
+        {
+            while let Ok(line) = receiver.try_recv() {
+                if pattern.is_match(&line) {
+                    self.log_ready = true;
+                    break;
+                }
+            }
+        }
+
+        if !self.file_ready
+            && let Some(file) = &self.config.file
+        {
+            self.file_ready = self.working_dir.join(file).exists();
+        }
+
+        if !self.port_ready
+            && let Some(port) = self.config.port
+        {
+            self.port_ready = TcpStream::connect(("127.0.0.1", port)).await.is_ok();
+        }
+
+        if !self.http_ready
+            && let (Some(url), Some(client)) = (&self.config.http, &self.client)
+        {
+            self.http_ready = client
+                .get(url)
+                .send()
+                .await
+                .is_ok_and(|response| response.status().is_success());
+        }
+
+        self.file_ready && self.http_ready && self.log_ready && self.port_ready
+    }
+}
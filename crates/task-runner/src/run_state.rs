@@ -4,22 +4,135 @@ use moon_app_context::AppContext;
 use moon_cache_item::cache_item;
 use moon_hash::Digest;
 use moon_task::Task;
+use moon_time::now_millis;
+use serde::Serialize;
+use std::time::Duration;
+
+// Only keep enough samples to smooth out outliers
+const DURATION_HISTORY_LIMIT: usize = 10;
+
+// Only keep enough runs to calculate a recent flake rate
+pub const RUN_HISTORY_LIMIT: usize = 50;
+
+cache_item!(
+    pub struct TaskRunRecord {
+        pub attempts: usize,
+        pub flaky: bool,
+        pub hash: String,
+        pub passed: bool,
+        pub time: u128,
+    }
+);
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRunStats {
+    pub failures: usize,
+    pub flake_rate: f64,
+    pub flaky_runs: usize,
+    pub runs: usize,
+}
 
 cache_item!(
     pub struct TaskRunCacheState {
+        pub durations: Vec<u64>,
         pub exit_code: i32,
         pub hash: String,
+        pub history: Vec<TaskRunRecord>,
         pub last_run_time: u128,
         pub target: String,
     }
 );
 
+impl TaskRunCacheState {
+    /// Record the duration of a successful execution, retaining
+    /// only the most recent samples.
+    pub fn record_duration(&mut self, duration: Duration) {
+        self.durations.push(duration.as_millis() as u64);
+
+        if self.durations.len() > DURATION_HISTORY_LIMIT {
+            self.durations
+                .drain(0..self.durations.len() - DURATION_HISTORY_LIMIT);
+        }
+    }
+
+    /// Record the outcome of an execution, retaining only the most recent
+    /// runs. A run is flaky if it only passed after retrying, or if its
+    /// outcome differs from a previous run with the same hash. Returns
+    /// true if the outcome flipped.
+    pub fn record_run(&mut self, hash: &str, passed: bool, attempts: usize) -> bool {
+        let flipped = !hash.is_empty()
+            && self
+                .history
+                .iter()
+                .rfind(|record| record.hash == hash)
+                .is_some_and(|record| record.passed != passed);
+
+        self.history.push(TaskRunRecord {
+            attempts,
+            flaky: flipped || (passed && attempts > 1),
+            hash: hash.to_owned(),
+            passed,
+            time: now_millis(),
+        });
+
+        if self.history.len() > RUN_HISTORY_LIMIT {
+            self.history
+                .drain(0..self.history.len() - RUN_HISTORY_LIMIT);
+        }
+
+        flipped
+    }
+
+    /// Calculate pass/fail and flakiness statistics from the most recent runs.
+    pub fn get_run_stats(&self, limit: usize) -> TaskRunStats {
+        let runs = &self.history[self.history.len().saturating_sub(limit)..];
+        let mut stats = TaskRunStats {
+            runs: runs.len(),
+            ..Default::default()
+        };
+
+        for run in runs {
+            if !run.passed {
+                stats.failures += 1;
+            }
+
+            if run.flaky {
+                stats.flaky_runs += 1;
+            }
+        }
+
+        if stats.runs > 0 {
+            stats.flake_rate = stats.flaky_runs as f64 / stats.runs as f64;
+        }
+
+        stats
+    }
+
+    /// Estimate how long the next execution will take, based on the
+    /// median of the recorded durations.
+    pub fn get_estimated_duration(&self) -> Option<Duration> {
+        if self.durations.is_empty() {
+            return None;
+        }
+
+        let mut durations = self.durations.clone();
+        durations.sort_unstable();
+
+        Some(Duration::from_millis(durations[durations.len() / 2]))
+    }
+}
+
 #[derive(Default)]
 pub struct TaskRunState {
     /// The digest of our internal fingerprint. This is separate from the action
     /// digest as this implementation is not Bazel compatible.
     pub digest: Digest,
 
+    /// Whether the execution was flaky, either passing after a retry,
+    /// or the outcome changing for the same hash.
+    pub flaky: bool,
+
     /// The last operation that was executed.
     pub operation: Operation,
 
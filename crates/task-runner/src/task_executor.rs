@@ -1,3 +1,5 @@
+use crate::readiness_prober::ReadinessProber;
+use crate::task_runner_error::TaskRunnerError;
 use moon_action::{ActionNode, ActionStatus, Operation, OperationList};
 use moon_action_context::{ActionContext, TargetState};
 use moon_app_context::AppContext;
@@ -6,7 +8,9 @@ use moon_config::TaskOutputStyle;
 use moon_console::TaskReportItem;
 use moon_process::{Command, Output, format_command_line};
 use moon_project::Project;
-use moon_task::Task;
+use moon_task::{Target, Task};
+use std::future;
+use std::path::PathBuf;
 use std::time::Duration;
 use tokio::task::{self, JoinHandle};
 use tokio::time::{sleep, timeout};
@@ -91,6 +95,10 @@ impl<'task> TaskExecutor<'task> {
             self.command
                 .env("MOON_TASK_RETRY_TOTAL", self.attempt_total.to_string());
 
+            // Persistent tasks may probe for readiness while running,
+            // and each attempt requires its own prober
+            let prober = self.create_readiness_prober();
+
             debug!(
                 task_target = self.task.target.as_str(),
                 command = self.command.get_bin_name(),
@@ -118,6 +126,26 @@ impl<'task> TaskExecutor<'task> {
                 }
             }
 
+            // Wait for the probes to pass, and notify dependents, but keep
+            // running the command. Only resolves when the probes fail.
+            async fn probe_readiness(
+                prober: Option<ReadinessProber<'_>>,
+                context: &ActionContext,
+                target: &Target,
+            ) {
+                if let Some(prober) = prober {
+                    let ready = prober.probe().await;
+
+                    context.set_target_readiness(target, ready);
+
+                    if !ready {
+                        return;
+                    }
+                }
+
+                future::pending::<()>().await
+            }
+
             let timeout_token = CancellationToken::new();
             let timeout_handle =
                 self.monitor_timeout(self.task.options.timeout, timeout_token.clone());
@@ -137,6 +165,20 @@ impl<'task> TaskExecutor<'task> {
                     self.stream,
                     self.interactive,
                 ) => result.map(Some),
+
+                // Or fail if the task never became ready
+                _ = probe_readiness(prober, context, &self.task.target) => {
+                    Err(TaskRunnerError::ReadinessTimeout {
+                        target: self.task.target.clone(),
+                        timeout: self
+                            .task
+                            .readiness
+                            .as_ref()
+                            .map(|config| config.timeout)
+                            .unwrap_or_default(),
+                    }
+                    .into())
+                }
             };
 
             // Cleanup before sending the result
@@ -241,6 +283,9 @@ impl<'task> TaskExecutor<'task> {
 
         self.stop_monitoring();
 
+        // Dependents must not wait on a task that has exited
+        context.set_target_readiness(&self.task.target, false);
+
         Ok(TaskExecuteResult {
             attempts: self.attempts.take(),
             error: execution_error,
@@ -248,6 +293,29 @@ impl<'task> TaskExecutor<'task> {
         })
     }
 
+    fn create_readiness_prober(&mut self) -> Option<ReadinessProber<'task>> {
+        if !self.persistent {
+            return None;
+        }
+
+        let config = self.task.readiness.as_ref()?;
+        let mut prober = ReadinessProber::new(self.task, config, self.get_working_dir());
+
+        if let Some(listener) = prober.create_log_listener() {
+            self.command.set_output_listener(listener);
+        }
+
+        Some(prober)
+    }
+
+    fn get_working_dir(&self) -> PathBuf {
+        if self.task.options.run_from_workspace_root {
+            self.app.workspace_root.clone()
+        } else {
+            self.project.root.clone()
+        }
+    }
+
     fn monitor_running_status(&mut self) {
         if self.persistent || self.interactive {
             return;
@@ -309,6 +377,17 @@ impl<'task> TaskExecutor<'task> {
             false
         };
 
+        // Log probes match against the output as it's streamed
+        if self.persistent
+            && self
+                .task
+                .readiness
+                .as_ref()
+                .is_some_and(|config| config.log.is_some())
+        {
+            self.stream = true;
+        }
+
         // If only a single persistent task is being ran, we should not prefix the output.
         if is_only_primary && (self.task.is_persistent() || self.task.deps.is_empty()) {
             report_item.output_prefix = None;
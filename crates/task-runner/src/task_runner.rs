@@ -1,5 +1,6 @@
 use crate::checks_runner::ChecksRunner;
 use crate::command_builder::CommandBuilder;
+use crate::file_auditor::{FILE_AUDIT_MUTEX, FileAuditor};
 use crate::output_archiver::{ArchiveOutcome, OutputArchiver};
 use crate::output_hydrater::{HydrateFrom, HydrateOutcome, OutputHydrater};
 use crate::run_state::*;
@@ -9,6 +10,7 @@ use moon_action::{ActionNode, ActionStatus, Operation, OperationList, OperationM
 use moon_action_context::{ActionContext, TargetState};
 use moon_app_context::AppContext;
 use moon_cache::{CacheItem, StorageOptions};
+use moon_common::color;
 use moon_console::TaskReportItem;
 use moon_daemon_client::DaemonClient;
 use moon_hash::{ContentHash, ContentHasher};
@@ -19,12 +21,13 @@ use moon_task_hasher::*;
 use moon_time::{is_stale, now_millis};
 use starbase_utils::fs;
 use std::sync::Arc;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
 #[derive(Debug)]
 pub struct TaskRunResult {
     pub hash: Option<ContentHash>,
     pub error: Option<miette::Report>,
+    pub flaky: bool,
     pub operations: OperationList,
 }
 
@@ -158,6 +161,7 @@ impl<'task> TaskRunner<'task> {
 
                 Ok(TaskRunResult {
                     error: None,
+                    flaky: self.state.flaky,
                     hash: maybe_hash,
                     operations: self.operations.take(),
                 })
@@ -179,6 +183,7 @@ impl<'task> TaskRunner<'task> {
 
                 Ok(TaskRunResult {
                     error: Some(error),
+                    flaky: self.state.flaky,
                     hash: None,
                     operations: self.operations.take(),
                 })
@@ -494,6 +499,56 @@ impl<'task> TaskRunner<'task> {
         Ok(())
     }
 
+    fn create_file_auditor(&self) -> Option<FileAuditor<'task>> {
+        if !self
+            .app_context
+            .workspace_config
+            .pipeline
+            .audit_task_files
+            .is_enabled()
+            || self.task.is_persistent()
+            || self.task.is_interactive()
+        {
+            return None;
+        }
+
+        Some(FileAuditor::new(self.app_context, self.project, self.task))
+    }
+
+    #[instrument(skip_all)]
+    async fn audit_files(&mut self, auditor: FileAuditor<'task>) -> miette::Result<()> {
+        let mut operation = Operation::file_audit();
+        let audit = auditor.audit().await?;
+
+        for file in &audit.undeclared_inputs {
+            warn!(
+                task_target = self.task.target.as_str(),
+                "Task {} read file {} which is not declared as an input",
+                color::label(self.task.target.as_str()),
+                color::file(file.to_string_lossy()),
+            );
+        }
+
+        for file in &audit.undeclared_outputs {
+            warn!(
+                task_target = self.task.target.as_str(),
+                "Task {} wrote file {} which is not declared as an output",
+                color::label(self.task.target.as_str()),
+                color::file(file.to_string_lossy()),
+            );
+        }
+
+        if let OperationMeta::FileAudit(inner) = &mut operation.meta {
+            **inner = audit;
+        }
+
+        operation.finish(ActionStatus::Passed);
+
+        self.operations.push(operation);
+
+        Ok(())
+    }
+
     #[instrument(skip(self, context, node))]
     pub async fn execute(
         &mut self,
@@ -520,7 +575,7 @@ impl<'task> TaskRunner<'task> {
         );
 
         // Build the command from the current task
-        let command = CommandBuilder::new(self.app_context, self.project, self.task)
+        let mut command = CommandBuilder::new(self.app_context, self.project, self.task)
             .build(
                 context,
                 node,
@@ -528,6 +583,25 @@ impl<'task> TaskRunner<'task> {
             )
             .await?;
 
+        // Snapshot the file tree (and trace file opens) for auditing
+        let mut auditor = self.create_file_auditor();
+        let mut _audit_guard = None;
+
+        if let Some(auditor) = &mut auditor {
+            // Audited tasks are ran one at a time, otherwise files touched by
+            // tasks running in parallel would be attributed to this task
+            _audit_guard = Some(
+                context
+                    .get_or_create_mutex(FILE_AUDIT_MUTEX)
+                    .await
+                    .lock_owned()
+                    .await,
+            );
+
+            auditor.snapshot().await?;
+            auditor.trace_command(&mut command);
+        }
+
         // Execute the command and gather all attempts made
         let executor = TaskExecutor::new(self.app_context, self.project, self.task, node, command);
 
@@ -562,12 +636,48 @@ impl<'task> TaskRunner<'task> {
 
         // Persist the state locally and for the remote service
         if let Some(last_attempt) = result.attempts.get_last_execution() {
+            // Only successful runs are representative of how long
+            // the task takes, failures may exit early
+            if last_attempt.has_passed()
+                && let Some(duration) = last_attempt.duration
+            {
+                self.cache.data.record_duration(duration);
+            }
+
+            let passed = last_attempt.has_passed();
+            let attempts = result
+                .attempts
+                .iter()
+                .filter(|op| op.meta.is_task_execution())
+                .count();
+
+            if self.cache.data.record_run(
+                self.report.hash.as_deref().unwrap_or_default(),
+                passed,
+                attempts,
+            ) {
+                warn!(
+                    task_target = self.task.target.as_str(),
+                    "Task {} has {} with the same hash that previously {}, it may be flaky",
+                    color::label(&self.task.target),
+                    if passed { "passed" } else { "failed" },
+                    if passed { "failed" } else { "passed" },
+                );
+
+                self.state.flaky = true;
+            }
+
             self.persist_state(last_attempt)?;
         }
 
         // Extract the attempts from the result
         self.operations.merge(result.attempts);
 
+        // Audit the files that were touched while running
+        if let Some(auditor) = auditor {
+            self.audit_files(auditor).await?;
+        }
+
         // Update the action state based on the result
         self.state.target = Some(result.run_state);
 
@@ -44,6 +44,14 @@ pub enum TaskRunnerError {
         error: Box<ProcessError>,
     },
 
+    #[diagnostic(code(task_runner::dependency_not_ready))]
+    #[error(
+        "Unable to run task {}, as its persistent dependency {} did not become ready.",
+        .target.style(Style::Label),
+        .dep_target.style(Style::Label),
+    )]
+    DependencyNotReady { dep_target: Target, target: Target },
+
     #[diagnostic(code(task_runner::missing_dependency_hash))]
     #[error(
         "Encountered a missing hash for task {}, which is a dependency of {}.\nThis either means the dependency hasn't ran, has failed, or there's a misconfiguration.\n\nTry disabling the task's cache, or marking it as local.",
@@ -81,4 +89,15 @@ pub enum TaskRunnerError {
         .output.style(Style::Path),
     )]
     OutputFileNotDeclared { target: Target, output: PathBuf },
+
+    #[diagnostic(
+        code(task_runner::readiness_timeout),
+        help("Try increasing the timeout with the `readiness.timeout` setting.")
+    )]
+    #[error(
+        "Task {} did not become ready within {} seconds.",
+        .target.style(Style::Label),
+        .timeout,
+    )]
+    ReadinessTimeout { target: Target, timeout: u64 },
 }
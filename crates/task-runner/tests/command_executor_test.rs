@@ -109,4 +109,48 @@ mod command_executor {
             assert_eq!(output.exit_code.unwrap(), 1);
         }
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn becomes_ready_when_streamed_output_matches_log_probe() {
+        let container = TaskRunnerContainer::new_os("runner", "ready-log").await;
+        let context = ActionContext::default();
+        context.track_target_readiness(&container.task.target);
+
+        let mut item = TaskReportItem::default();
+        let executor = container.create_command_executor(&context).await;
+
+        let (result, ready) = tokio::join!(
+            executor.execute(&context, &mut item),
+            context.wait_for_target_readiness(&container.task.target),
+        );
+
+        let result = result.unwrap();
+
+        assert!(ready);
+        assert!(result.error.is_none());
+        assert_eq!(result.attempts.len(), 1);
+        assert_eq!(result.attempts[0].status, ActionStatus::Passed);
+    }
+    #[tokio::test(flavor = "multi_thread")]
+    async fn probes_readiness_on_each_attempt() {
+        let container = TaskRunnerContainer::new_os("runner", "ready-log-retry").await;
+        let context = ActionContext::default();
+        context.track_target_readiness(&container.task.target);
+
+        let mut item = TaskReportItem::default();
+        let executor = container.create_command_executor(&context).await;
+
+        let (result, ready) = tokio::join!(
+            executor.execute(&context, &mut item),
+            context.wait_for_target_readiness(&container.task.target),
+        );
+
+        let result = result.unwrap();
+
+        assert!(ready);
+        assert!(result.error.is_none());
+        assert_eq!(result.attempts.len(), 2);
+        assert_eq!(result.attempts[0].status, ActionStatus::Failed);
+        assert_eq!(result.attempts[1].status, ActionStatus::Passed);
+    }
 }
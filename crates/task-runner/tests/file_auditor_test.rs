@@ -0,0 +1,297 @@
+use moon_app_context::AppContext;
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_config::PipelineAuditTaskFiles;
+use moon_process::Command;
+use moon_project::Project;
+use moon_task::Task;
+use moon_task_runner::file_auditor::{FileAuditor, extract_traced_path};
+use moon_test_utils::WorkspaceMocker;
+use rustc_hash::FxHashSet;
+use starbase_sandbox::{Sandbox, create_sandbox};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+struct AuditContainer {
+    sandbox: Sandbox,
+    app_context: Arc<AppContext>,
+    project: Arc<Project>,
+    task: Arc<Task>,
+}
+
+impl AuditContainer {
+    async fn new(task_id: &str) -> Self {
+        let sandbox = create_sandbox("audit");
+        sandbox.enable_git();
+
+        let mocker = WorkspaceMocker::new(sandbox.path())
+            .load_default_configs()
+            .with_default_projects()
+            .update_workspace_config(|config| {
+                config.pipeline.audit_task_files = PipelineAuditTaskFiles::Snapshot;
+            });
+
+        let app_context = mocker.mock_app_context();
+        let workspace_graph = mocker.mock_workspace_graph().await;
+
+        Self {
+            project: workspace_graph.get_project("project").unwrap(),
+            task: workspace_graph
+                .get_task_from_project("project", task_id)
+                .unwrap(),
+            app_context: Arc::new(app_context),
+            sandbox,
+        }
+    }
+
+    fn create_auditor(&self) -> FileAuditor<'_> {
+        FileAuditor::new(&self.app_context, &self.project, &self.task)
+    }
+}
+
+mod file_auditor {
+    use super::*;
+
+    #[tokio::test]
+    async fn no_undeclared_files_when_nothing_changes() {
+        let container = AuditContainer::new("build").await;
+
+        let mut auditor = container.create_auditor();
+        auditor.snapshot().await.unwrap();
+
+        let audit = auditor.audit().await.unwrap();
+
+        assert!(audit.undeclared_inputs.is_empty());
+        assert!(audit.undeclared_outputs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn doesnt_report_declared_outputs() {
+        let container = AuditContainer::new("build").await;
+
+        let mut auditor = container.create_auditor();
+        auditor.snapshot().await.unwrap();
+
+        container.sandbox.create_file("project/dist/index.js", "");
+        container
+            .sandbox
+            .create_file("project/dist/nested/a.js", "");
+
+        let audit = auditor.audit().await.unwrap();
+
+        assert!(audit.undeclared_outputs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_created_files_outside_outputs() {
+        let container = AuditContainer::new("build").await;
+
+        let mut auditor = container.create_auditor();
+        auditor.snapshot().await.unwrap();
+
+        container.sandbox.create_file("project/dist/index.js", "");
+        container.sandbox.create_file("project/build.log", "");
+
+        let audit = auditor.audit().await.unwrap();
+
+        assert_eq!(
+            audit.undeclared_outputs,
+            vec![PathBuf::from("project/build.log")]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_files_ignored_by_vcs() {
+        let container = AuditContainer::new("build").await;
+
+        let mut auditor = container.create_auditor();
+        auditor.snapshot().await.unwrap();
+
+        // Matches `*.log` in the fixture's `.gitignore`
+        container.sandbox.create_file("project/debug.log", "");
+
+        let audit = auditor.audit().await.unwrap();
+
+        assert_eq!(
+            audit.undeclared_outputs,
+            vec![PathBuf::from("project/debug.log")]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_modified_and_removed_files() {
+        let container = AuditContainer::new("build").await;
+
+        let mut auditor = container.create_auditor();
+        auditor.snapshot().await.unwrap();
+
+        container
+            .sandbox
+            .create_file("project/src/index.js", "export default 1;");
+        std::fs::remove_file(container.sandbox.path().join("project/package.json")).unwrap();
+
+        let audit = auditor.audit().await.unwrap();
+
+        assert_eq!(
+            audit.undeclared_outputs,
+            vec![
+                PathBuf::from("project/package.json"),
+                PathBuf::from("project/src/index.js")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn matches_output_globs() {
+        let container = AuditContainer::new("build-globs").await;
+
+        let mut auditor = container.create_auditor();
+        auditor.snapshot().await.unwrap();
+
+        container.sandbox.create_file("project/dist/index.js", "");
+        container
+            .sandbox
+            .create_file("project/dist/index.js.map", "");
+
+        let audit = auditor.audit().await.unwrap();
+
+        assert_eq!(
+            audit.undeclared_outputs,
+            vec![PathBuf::from("project/dist/index.js.map")]
+        );
+    }
+
+    #[tokio::test]
+    async fn doesnt_trace_when_snapshotting() {
+        let container = AuditContainer::new("build").await;
+
+        let mut auditor = container.create_auditor();
+        let mut command = Command::new("node");
+        command.arg("index.js");
+
+        auditor.trace_command(&mut command);
+
+        assert_eq!(command.get_bin_name(), "node");
+        assert_eq!(command.args.len(), 1);
+    }
+}
+
+mod parse_trace {
+    use super::*;
+
+    fn parse(
+        container: &AuditContainer,
+        trace: &str,
+    ) -> (
+        FxHashSet<WorkspaceRelativePathBuf>,
+        FxHashSet<WorkspaceRelativePathBuf>,
+    ) {
+        let auditor = container.create_auditor();
+        let mut read_files = FxHashSet::default();
+        let mut written_files = FxHashSet::default();
+
+        auditor.parse_trace(trace, &mut read_files, &mut written_files);
+
+        (read_files, written_files)
+    }
+
+    #[test]
+    fn extracts_quoted_paths() {
+        assert_eq!(
+            extract_traced_path(
+                r#"1234  openat(AT_FDCWD, "src/index.js", O_RDONLY|O_CLOEXEC) = 3"#
+            ),
+            Some(PathBuf::from("src/index.js"))
+        );
+        assert_eq!(
+            extract_traced_path(r#"creat("/tmp/out.log", 0644) = 3"#),
+            Some(PathBuf::from("/tmp/out.log"))
+        );
+        assert_eq!(
+            extract_traced_path(r#"openat(AT_FDCWD, "", O_RDONLY) = -1 ENOENT"#),
+            None
+        );
+        assert_eq!(extract_traced_path("+++ exited with 0 +++"), None);
+    }
+
+    #[tokio::test]
+    async fn categorizes_read_only_opens_as_reads() {
+        let container = AuditContainer::new("build").await;
+
+        let (read_files, written_files) = parse(
+            &container,
+            r#"1234  openat(AT_FDCWD, "src/index.js", O_RDONLY|O_CLOEXEC) = 3"#,
+        );
+
+        assert_eq!(
+            read_files,
+            FxHashSet::from_iter([WorkspaceRelativePathBuf::from("project/src/index.js")])
+        );
+        assert!(written_files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn categorizes_write_opens_as_writes() {
+        let container = AuditContainer::new("build").await;
+        container.sandbox.create_file("project/dist/index.js", "");
+
+        let (read_files, written_files) = parse(
+            &container,
+            &format!(
+                r#"1234  openat(AT_FDCWD, "{}", O_WRONLY|O_CREAT|O_TRUNC|O_CLOEXEC, 0666) = 3"#,
+                container
+                    .sandbox
+                    .path()
+                    .join("project/dist/index.js")
+                    .display()
+            ),
+        );
+
+        assert!(read_files.is_empty());
+        assert_eq!(
+            written_files,
+            FxHashSet::from_iter([WorkspaceRelativePathBuf::from("project/dist/index.js")])
+        );
+    }
+
+    #[tokio::test]
+    async fn categorizes_creat_as_writes() {
+        let container = AuditContainer::new("build").await;
+        container.sandbox.create_file("project/build.log", "");
+
+        let (read_files, written_files) =
+            parse(&container, r#"1234  creat("build.log", 0644) = 3"#);
+
+        assert!(read_files.is_empty());
+        assert_eq!(
+            written_files,
+            FxHashSet::from_iter([WorkspaceRelativePathBuf::from("project/build.log")])
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_failed_opens() {
+        let container = AuditContainer::new("build").await;
+
+        let (read_files, written_files) = parse(
+            &container,
+            r#"1234  openat(AT_FDCWD, "package.json", O_RDONLY|O_CLOEXEC) = -1 EACCES (Permission denied)
+1234  openat(AT_FDCWD, "src/missing.js", O_RDONLY|O_CLOEXEC) = -1 ENOENT (No such file or directory)"#,
+        );
+
+        assert!(read_files.is_empty());
+        assert!(written_files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn ignores_files_outside_the_workspace() {
+        let container = AuditContainer::new("build").await;
+
+        let (read_files, written_files) = parse(
+            &container,
+            r#"1234  openat(AT_FDCWD, "/etc/hosts", O_RDONLY|O_CLOEXEC) = 3"#,
+        );
+
+        assert!(read_files.is_empty());
+        assert!(written_files.is_empty());
+    }
+}
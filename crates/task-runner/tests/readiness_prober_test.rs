@@ -0,0 +1,130 @@
+use moon_config::schematic::RegexSetting;
+use moon_task::{Task, TaskReadinessConfig};
+use moon_task_runner::readiness_prober::ReadinessProber;
+use starbase_sandbox::create_empty_sandbox;
+use std::net::TcpListener;
+
+fn create_config() -> TaskReadinessConfig {
+    TaskReadinessConfig {
+        file: None,
+        http: None,
+        interval: 10,
+        log: None,
+        port: None,
+        timeout: 1,
+    }
+}
+
+mod readiness_prober {
+    use super::*;
+
+    #[tokio::test]
+    async fn ready_when_no_probes() {
+        let sandbox = create_empty_sandbox();
+        let task = Task::default();
+        let config = create_config();
+
+        let prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+
+        assert!(prober.probe().await);
+    }
+
+    #[tokio::test]
+    async fn ready_when_file_exists() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(".ready", "");
+
+        let task = Task::default();
+        let config = TaskReadinessConfig {
+            file: Some(".ready".into()),
+            ..create_config()
+        };
+
+        let prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+
+        assert!(prober.probe().await);
+    }
+
+    #[tokio::test]
+    async fn not_ready_when_file_missing() {
+        let sandbox = create_empty_sandbox();
+        let task = Task::default();
+        let config = TaskReadinessConfig {
+            file: Some(".ready".into()),
+            ..create_config()
+        };
+
+        let prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+
+        assert!(!prober.probe().await);
+    }
+
+    #[tokio::test]
+    async fn ready_when_port_accepts_connections() {
+        let sandbox = create_empty_sandbox();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+        let task = Task::default();
+        let config = TaskReadinessConfig {
+            port: Some(listener.local_addr().unwrap().port()),
+            ..create_config()
+        };
+
+        let prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+
+        assert!(prober.probe().await);
+    }
+
+    #[tokio::test]
+    async fn ready_when_log_line_matches() {
+        let sandbox = create_empty_sandbox();
+        let task = Task::default();
+        let config = TaskReadinessConfig {
+            log: Some(RegexSetting::new("listening on \\d+").unwrap()),
+            ..create_config()
+        };
+
+        let mut prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+        let listener = prober.create_log_listener().unwrap();
+
+        listener.send("starting server".into()).unwrap();
+        listener.send("listening on 3000".into()).unwrap();
+
+        assert!(prober.probe().await);
+    }
+
+    #[tokio::test]
+    async fn not_ready_when_log_line_doesnt_match() {
+        let sandbox = create_empty_sandbox();
+        let task = Task::default();
+        let config = TaskReadinessConfig {
+            log: Some(RegexSetting::new("listening on \\d+").unwrap()),
+            ..create_config()
+        };
+
+        let mut prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+        let listener = prober.create_log_listener().unwrap();
+
+        listener.send("starting server".into()).unwrap();
+
+        assert!(!prober.probe().await);
+    }
+
+    #[tokio::test]
+    async fn requires_all_probes_to_pass() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(".ready", "");
+
+        let task = Task::default();
+        let config = TaskReadinessConfig {
+            file: Some(".ready".into()),
+            log: Some(RegexSetting::new("ready").unwrap()),
+            ..create_config()
+        };
+
+        let mut prober = ReadinessProber::new(&task, &config, sandbox.path().to_path_buf());
+        let _listener = prober.create_log_listener().unwrap();
+
+        assert!(!prober.probe().await);
+    }
+}
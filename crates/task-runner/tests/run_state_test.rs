@@ -0,0 +1,118 @@
+use moon_task_runner::{RUN_HISTORY_LIMIT, TaskRunCacheState};
+
+mod run_state {
+    use super::*;
+
+    mod record_run {
+        use super::*;
+
+        #[test]
+        fn records_outcomes() {
+            let mut state = TaskRunCacheState::default();
+
+            assert!(!state.record_run("a", true, 1));
+            assert!(!state.record_run("b", false, 1));
+
+            assert_eq!(state.history.len(), 2);
+            assert!(state.history[0].passed);
+            assert!(!state.history[1].passed);
+            assert!(!state.history[0].flaky);
+            assert!(!state.history[1].flaky);
+        }
+
+        #[test]
+        fn flags_flaky_when_outcome_flips_for_same_hash() {
+            let mut state = TaskRunCacheState::default();
+
+            assert!(!state.record_run("a", true, 1));
+            assert!(state.record_run("a", false, 1));
+            assert!(state.record_run("a", true, 1));
+
+            assert!(state.history[1].flaky);
+            assert!(state.history[2].flaky);
+        }
+
+        #[test]
+        fn doesnt_flag_when_hash_changes() {
+            let mut state = TaskRunCacheState::default();
+
+            assert!(!state.record_run("a", true, 1));
+            assert!(!state.record_run("b", false, 1));
+        }
+
+        #[test]
+        fn doesnt_flag_without_a_hash() {
+            let mut state = TaskRunCacheState::default();
+
+            assert!(!state.record_run("", true, 1));
+            assert!(!state.record_run("", false, 1));
+        }
+
+        #[test]
+        fn flags_flaky_when_passing_after_retry() {
+            let mut state = TaskRunCacheState::default();
+
+            assert!(!state.record_run("a", true, 3));
+            assert!(!state.record_run("b", false, 3));
+
+            assert!(state.history[0].flaky);
+            assert!(!state.history[1].flaky);
+        }
+
+        #[test]
+        fn retains_only_recent_runs() {
+            let mut state = TaskRunCacheState::default();
+
+            for i in 0..(RUN_HISTORY_LIMIT + 5) {
+                state.record_run(&i.to_string(), true, 1);
+            }
+
+            assert_eq!(state.history.len(), RUN_HISTORY_LIMIT);
+            assert_eq!(state.history[0].hash, "5");
+        }
+    }
+
+    mod get_run_stats {
+        use super::*;
+
+        #[test]
+        fn returns_empty_stats_without_history() {
+            let state = TaskRunCacheState::default();
+            let stats = state.get_run_stats(RUN_HISTORY_LIMIT);
+
+            assert_eq!(stats.runs, 0);
+            assert_eq!(stats.flake_rate, 0.0);
+        }
+
+        #[test]
+        fn calculates_flake_rate() {
+            let mut state = TaskRunCacheState::default();
+            state.record_run("a", true, 1);
+            state.record_run("a", false, 1);
+            state.record_run("b", true, 2);
+            state.record_run("c", false, 1);
+
+            let stats = state.get_run_stats(RUN_HISTORY_LIMIT);
+
+            assert_eq!(stats.runs, 4);
+            assert_eq!(stats.failures, 2);
+            assert_eq!(stats.flaky_runs, 2);
+            assert_eq!(stats.flake_rate, 0.5);
+        }
+
+        #[test]
+        fn only_includes_the_most_recent_runs() {
+            let mut state = TaskRunCacheState::default();
+            state.record_run("a", true, 1);
+            state.record_run("a", false, 1);
+            state.record_run("b", true, 1);
+            state.record_run("c", true, 1);
+
+            let stats = state.get_run_stats(2);
+
+            assert_eq!(stats.runs, 2);
+            assert_eq!(stats.failures, 0);
+            assert_eq!(stats.flake_rate, 0.0);
+        }
+    }
+}
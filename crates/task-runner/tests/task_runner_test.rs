@@ -957,6 +957,79 @@ mod task_runner {
             );
         }
 
+        #[tokio::test(flavor = "multi_thread")]
+        async fn executes_and_records_duration_on_success() {
+            let container = TaskRunnerContainer::new_os("runner", "success").await;
+            container.sandbox.enable_git();
+
+            let mut runner = container.create_runner();
+            let node = container.create_action_node();
+            let context = ActionContext::default();
+
+            setup_exec_state(&mut runner);
+
+            runner.execute(&context, &node).await.unwrap();
+
+            assert_eq!(runner.cache.data.durations.len(), 1);
+            assert!(runner.cache.data.get_estimated_duration().is_some());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn executes_and_doesnt_record_duration_on_failure() {
+            let container = TaskRunnerContainer::new_os("runner", "failure").await;
+            container.sandbox.enable_git();
+
+            let mut runner = container.create_runner();
+            let node = container.create_action_node();
+            let context = ActionContext::default();
+
+            setup_exec_state(&mut runner);
+
+            let _ = runner.execute(&context, &node).await;
+
+            assert!(runner.cache.data.durations.is_empty());
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn executes_and_records_run_history() {
+            let container = TaskRunnerContainer::new_os("runner", "failure").await;
+            container.sandbox.enable_git();
+
+            let mut runner = container.create_runner();
+            let node = container.create_action_node();
+            let context = ActionContext::default();
+
+            setup_exec_state(&mut runner);
+
+            let _ = runner.execute(&context, &node).await;
+
+            let record = runner.cache.data.history.last().unwrap();
+
+            assert_eq!(runner.cache.data.history.len(), 1);
+            assert_eq!(record.hash, "hash123");
+            assert!(!record.passed);
+            assert!(!record.flaky);
+            assert!(!runner.state.flaky);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn executes_and_flags_flaky_when_outcome_flips_for_same_hash() {
+            let container = TaskRunnerContainer::new_os("runner", "success").await;
+            container.sandbox.enable_git();
+
+            let mut runner = container.create_runner();
+            let node = container.create_action_node();
+            let context = ActionContext::default();
+
+            setup_exec_state(&mut runner);
+
+            runner.cache.data.record_run("hash123", false, 1);
+            runner.execute(&context, &node).await.unwrap();
+
+            assert!(runner.cache.data.history.last().unwrap().flaky);
+            assert!(runner.state.flaky);
+        }
+
         #[tokio::test(flavor = "multi_thread")]
         async fn executes_and_sets_failed_state() {
             let container = TaskRunnerContainer::new_os("runner", "failure").await;
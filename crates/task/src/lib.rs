@@ -7,7 +7,8 @@ pub use moon_config::{
     TaskCheckFingerprintConfig, TaskCheckRequirementConfig, TaskCheckType, TaskConfig,
     TaskOperatingSystem, TaskOptionAffectedFilesConfig, TaskOptionAffectedFilesEntry,
     TaskOptionAffectedFilesPattern, TaskOptionCache, TaskOptionEnvFile, TaskOptionRunInCI,
-    TaskOptionsConfig, TaskOutputStyle, TaskPriority, TaskType, TaskUnixShell, TaskWindowsShell,
+    TaskOptionsConfig, TaskOutputStyle, TaskPriority, TaskReadinessConfig, TaskType, TaskUnixShell,
+    TaskWindowsShell,
 };
 pub use moon_target::*;
 pub use task::*;
@@ -3,7 +3,7 @@ use crate::task_options::TaskOptions;
 use moon_common::{Id, cacheable, path::WorkspaceRelativePathBuf};
 use moon_config::{
     EnvMap, Input, Output, TaskCheck, TaskDependencyConfig, TaskOptionRunInCI, TaskPreset,
-    TaskType, is_false, schematic::RegexSetting,
+    TaskReadinessConfig, TaskType, is_false, schematic::RegexSetting,
 };
 use moon_target::Target;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -131,6 +131,9 @@ cacheable!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub preset: Option<TaskPreset>,
 
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub readiness: Option<TaskReadinessConfig>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub script: Option<String>,
 
@@ -146,6 +149,9 @@ cacheable!(
 
         #[serde(rename = "type")]
         pub type_of: TaskType,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub variant: Option<Id>,
     }
 );
 
@@ -380,12 +386,14 @@ impl Default for Task {
             output_files: FxHashMap::default(),
             output_globs: FxHashMap::default(),
             preset: None,
+            readiness: None,
             script: None,
             state: TaskState::default(),
             tags: vec![],
             target: Target::default(),
             toolchains: vec![Id::raw("system")],
             type_of: TaskType::default(),
+            variant: None,
         }
     }
 }
@@ -1,8 +1,9 @@
-use moon_common::cacheable;
+use moon_common::{Id, cacheable};
 use moon_config::{
     Input, MergeStrategy, TaskOperatingSystem, TaskOptionAffectedFilesPattern, TaskOptionCache,
     TaskOptionRunInCI, TaskOutputStyle, TaskPriority, TaskUnixShell, TaskWindowsShell,
 };
+use std::collections::BTreeMap;
 
 cacheable!(
     #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -71,6 +72,9 @@ cacheable!(
 
         pub priority: TaskPriority,
 
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        pub resources: BTreeMap<Id, u32>,
+
         pub retry_count: u8,
 
         pub run_deps_in_parallel: bool,
@@ -119,6 +123,7 @@ impl Default for TaskOptions {
             output_style: None,
             persistent: false,
             priority: TaskPriority::Normal,
+            resources: BTreeMap::default(),
             retry_count: 0,
             run_deps_in_parallel: true,
             run_in_sync_phase: false,
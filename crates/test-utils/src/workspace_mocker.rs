@@ -16,6 +16,7 @@ use moon_task_builder::*;
 use moon_task_graph::Task;
 use moon_toolchain_plugin::ToolchainRegistry;
 use moon_vcs::{BoxedVcs, git::Git};
+use moon_vcs_plugin::VcsRegistry;
 use moon_workspace::*;
 pub use moon_workspace_graph::WorkspaceGraph;
 use proto_core::{ProtoConfig, ProtoEnvironment};
@@ -187,6 +188,15 @@ impl WorkspaceMocker {
         })
     }
 
+    pub fn with_test_vcs(self) -> Self {
+        self.update_workspace_config(|config| {
+            config.vcs.plugin = Some(
+                proto_core::warpgate::find_debug_locator("vcs_canned")
+                    .expect("Development plugins missing, build with `just build-wasm`!"),
+            );
+        })
+    }
+
     pub fn with_global_envs(mut self) -> Self {
         let home_dir = std::env::home_dir().unwrap();
 
@@ -412,6 +422,21 @@ impl WorkspaceMocker {
         .unwrap()
     }
 
+    pub fn mock_vcs_registry(&self) -> VcsRegistry {
+        VcsRegistry::new(
+            MoonHostData {
+                moon_env: Arc::new(self.moon_env.clone()),
+                proto_env: Arc::new(self.proto_env.clone()),
+                extensions_config: Arc::new(self.extensions_config.clone()),
+                toolchains_config: Arc::new(self.toolchains_config.clone()),
+                workspace_config: Arc::new(self.workspace_config.clone()),
+                workspace_graph: Arc::new(OnceLock::new()),
+            },
+            Arc::new(self.workspace_config.vcs.clone()),
+        )
+        .unwrap()
+    }
+
     pub fn mock_vcs_adapter(&self) -> BoxedVcs {
         Box::new(
             Git::load(
@@ -0,0 +1,9 @@
+mod plugin_vcs;
+mod vcs_plugin;
+mod vcs_plugin_error;
+mod vcs_registry;
+
+pub use plugin_vcs::*;
+pub use vcs_plugin::*;
+pub use vcs_plugin_error::*;
+pub use vcs_registry::*;
@@ -0,0 +1,458 @@
+use crate::vcs_plugin::VcsPlugin;
+use crate::vcs_plugin_error::VcsPluginError;
+use crate::vcs_registry::VcsRegistry;
+use async_trait::async_trait;
+use ignore::WalkBuilder;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use miette::IntoDiagnostic;
+use moon_common::path::{
+    PathExt, WorkspaceRelativePath, WorkspaceRelativePathBuf, locate_config_dir,
+};
+use moon_config::VcsConfig;
+use moon_hash::ContentHash;
+use moon_pdk_api::{
+    GetVcsImpactsInput, GetVcsImpactsOutput, InitializeVcsInput, InitializeVcsOutput, MoonContext,
+    SetupVcsHookEnvironmentInput, TeardownVcsHookEnvironmentInput, VcsChangeMask,
+    VcsHistoryCompleteness, VcsImpactCompleteness, VcsImpactIntent, VcsInitialization,
+};
+use moon_vcs::{ChangedFiles, ChangedStatus, Vcs, VcsHookEnvironment};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, warn};
+use version_spec::Version;
+
+/// A VCS adapter that delegates to a WASM plugin, for providers
+/// that are not built into moon, like Jujutsu or Sapling.
+pub struct PluginVcs {
+    context: MoonContext,
+    default_branch: String,
+    hooks: Vec<String>,
+    ignore: Gitignore,
+    initialization: VcsInitialization,
+    plugin: Arc<VcsPlugin>,
+    repository_root: PathBuf,
+    working_root: PathBuf,
+    workspace_root: PathBuf,
+}
+
+impl PluginVcs {
+    pub async fn load(
+        registry: &VcsRegistry,
+        workspace_root: &Path,
+        config: &VcsConfig,
+    ) -> miette::Result<Self> {
+        let id = registry.get_plugin_id();
+        let plugin = registry.load(id).await?;
+        let context = registry.create_context();
+
+        debug!(
+            plugin_id = id.as_str(),
+            "Initializing VCS plugin for the current workspace"
+        );
+
+        let initialization = match plugin
+            .initialize_vcs(InitializeVcsInput {
+                baseline: Some(config.default_branch.clone()),
+                remote_candidates: config.remote_candidates.clone(),
+                context: context.clone(),
+            })
+            .await?
+        {
+            InitializeVcsOutput::NotDetected { reason } => {
+                return Err(VcsPluginError::NotDetected {
+                    id: id.to_string(),
+                    reason,
+                }
+                .into());
+            }
+            InitializeVcsOutput::Initialized { initialization } => *initialization,
+        };
+
+        let repository_root = plugin
+            .to_real_path(&initialization.roots.repository_root)
+            .to_path_buf();
+        let working_root = plugin
+            .to_real_path(&initialization.roots.working_root)
+            .to_path_buf();
+
+        debug!(
+            plugin_id = id.as_str(),
+            client = initialization.client.as_str(),
+            repository_root = ?repository_root,
+            working_root = ?working_root,
+            "Initialized VCS plugin",
+        );
+
+        let mut hooks = config.hooks.keys().cloned().collect::<Vec<_>>();
+        hooks.sort();
+
+        Ok(Self {
+            context,
+            default_branch: config.default_branch.clone(),
+            hooks,
+            ignore: load_ignore(&working_root),
+            initialization,
+            plugin,
+            repository_root,
+            working_root,
+            workspace_root: workspace_root.to_path_buf(),
+        })
+    }
+
+    pub fn get_initialization(&self) -> &VcsInitialization {
+        &self.initialization
+    }
+
+    async fn get_impacts(&self, intent: VcsImpactIntent) -> miette::Result<ChangedFiles> {
+        let output = self
+            .plugin
+            .get_vcs_impacts(GetVcsImpactsInput {
+                context: self.context.clone(),
+                intent,
+            })
+            .await?;
+
+        convert_impacts(self.plugin.id.as_str(), output)
+    }
+
+    fn get_hooks_dir(&self) -> PathBuf {
+        locate_config_dir(&self.workspace_root).join("hooks")
+    }
+
+    fn missing_state(&self, field: &str) -> VcsPluginError {
+        VcsPluginError::MissingState {
+            id: self.plugin.id.to_string(),
+            field: field.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Vcs for PluginVcs {
+    async fn get_local_branch(&self) -> miette::Result<String> {
+        self.initialization
+            .current
+            .label
+            .clone()
+            .or_else(|| self.initialization.recorded.label.clone())
+            .ok_or_else(|| self.missing_state("local branch").into())
+    }
+
+    async fn get_local_branch_revision(&self) -> miette::Result<String> {
+        self.initialization
+            .recorded
+            .id
+            .clone()
+            .ok_or_else(|| self.missing_state("local revision").into())
+    }
+
+    async fn get_default_branch(&self) -> miette::Result<String> {
+        Ok(self.default_branch.clone())
+    }
+
+    async fn get_default_branch_revision(&self) -> miette::Result<String> {
+        self.initialization
+            .baseline
+            .as_ref()
+            .and_then(|state| state.id.clone())
+            .ok_or_else(|| self.missing_state("default branch revision").into())
+    }
+
+    async fn get_file_hashes(
+        &self,
+        files: &[WorkspaceRelativePathBuf],
+        allow_ignored: bool,
+    ) -> miette::Result<BTreeMap<WorkspaceRelativePathBuf, String>> {
+        let mut map = BTreeMap::new();
+
+        // Providers are not required to expose object IDs, so hash
+        // the file contents on the host instead
+        for file in files {
+            let abs_file = file.to_logical_path(&self.workspace_root);
+
+            if abs_file.is_file() && (allow_ignored || !self.is_ignored(&abs_file)) {
+                map.insert(
+                    file.to_owned(),
+                    ContentHash::hash_file(&abs_file)?.as_hex().to_owned(),
+                );
+            }
+        }
+
+        Ok(map)
+    }
+
+    async fn get_file_tree(
+        &self,
+        dir: &WorkspaceRelativePath,
+    ) -> miette::Result<Vec<WorkspaceRelativePathBuf>> {
+        let abs_dir = dir.to_logical_path(&self.workspace_root);
+        let repository_root = self.repository_root.clone();
+        let mut paths = vec![];
+
+        let walker = WalkBuilder::new(&abs_dir)
+            .hidden(false)
+            .require_git(false)
+            .filter_entry(move |entry| entry.path() != repository_root)
+            .build();
+
+        for entry in walker {
+            let entry = entry.into_diagnostic()?;
+
+            if entry.file_type().is_some_and(|ty| ty.is_file()) {
+                paths.push(
+                    entry
+                        .path()
+                        .relative_to(&self.workspace_root)
+                        .into_diagnostic()?,
+                );
+            }
+        }
+
+        Ok(paths)
+    }
+
+    fn get_repository_root(&self) -> miette::Result<PathBuf> {
+        Ok(self.repository_root.clone())
+    }
+
+    async fn get_repository_slug(&self) -> miette::Result<String> {
+        self.initialization
+            .repository_slug
+            .clone()
+            .ok_or_else(|| self.missing_state("repository slug").into())
+    }
+
+    async fn get_changed_files(&self) -> miette::Result<ChangedFiles> {
+        self.get_impacts(VcsImpactIntent::Working).await
+    }
+
+    async fn get_changed_files_against_previous_revision(
+        &self,
+        revision: &str,
+    ) -> miette::Result<ChangedFiles> {
+        self.get_impacts(VcsImpactIntent::Submission {
+            base: None,
+            head: Some(revision.to_owned()),
+            include_working: false,
+        })
+        .await
+    }
+
+    async fn get_changed_files_between_revisions(
+        &self,
+        base_revision: &str,
+        revision: &str,
+    ) -> miette::Result<ChangedFiles> {
+        self.get_impacts(VcsImpactIntent::Submission {
+            base: Some(base_revision.to_owned()),
+            head: if revision.is_empty() {
+                None
+            } else {
+                Some(revision.to_owned())
+            },
+            include_working: revision.is_empty(),
+        })
+        .await
+    }
+
+    async fn get_version(&self) -> miette::Result<Version> {
+        let version = self
+            .initialization
+            .client_version
+            .as_deref()
+            .ok_or_else(|| self.missing_state("client version"))?;
+
+        Ok(
+            Version::parse(version).map_err(|error| VcsPluginError::InvalidVersion {
+                id: self.plugin.id.to_string(),
+                version: version.to_owned(),
+                error: Box::new(error),
+            })?,
+        )
+    }
+
+    fn get_working_root(&self) -> miette::Result<PathBuf> {
+        Ok(self.working_root.clone())
+    }
+
+    fn is_default_branch(&self, branch: &str) -> bool {
+        let default_branch = &self.default_branch;
+
+        if default_branch.as_str() == branch {
+            return true;
+        }
+
+        if default_branch.contains('/') {
+            return default_branch.ends_with(&format!("/{branch}"));
+        }
+
+        false
+    }
+
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    fn is_ignored(&self, file: &Path) -> bool {
+        file.strip_prefix(&self.working_root).is_ok_and(|rel| {
+            self.ignore
+                .matched_path_or_any_parents(rel, file.is_dir())
+                .is_ignore()
+        })
+    }
+
+    async fn is_shallow_checkout(&self) -> miette::Result<bool> {
+        Ok(self.initialization.history == VcsHistoryCompleteness::Incomplete)
+    }
+
+    async fn setup_hooks(&self) -> miette::Result<Option<VcsHookEnvironment>> {
+        let hooks_dir = self.get_hooks_dir();
+
+        let Some(output) = self
+            .plugin
+            .setup_vcs_hook_environment(SetupVcsHookEnvironmentInput {
+                context: self.context.clone(),
+                hooks_dir: self.plugin.to_virtual_path(&hooks_dir),
+                hooks: self.hooks.clone(),
+            })
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(VcsHookEnvironment {
+            hooks_dir,
+            working_dir: output
+                .working_dir
+                .map(|dir| dir.to_path_buf())
+                .unwrap_or_else(|| self.working_root.clone()),
+        }))
+    }
+
+    async fn teardown_hooks(&self) -> miette::Result<()> {
+        self.plugin
+            .teardown_vcs_hook_environment(TeardownVcsHookEnvironmentInput {
+                context: self.context.clone(),
+                hooks_dir: self.plugin.to_virtual_path(self.get_hooks_dir()),
+                hooks: self.hooks.clone(),
+            })
+            .await
+    }
+}
+
+impl fmt::Debug for PluginVcs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginVcs")
+            .field("plugin", &self.plugin)
+            .field("initialization", &self.initialization)
+            .field("default_branch", &self.default_branch)
+            .field("repository_root", &self.repository_root)
+            .field("working_root", &self.working_root)
+            .field("workspace_root", &self.workspace_root)
+            .finish()
+    }
+}
+
+fn load_ignore(working_root: &Path) -> Gitignore {
+    let mut builder = GitignoreBuilder::new(working_root);
+
+    for name in [".gitignore", ".ignore"] {
+        let file = working_root.join(name);
+
+        if file.exists()
+            && let Some(error) = builder.add(&file)
+        {
+            warn!(file = ?file, "Failed to parse ignore file: {error}");
+        }
+    }
+
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}
+
+fn is_valid_change_path(path: &Path) -> bool {
+    let Some(value) = path.to_str() else {
+        return false;
+    };
+
+    !value.is_empty()
+        && !value.contains('\\')
+        && !value.contains('\0')
+        && !value.split('/').any(|part| part.is_empty())
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Convert a change mask into the statuses used by moon, validating
+/// that it contains a change-kind bit, a location bit, and no reserved bits.
+pub fn convert_change_mask(mask: VcsChangeMask) -> Option<Vec<ChangedStatus>> {
+    if !VcsChangeMask::KNOWN_BITS.contains(mask)
+        || !mask.intersects(VcsChangeMask::CHANGE_BITS)
+        || !mask.intersects(VcsChangeMask::LOCATION_BITS)
+    {
+        return None;
+    }
+
+    let mut statuses = vec![];
+
+    for (flag, status) in [
+        (VcsChangeMask::ADDED, ChangedStatus::Added),
+        (VcsChangeMask::DELETED, ChangedStatus::Deleted),
+        (VcsChangeMask::MODIFIED, ChangedStatus::Modified),
+        (VcsChangeMask::STAGED, ChangedStatus::Staged),
+        (VcsChangeMask::WORKING, ChangedStatus::Unstaged),
+        (VcsChangeMask::UNTRACKED, ChangedStatus::Untracked),
+    ] {
+        if mask.contains(flag) {
+            statuses.push(status);
+        }
+    }
+
+    Some(statuses)
+}
+
+/// Convert an impacts result into changed files, failing when the
+/// provider could not produce a safe answer or returned invalid data.
+pub fn convert_impacts(id: &str, output: GetVcsImpactsOutput) -> miette::Result<ChangedFiles> {
+    if output.completeness == VcsImpactCompleteness::Unavailable {
+        return Err(VcsPluginError::ImpactsUnavailable {
+            id: id.to_owned(),
+            diagnostics: output.diagnostics,
+        }
+        .into());
+    }
+
+    for diagnostic in &output.diagnostics {
+        warn!(plugin_id = id, "{diagnostic}");
+    }
+
+    let mut changed_files = ChangedFiles::default();
+
+    for (path, mask) in output.changes {
+        if !is_valid_change_path(&path) {
+            return Err(VcsPluginError::InvalidChangePath {
+                id: id.to_owned(),
+                path: path.to_string_lossy().to_string(),
+            }
+            .into());
+        }
+
+        let Some(statuses) = convert_change_mask(mask) else {
+            return Err(VcsPluginError::InvalidChangeMask {
+                id: id.to_owned(),
+                path: path.to_string_lossy().to_string(),
+                mask: mask.bits(),
+            }
+            .into());
+        };
+
+        changed_files.files.insert(
+            WorkspaceRelativePathBuf::from(path.to_string_lossy().as_ref()),
+            statuses,
+        );
+    }
+
+    Ok(changed_files)
+}
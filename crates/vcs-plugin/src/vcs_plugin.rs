@@ -0,0 +1,147 @@
+use crate::vcs_plugin_error::VcsPluginError;
+use async_trait::async_trait;
+use moon_common::Id;
+use moon_pdk_api::*;
+use moon_plugin::{Plugin, PluginContainer, PluginRegistration, PluginType};
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use tracing::instrument;
+
+pub type VcsMetadata = RegisterVcsOutput;
+
+pub struct VcsPlugin {
+    pub id: Id,
+    pub locator: PluginLocator,
+    pub metadata: VcsMetadata,
+
+    plugin: Arc<PluginContainer>,
+
+    // Providers pin their state during initialization, and a new
+    // initialization requires a new instance, so only ever call once
+    initialization: OnceCell<InitializeVcsOutput>,
+}
+
+#[async_trait]
+impl Plugin for VcsPlugin {
+    async fn new(registration: PluginRegistration) -> miette::Result<Self> {
+        let plugin = Arc::new(registration.container);
+
+        let metadata: RegisterVcsOutput = plugin
+            .cache_func_with(
+                "register_vcs",
+                RegisterVcsInput {
+                    id: registration.id.clone(),
+                    host_protocol_version: VCS_PLUGIN_PROTOCOL_VERSION,
+                },
+            )
+            .await?;
+
+        if metadata.protocol_version != VCS_PLUGIN_PROTOCOL_VERSION {
+            return Err(VcsPluginError::ProtocolMismatch {
+                id: registration.id.to_string(),
+                expected: VCS_PLUGIN_PROTOCOL_VERSION,
+                actual: metadata.protocol_version,
+            }
+            .into());
+        }
+
+        Ok(Self {
+            id: registration.id,
+            locator: registration.locator,
+            metadata,
+            plugin,
+            initialization: OnceCell::new(),
+        })
+    }
+
+    fn get_id(&self) -> &Id {
+        &self.id
+    }
+
+    fn get_type(&self) -> PluginType {
+        PluginType::Vcs
+    }
+
+    async fn has_func(&self, name: &str) -> bool {
+        self.plugin.has_func(name).await
+    }
+}
+
+impl VcsPlugin {
+    #[instrument(skip(self))]
+    pub async fn initialize_vcs(
+        &self,
+        input: InitializeVcsInput,
+    ) -> miette::Result<InitializeVcsOutput> {
+        let output = self
+            .initialization
+            .get_or_try_init(async || self.call_func_with("initialize_vcs", input).await)
+            .await?;
+
+        Ok(output.clone())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_vcs_impacts(
+        &self,
+        input: GetVcsImpactsInput,
+    ) -> miette::Result<GetVcsImpactsOutput> {
+        // Not cached since the intent may reference movable expressions,
+        // and the provider is responsible for resolving them consistently
+        Ok(self.call_func_with("get_vcs_impacts", input).await?)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn setup_vcs_hook_environment(
+        &self,
+        input: SetupVcsHookEnvironmentInput,
+    ) -> miette::Result<Option<SetupVcsHookEnvironmentOutput>> {
+        if !self.has_func("setup_vcs_hook_environment").await {
+            return Ok(None);
+        }
+
+        let mut output: SetupVcsHookEnvironmentOutput = self
+            .call_func_with("setup_vcs_hook_environment", input)
+            .await?;
+
+        if let Some(dir) = &mut output.working_dir {
+            *dir = VirtualPath::new(self.plugin.to_real_path(&dir));
+        }
+
+        Ok(Some(output))
+    }
+
+    #[instrument(skip(self))]
+    pub async fn teardown_vcs_hook_environment(
+        &self,
+        input: TeardownVcsHookEnvironmentInput,
+    ) -> miette::Result<()> {
+        if self.has_func("teardown_vcs_hook_environment").await {
+            let _: TeardownVcsHookEnvironmentOutput = self
+                .call_func_with("teardown_vcs_hook_environment", input)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for VcsPlugin {
+    type Target = PluginContainer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.plugin
+    }
+}
+
+impl fmt::Debug for VcsPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VcsPlugin")
+            .field("id", &self.id)
+            .field("locator", &self.locator)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
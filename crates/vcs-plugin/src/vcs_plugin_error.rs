@@ -0,0 +1,81 @@
+use miette::Diagnostic;
+use moon_common::{Style, Stylize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Diagnostic)]
+pub enum VcsPluginError {
+    #[diagnostic(code(vcs_plugin::impacts_unavailable))]
+    #[error(
+        "The {} VCS plugin was unable to determine changed files.{}",
+        .id.style(Style::Id),
+        .diagnostics.iter().map(|diag| format!("\n  {diag}")).collect::<String>(),
+    )]
+    ImpactsUnavailable {
+        id: String,
+        diagnostics: Vec<String>,
+    },
+
+    #[diagnostic(code(vcs_plugin::invalid_change_mask))]
+    #[error(
+        "The {} VCS plugin returned an invalid change mask {} for {}.",
+        .id.style(Style::Id),
+        .mask.style(Style::Symbol),
+        .path.style(Style::File),
+    )]
+    InvalidChangeMask { id: String, path: String, mask: u8 },
+
+    #[diagnostic(
+        code(vcs_plugin::invalid_change_path),
+        help = "Paths must be relative from the workspace root, and use forward slashes."
+    )]
+    #[error(
+        "The {} VCS plugin returned an invalid changed file path {}.",
+        .id.style(Style::Id),
+        .path.style(Style::File),
+    )]
+    InvalidChangePath { id: String, path: String },
+
+    #[diagnostic(code(vcs_plugin::invalid_version))]
+    #[error(
+        "The {} VCS plugin returned an invalid client version {}.",
+        .id.style(Style::Id),
+        .version.style(Style::Symbol),
+    )]
+    InvalidVersion {
+        id: String,
+        version: String,
+        #[source]
+        error: Box<version_spec::SpecError>,
+    },
+
+    #[diagnostic(code(vcs_plugin::missing_state))]
+    #[error(
+        "The {} VCS plugin did not provide a {} for the current repository.",
+        .id.style(Style::Id),
+        .field,
+    )]
+    MissingState { id: String, field: String },
+
+    #[diagnostic(code(vcs_plugin::not_detected))]
+    #[error(
+        "The {} VCS plugin does not apply to this workspace: {reason}",
+        .id.style(Style::Id),
+    )]
+    NotDetected { id: String, reason: String },
+
+    #[diagnostic(
+        code(vcs_plugin::protocol_mismatch),
+        help = "Upgrade moon or the plugin so that both use the same protocol."
+    )]
+    #[error(
+        "The {} VCS plugin implements protocol version {}, but moon requires version {}.",
+        .id.style(Style::Id),
+        .actual.style(Style::Symbol),
+        .expected.style(Style::Symbol),
+    )]
+    ProtocolMismatch {
+        id: String,
+        expected: u16,
+        actual: u16,
+    },
+}
@@ -0,0 +1,99 @@
+use crate::vcs_plugin::VcsPlugin;
+use moon_common::Id;
+use moon_config::VcsConfig;
+use moon_plugin::{MoonHostData, PluginLocator, PluginRegistry, PluginType, PluginsConfig};
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct VcsRegistryConfig {
+    id: Id,
+    config: Arc<VcsConfig>,
+}
+
+impl Default for VcsRegistryConfig {
+    fn default() -> Self {
+        Self {
+            id: Id::raw("vcs"),
+            config: Arc::new(VcsConfig::default()),
+        }
+    }
+}
+
+impl PluginsConfig for VcsRegistryConfig {
+    fn get_ids(&self) -> Vec<&Id> {
+        if self.config.plugin.is_some() {
+            vec![&self.id]
+        } else {
+            vec![]
+        }
+    }
+
+    fn get_locator(&self, id: &Id) -> Option<&PluginLocator> {
+        if id == &self.id {
+            self.config.plugin.as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+impl Deref for VcsRegistryConfig {
+    type Target = VcsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.config
+    }
+}
+
+#[derive(Debug)]
+pub struct VcsRegistry(PluginRegistry<VcsRegistryConfig, VcsPlugin>);
+
+impl Default for VcsRegistry {
+    fn default() -> Self {
+        Self(
+            PluginRegistry::new(
+                PluginType::Vcs,
+                MoonHostData::default(),
+                VcsRegistryConfig::default(),
+            )
+            .unwrap(),
+        )
+    }
+}
+
+impl VcsRegistry {
+    pub fn new(host_data: MoonHostData, config: Arc<VcsConfig>) -> miette::Result<Self> {
+        Ok(Self(PluginRegistry::new(
+            PluginType::Vcs,
+            host_data,
+            VcsRegistryConfig {
+                config,
+                ..Default::default()
+            },
+        )?))
+    }
+
+    /// Return the ID the configured plugin is registered under.
+    pub fn get_plugin_id(&self) -> &Id {
+        &self.config_data.id
+    }
+
+    /// Load the configured VCS plugin, or `None` if not configured.
+    pub async fn load_configured(&self) -> miette::Result<Option<Arc<VcsPlugin>>> {
+        if self.config_data.plugin.is_none() {
+            return Ok(None);
+        }
+
+        self.load(self.get_plugin_id()).await.map(Some)
+    }
+}
+
+impl Deref for VcsRegistry {
+    type Target = PluginRegistry<VcsRegistryConfig, VcsPlugin>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
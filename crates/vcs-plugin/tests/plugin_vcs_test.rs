@@ -0,0 +1,246 @@
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_pdk_api::{GetVcsImpactsOutput, VcsChangeMask, VcsImpactCompleteness};
+use moon_test_utils::WorkspaceMocker;
+use moon_vcs::{ChangedStatus, Vcs};
+use moon_vcs_plugin::{PluginVcs, convert_change_mask, convert_impacts};
+use starbase_sandbox::{Sandbox, create_empty_sandbox};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+fn create_workspace() -> (Sandbox, WorkspaceMocker) {
+    let sandbox = create_empty_sandbox();
+    sandbox.create_file(".canned/state", "");
+    sandbox.create_file("src/file.txt", "content");
+
+    let mocker = WorkspaceMocker::new(sandbox.path()).with_test_vcs();
+
+    (sandbox, mocker)
+}
+
+async fn load_vcs(mocker: &WorkspaceMocker) -> PluginVcs {
+    PluginVcs::load(
+        &mocker.mock_vcs_registry(),
+        &mocker.workspace_root,
+        &mocker.workspace_config.vcs,
+    )
+    .await
+    .unwrap()
+}
+
+fn path(value: &str) -> WorkspaceRelativePathBuf {
+    WorkspaceRelativePathBuf::from(value)
+}
+
+mod plugin_vcs {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "does not apply to this workspace")]
+    async fn errors_if_not_detected() {
+        let sandbox = create_empty_sandbox();
+        let mocker = WorkspaceMocker::new(sandbox.path()).with_test_vcs();
+
+        load_vcs(&mocker).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn returns_initialized_state() {
+        let (sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+
+        assert_eq!(vcs.get_local_branch().await.unwrap(), "feature");
+        assert_eq!(vcs.get_local_branch_revision().await.unwrap(), "c2");
+        assert_eq!(vcs.get_default_branch().await.unwrap(), "master");
+        assert_eq!(vcs.get_default_branch_revision().await.unwrap(), "c1");
+        assert_eq!(vcs.get_repository_slug().await.unwrap(), "moonrepo/canned");
+        assert_eq!(vcs.get_version().await.unwrap().to_string(), "1.2.3");
+        assert_eq!(vcs.get_working_root().unwrap(), sandbox.path());
+        assert_eq!(
+            vcs.get_repository_root().unwrap(),
+            sandbox.path().join(".canned")
+        );
+        assert!(vcs.is_enabled());
+        assert!(vcs.is_default_branch("master"));
+        assert!(!vcs.is_shallow_checkout().await.unwrap());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn returns_working_changes() {
+        let (_sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+        let changes = vcs.get_changed_files().await.unwrap();
+
+        assert_eq!(changes.files.len(), 3);
+        assert_eq!(
+            changes.files.get(&path("src/working.txt")).unwrap(),
+            &vec![ChangedStatus::Modified, ChangedStatus::Unstaged]
+        );
+        assert_eq!(
+            changes.files.get(&path("src/staged.txt")).unwrap(),
+            &vec![ChangedStatus::Added, ChangedStatus::Staged]
+        );
+        assert_eq!(
+            changes.files.get(&path("src/untracked.txt")).unwrap(),
+            &vec![ChangedStatus::Added, ChangedStatus::Untracked]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn returns_changes_against_previous_revision() {
+        let (_sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+        let changes = vcs
+            .get_changed_files_against_previous_revision("c2")
+            .await
+            .unwrap();
+
+        assert_eq!(changes.all(), vec![&path("src/recorded.txt")]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn returns_changes_between_revisions() {
+        let (_sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+        let changes = vcs
+            .get_changed_files_between_revisions("c1", "c2")
+            .await
+            .unwrap();
+
+        assert_eq!(changes.files.len(), 2);
+        assert_eq!(
+            changes.files.get(&path("src/removed.txt")).unwrap(),
+            &vec![ChangedStatus::Deleted]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn includes_working_changes_when_no_head() {
+        let (_sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+        let changes = vcs
+            .get_changed_files_between_revisions("c1", "")
+            .await
+            .unwrap();
+
+        assert_eq!(changes.files.len(), 5);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "unable to determine changed files")]
+    async fn errors_when_impacts_unavailable() {
+        let (_sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+
+        vcs.get_changed_files_against_previous_revision("unavailable")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn hashes_files_on_the_host() {
+        let (_sandbox, mocker) = create_workspace();
+        let vcs = load_vcs(&mocker).await;
+        let hashes = vcs
+            .get_file_hashes(&[path("src/file.txt"), path("src/missing.txt")], false)
+            .await
+            .unwrap();
+
+        assert_eq!(hashes.len(), 1);
+        assert_eq!(
+            hashes.get(&path("src/file.txt")).unwrap(),
+            "ed7002b439e9ac845f22357d822bac1444730fbdb6016d3ec9432297b9ec9f73"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn returns_file_tree_without_metadata_dir() {
+        let (sandbox, mocker) = create_workspace();
+        sandbox.create_file(".gitignore", "ignored.txt");
+        sandbox.create_file("src/ignored.txt", "");
+
+        let vcs = load_vcs(&mocker).await;
+        let mut tree = vcs.get_file_tree(&path(".")).await.unwrap();
+        tree.sort();
+
+        assert_eq!(tree, vec![path(".gitignore"), path("src/file.txt")]);
+        assert!(vcs.is_ignored(&sandbox.path().join("src/ignored.txt")));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sets_up_and_tears_down_hooks() {
+        let (sandbox, mut mocker) = create_workspace();
+        mocker
+            .workspace_config
+            .vcs
+            .hooks
+            .insert("pre-commit".into(), vec![]);
+
+        let vcs = load_vcs(&mocker).await;
+        let env = vcs.setup_hooks().await.unwrap().unwrap();
+
+        assert_eq!(env.working_dir, sandbox.path());
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join(".canned/hooks")).unwrap(),
+            "pre-commit"
+        );
+
+        vcs.teardown_hooks().await.unwrap();
+
+        assert!(!sandbox.path().join(".canned/hooks").exists());
+    }
+}
+
+mod convert_impacts {
+    use super::*;
+
+    #[test]
+    fn maps_mask_bits_to_statuses() {
+        assert_eq!(
+            convert_change_mask(VcsChangeMask::DELETED | VcsChangeMask::STAGED).unwrap(),
+            vec![ChangedStatus::Deleted, ChangedStatus::Staged]
+        );
+        assert_eq!(
+            convert_change_mask(VcsChangeMask::MODIFIED | VcsChangeMask::RECORDED).unwrap(),
+            vec![ChangedStatus::Modified]
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_masks() {
+        assert!(convert_change_mask(VcsChangeMask::ADDED).is_none());
+        assert!(convert_change_mask(VcsChangeMask::WORKING).is_none());
+        assert!(convert_change_mask(VcsChangeMask::from_bits_retain(128)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid changed file path")]
+    fn rejects_parent_paths() {
+        convert_impacts(
+            "vcs",
+            GetVcsImpactsOutput {
+                changes: BTreeMap::from([(
+                    PathBuf::from("../outside.txt"),
+                    VcsChangeMask::ADDED | VcsChangeMask::WORKING,
+                )]),
+                completeness: VcsImpactCompleteness::Exact,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid change mask")]
+    fn rejects_invalid_masks() {
+        convert_impacts(
+            "vcs",
+            GetVcsImpactsOutput {
+                changes: BTreeMap::from([(PathBuf::from("file.txt"), VcsChangeMask::ADDED)]),
+                completeness: VcsImpactCompleteness::Conservative,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+mod query_fields;
 mod query_projects;
 mod query_tasks;
 
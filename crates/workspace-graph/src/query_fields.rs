@@ -0,0 +1,67 @@
+use moon_config::{Input, Output, ProjectMetadataConfig};
+use moon_project_graph::Project;
+use moon_task_graph::Task;
+use serde_json::Value;
+
+fn get_project_metadata(project: &Project) -> Option<&ProjectMetadataConfig> {
+    project.config.project.as_ref()
+}
+
+pub fn get_project_owner(project: &Project) -> Option<&str> {
+    get_project_metadata(project).and_then(|meta| meta.owner.as_deref())
+}
+
+pub fn get_project_channel(project: &Project) -> Option<&str> {
+    get_project_metadata(project).and_then(|meta| meta.channel.as_deref())
+}
+
+pub fn get_project_maintainers(project: &Project) -> &[String] {
+    get_project_metadata(project)
+        .map(|meta| meta.maintainers.as_slice())
+        .unwrap_or_default()
+}
+
+/// Return the values of a custom metadata field as strings. Lists are
+/// flattened into their items, while null values are treated as missing.
+pub fn get_project_custom_metadata(project: &Project, key: &str) -> Vec<String> {
+    fn stringify(value: &Value, values: &mut Vec<String>) {
+        match value {
+            Value::Null => {}
+            Value::String(inner) => values.push(inner.to_owned()),
+            Value::Array(items) => {
+                for item in items {
+                    stringify(item, values);
+                }
+            }
+            other => values.push(other.to_string()),
+        };
+    }
+
+    let mut values = vec![];
+
+    if let Some(value) = get_project_metadata(project).and_then(|meta| meta.metadata.get(key)) {
+        stringify(value, &mut values);
+    }
+
+    values
+}
+
+/// Return file and glob inputs, without a leading slash
+/// for workspace relative paths, so that globs can match them.
+pub fn get_task_input_paths(task: &Task) -> Vec<&str> {
+    task.inputs
+        .iter()
+        .filter(|input| matches!(input, Input::File(_) | Input::Glob(_)))
+        .map(|input| input.as_str().trim_start_matches('/'))
+        .collect()
+}
+
+/// Return file and glob outputs, without a leading slash
+/// for workspace relative paths, so that globs can match them.
+pub fn get_task_output_paths(task: &Task) -> Vec<&str> {
+    task.outputs
+        .iter()
+        .filter(|output| matches!(output, Output::File(_) | Output::Glob(_)))
+        .map(|output| output.as_str().trim_start_matches('/'))
+        .collect()
+}
@@ -1,7 +1,9 @@
-use crate::WorkspaceGraph;
+use crate::query_fields::*;
+use crate::{GraphConnections, WorkspaceGraph};
 use moon_common::{Id, IdExt, color};
 use moon_project_graph::Project;
 use moon_query::*;
+use moon_task_graph::Task;
 use std::{fmt::Debug, sync::Arc};
 use tracing::{debug, instrument};
 
@@ -83,6 +85,16 @@ impl WorkspaceGraph {
             let matches = match condition {
                 Condition::Field { field, .. } => {
                     let result = match field {
+                        Field::DependsOn(ids) => {
+                            condition.matches_related(ids, &self.projects.dependencies_of(project))
+                        }
+                        Field::DeepDependsOn(ids) => condition
+                            .matches_related(ids, &self.projects.deep_dependencies_of(project)),
+                        Field::DependencyOf(ids) => {
+                            condition.matches_related(ids, &self.projects.dependents_of(project))
+                        }
+                        Field::DeepDependencyOf(ids) => condition
+                            .matches_related(ids, &self.projects.deep_dependents_of(project)),
                         Field::Language(langs) => condition.matches_enum(langs, &project.language),
                         Field::Project(ids) => {
                             if condition.matches(ids, &project.id)? {
@@ -100,7 +112,20 @@ impl WorkspaceGraph {
                                 Ok(false)
                             }
                         }
+                        Field::ProjectChannel(channels) => match get_project_channel(project) {
+                            Some(channel) => condition.matches(channels, channel),
+                            None => Ok(false),
+                        },
                         Field::ProjectLayer(types) => condition.matches_enum(types, &project.layer),
+                        Field::ProjectMaintainer(maintainers) => {
+                            condition.matches_list(maintainers, get_project_maintainers(project))
+                        }
+                        Field::ProjectMetadata(key, values) => condition
+                            .matches_list(values, &get_project_custom_metadata(project, key)),
+                        Field::ProjectOwner(owners) => match get_project_owner(project) {
+                            Some(owner) => condition.matches(owners, owner),
+                            None => Ok(false),
+                        },
                         Field::ProjectId(ids) => condition.matches(ids, &project.id),
                         Field::ProjectSource(sources) => {
                             condition.matches(sources, &project.source)
@@ -115,6 +140,24 @@ impl WorkspaceGraph {
                                 .and_then(|task_id| condition.matches(ids, task_id))
                                 .unwrap_or_default()
                         })),
+                        Field::TaskInput(paths) => Ok(self
+                            .tasks
+                            .get_many(&project.task_targets)?
+                            .iter()
+                            .any(|task| {
+                                condition
+                                    .matches_list(paths, &get_task_input_paths(task))
+                                    .unwrap_or_default()
+                            })),
+                        Field::TaskOutput(paths) => Ok(self
+                            .tasks
+                            .get_many(&project.task_targets)?
+                            .iter()
+                            .any(|task| {
+                                condition
+                                    .matches_list(paths, &get_task_output_paths(task))
+                                    .unwrap_or_default()
+                            })),
                         Field::TaskTag(tags) => Ok(self
                             .tasks
                             .get_many(&project.task_targets)?
@@ -138,6 +181,25 @@ impl WorkspaceGraph {
 
                                 condition.matches_list(ids, &toolchains).unwrap_or_default()
                             })),
+                        Field::TaskDependsOn(targets)
+                        | Field::DeepTaskDependsOn(targets)
+                        | Field::TaskDependencyOf(targets)
+                        | Field::DeepTaskDependencyOf(targets) => {
+                            let mut related = vec![];
+
+                            for task in self.tasks.get_many_unexpanded(&project.task_targets)? {
+                                related.extend(match field {
+                                    Field::TaskDependsOn(_) => self.tasks.dependencies_of(task),
+                                    Field::DeepTaskDependsOn(_) => {
+                                        self.tasks.deep_dependencies_of(task)
+                                    }
+                                    Field::TaskDependencyOf(_) => self.tasks.dependents_of(task),
+                                    _ => self.tasks.deep_dependents_of(task),
+                                });
+                            }
+
+                            condition.matches_related(targets, &related)
+                        }
                         Field::TaskType(types) => Ok(self
                             .tasks
                             .get_many(&project.task_targets)?
@@ -147,6 +209,15 @@ impl WorkspaceGraph {
                                     .matches_enum(types, &task.type_of)
                                     .unwrap_or_default()
                             })),
+                        Field::TaskVariant(ids) => Ok(self
+                            .tasks
+                            .get_many(&project.task_targets)?
+                            .iter()
+                            .any(|task| {
+                                condition
+                                    .matches_list(ids, task.variant.as_slice())
+                                    .unwrap_or_default()
+                            })),
                     };
 
                     result?
@@ -154,6 +225,9 @@ impl WorkspaceGraph {
                 Condition::Criteria { criteria } => {
                     self.does_project_match_criteria(project, criteria)?
                 }
+                Condition::Exists { field, negated } => {
+                    self.does_project_have_field(project, field)? != *negated
+                }
             };
 
             if matches {
@@ -176,4 +250,30 @@ impl WorkspaceGraph {
 
         Ok(true)
     }
+
+    fn does_project_have_field(&self, project: &Project, field: &Field) -> miette::Result<bool> {
+        let has_any_task = |op: fn(&Task) -> bool| -> miette::Result<bool> {
+            Ok(self
+                .tasks
+                .get_many(&project.task_targets)?
+                .iter()
+                .any(|task| op(task)))
+        };
+
+        Ok(match field {
+            Field::ProjectAlias(_) => !project.aliases.is_empty(),
+            Field::ProjectChannel(_) => get_project_channel(project).is_some(),
+            Field::ProjectMaintainer(_) => !get_project_maintainers(project).is_empty(),
+            Field::ProjectMetadata(key, _) => !get_project_custom_metadata(project, key).is_empty(),
+            Field::ProjectOwner(_) => get_project_owner(project).is_some(),
+            Field::ProjectTag(_) => !project.config.tags.is_empty(),
+            Field::Task(_) => !project.task_targets.is_empty(),
+            Field::TaskInput(_) => has_any_task(|task| !get_task_input_paths(task).is_empty())?,
+            Field::TaskOutput(_) => has_any_task(|task| !get_task_output_paths(task).is_empty())?,
+            Field::TaskTag(_) => has_any_task(|task| !task.tags.is_empty())?,
+            Field::TaskToolchain(_) => has_any_task(|task| !task.toolchains.is_empty())?,
+            Field::TaskVariant(_) => has_any_task(|task| task.variant.is_some())?,
+            _ => false,
+        })
+    }
 }
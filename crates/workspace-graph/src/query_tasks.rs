@@ -1,4 +1,5 @@
-use crate::WorkspaceGraph;
+use crate::query_fields::*;
+use crate::{GraphConnections, WorkspaceGraph};
 use moon_common::color;
 use moon_project_graph::Project;
 use moon_query::*;
@@ -104,10 +105,64 @@ impl WorkspaceGraph {
                                 Ok(false)
                             }
                         }
+                        Field::ProjectChannel(channels) => {
+                            match self
+                                .get_task_parent_project(task)?
+                                .and_then(get_project_channel)
+                            {
+                                Some(channel) => condition.matches(channels, channel),
+                                None => Ok(false),
+                            }
+                        }
+                        Field::ProjectMaintainer(maintainers) => {
+                            match self.get_task_parent_project(task)? {
+                                Some(project) => condition
+                                    .matches_list(maintainers, get_project_maintainers(project)),
+                                None => Ok(false),
+                            }
+                        }
+                        Field::ProjectMetadata(key, values) => {
+                            match self.get_task_parent_project(task)? {
+                                Some(project) => condition.matches_list(
+                                    values,
+                                    &get_project_custom_metadata(project, key),
+                                ),
+                                None => Ok(false),
+                            }
+                        }
+                        Field::ProjectOwner(owners) => {
+                            match self
+                                .get_task_parent_project(task)?
+                                .and_then(get_project_owner)
+                            {
+                                Some(owner) => condition.matches(owners, owner),
+                                None => Ok(false),
+                            }
+                        }
                         Field::Task(ids) => condition.matches(ids, &task.id),
+                        Field::TaskInput(paths) => {
+                            condition.matches_list(paths, &get_task_input_paths(task))
+                        }
+                        Field::TaskOutput(paths) => {
+                            condition.matches_list(paths, &get_task_output_paths(task))
+                        }
+                        Field::TaskDependsOn(targets) => {
+                            condition.matches_related(targets, &self.tasks.dependencies_of(task))
+                        }
+                        Field::DeepTaskDependsOn(targets) => condition
+                            .matches_related(targets, &self.tasks.deep_dependencies_of(task)),
+                        Field::TaskDependencyOf(targets) => {
+                            condition.matches_related(targets, &self.tasks.dependents_of(task))
+                        }
+                        Field::DeepTaskDependencyOf(targets) => {
+                            condition.matches_related(targets, &self.tasks.deep_dependents_of(task))
+                        }
                         Field::TaskTag(tags) => condition.matches_list(tags, &task.tags),
                         Field::TaskToolchain(ids) => condition.matches_list(ids, &task.toolchains),
                         Field::TaskType(types) => condition.matches_enum(types, &task.type_of),
+                        Field::TaskVariant(ids) => {
+                            condition.matches_list(ids, task.variant.as_slice())
+                        }
                         // These fields match against the task's parent project
                         _ => Ok(false),
                     };
@@ -117,6 +172,9 @@ impl WorkspaceGraph {
                 Condition::Criteria { criteria } => {
                     self.does_task_match_criteria(task, criteria)?
                 }
+                Condition::Exists { field, negated } => {
+                    self.does_task_have_field(task, field)? != *negated
+                }
             };
 
             if matches {
@@ -139,4 +197,28 @@ impl WorkspaceGraph {
 
         Ok(true)
     }
+
+    fn does_task_have_field(&self, task: &Task, field: &Field) -> miette::Result<bool> {
+        let project = self.get_task_parent_project(task)?;
+
+        Ok(match field {
+            Field::ProjectAlias(_) => project.is_some_and(|project| !project.aliases.is_empty()),
+            Field::ProjectChannel(_) => project.and_then(get_project_channel).is_some(),
+            Field::ProjectMaintainer(_) => {
+                project.is_some_and(|project| !get_project_maintainers(project).is_empty())
+            }
+            Field::ProjectMetadata(key, _) => {
+                project.is_some_and(|project| !get_project_custom_metadata(project, key).is_empty())
+            }
+            Field::ProjectOwner(_) => project.and_then(get_project_owner).is_some(),
+            Field::ProjectTag(_) => project.is_some_and(|project| !project.config.tags.is_empty()),
+            Field::Task(_) => true,
+            Field::TaskInput(_) => !get_task_input_paths(task).is_empty(),
+            Field::TaskOutput(_) => !get_task_output_paths(task).is_empty(),
+            Field::TaskTag(_) => !task.tags.is_empty(),
+            Field::TaskToolchain(_) => !task.toolchains.is_empty(),
+            Field::TaskVariant(_) => task.variant.is_some(),
+            _ => false,
+        })
+    }
 }
@@ -88,6 +88,16 @@ pub struct WorkspaceBuilder {
     /// These are used for invalidation.
     config_paths: Vec<WorkspaceRelativePathBuf>,
 
+    /// Project dependency edges that were disconnected because they
+    /// would have formed a cycle. These are surfaced when analyzing.
+    #[serde(default)]
+    cycle_project_edges: Vec<(Id, Id)>,
+
+    /// Task dependency edges that were disconnected because they
+    /// would have formed a cycle. These are surfaced when analyzing.
+    #[serde(default)]
+    cycle_task_edges: Vec<(Target, Target)>,
+
     /// Input files discovered by plugins while extending the graph.
     /// These are used for invalidation.
     #[serde(skip)]
@@ -138,6 +148,8 @@ impl WorkspaceBuilder {
             aliases: FxHashMap::default(),
             config_paths: vec![],
             context: Some(Arc::new(context)),
+            cycle_project_edges: vec![],
+            cycle_task_edges: vec![],
             deferred_project_edges: vec![],
             plugin_input_paths: BTreeSet::default(),
             project_data: FxHashMap::default(),
@@ -293,6 +305,7 @@ impl WorkspaceBuilder {
         let mut project_graph = ProjectGraph::new(graph_context.clone());
         project_graph.default_id = context.workspace_config.default_project.clone();
         project_graph.aliases.extend(self.aliases);
+        project_graph.cycle_edges = self.cycle_project_edges;
         let mut loaded_projects = FxHashMap::default();
 
         let graph = self.project_graph.filter_map(
@@ -323,6 +336,7 @@ impl WorkspaceBuilder {
         let project_graph = Arc::new(project_graph);
 
         let mut task_graph = TaskGraph::new(graph_context, Arc::clone(&project_graph));
+        task_graph.cycle_edges = self.cycle_task_edges;
         let mut loaded_tasks = FxHashMap::default();
 
         task_graph.graph = self.task_graph.filter_map(
@@ -389,6 +403,9 @@ impl WorkspaceBuilder {
                     dependency_id = edge.target_id.as_str(),
                     "Encountered a dependency cycle (from project); will disconnect nodes to avoid recursion",
                 );
+
+                self.cycle_project_edges
+                    .push((edge.source_id, edge.target_id));
             } else {
                 self.project_graph
                     .add_edge(edge.source_index, edge.target_index, edge.scope);
@@ -489,6 +506,11 @@ impl WorkspaceBuilder {
                         dependency_id = dep_config.id.as_str(),
                         "Encountered a dependency cycle (from project); will disconnect nodes to avoid recursion",
                     );
+
+                    // The root project is never linked, so it's not a cycle
+                    if !dep_config.is_root_scope() {
+                        self.cycle_project_edges.push((id.clone(), dep_id));
+                    }
                 }
 
                 continue;
@@ -697,6 +719,9 @@ impl WorkspaceBuilder {
                     "Encountered a dependency cycle (from task); will disconnect nodes to avoid recursion",
                 );
 
+                self.cycle_task_edges
+                    .push((target.clone(), dep_config.target.clone()));
+
                 continue;
             }
 
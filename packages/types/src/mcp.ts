@@ -1,8 +1,20 @@
-import type { Action } from './pipeline';
+import type { Duration } from './common';
+import type { Action, ActionStatus, AffectedTaskState } from './pipeline';
 import type { Project, ProjectFragment } from './project';
 import type { Task, TaskFragment } from './task';
 import type { TemplateVariable } from './template-config';
 
+export interface GetAffectedTasksTool {
+	base?: string;
+	head?: string;
+	includeDependents?: boolean;
+	remote?: boolean;
+}
+
+export interface GetAffectedTasksToolResponse {
+	tasks: Record<string, AffectedTaskState>;
+}
+
 export interface GetChangedFilesTool {
 	base?: string;
 	head?: string;
@@ -69,6 +81,42 @@ export interface GetTemplatesToolResponse {
 	templates: TemplateSummary[];
 }
 
+export interface QueryProjectsTool {
+	query: string;
+}
+
+export interface QueryProjectsToolResponse {
+	projects: ProjectFragment[];
+}
+
+export interface QueryTasksTool {
+	query: string;
+}
+
+export interface QueryTasksToolResponse {
+	tasks: TaskFragment[];
+}
+
+export interface RunTaskResult {
+	duration?: Duration;
+	error?: string;
+	exitCode?: number;
+	label: string;
+	status: ActionStatus;
+	stderr?: string;
+	stdout?: string;
+}
+
+export interface RunTasksTool {
+	targets: string[];
+	includeDependents?: boolean;
+}
+
+export interface RunTasksToolResponse {
+	passed: boolean;
+	results: RunTaskResult[];
+}
+
 export interface SyncProjectsTool {
 	ids: string[];
 }
@@ -36,6 +36,12 @@ export interface OperationMetaArchiveCreation {
 	type: 'archive-creation';
 }
 
+export interface OperationMetaFileAudit {
+	type: 'file-audit';
+	undeclaredInputs?: string[];
+	undeclaredOutputs?: string[];
+}
+
 export interface OperationMetaHashGeneration {
 	type: 'hash-generation';
 	hash?: string;
@@ -71,6 +77,7 @@ export interface OperationMetaTaskExecution extends OperationBaseProcessOutput {
 
 export type OperationMeta =
 	| OperationMetaArchiveCreation
+	| OperationMetaFileAudit
 	| OperationMetaHashGeneration
 	| OperationMetaMutexAcquisition
 	| OperationMetaNoOperation
@@ -104,6 +111,7 @@ export interface Action {
 	node: ActionNode;
 	nodeIndex: number;
 	operations: Operation[];
+	quarantined: boolean;
 	startedAt?: string | null;
 	status: ActionStatus;
 }
@@ -11,6 +11,7 @@ import type {
 	TaskOutputStyle,
 	TaskPreset,
 	TaskPriority,
+	TaskReadinessConfig,
 	TaskType,
 	TaskUnixShell,
 	TaskWindowsShell,
@@ -43,6 +44,7 @@ export interface TaskOptions {
 	outputStyle?: TaskOutputStyle | null;
 	persistent: boolean;
 	priority: TaskPriority;
+	resources?: Record<string, number>;
 	retryCount: number;
 	runDepsInParallel: boolean;
 	runInCI: boolean;
@@ -93,12 +95,14 @@ export interface Task {
 	outputFiles?: Record<string, TaskFileOutput>;
 	outputGlobs?: Record<string, TaskGlobOutput>;
 	preset?: TaskPreset | null;
+	readiness?: TaskReadinessConfig | null;
 	script?: string | null;
 	state: TaskState;
 	tags: Id[];
 	target: string;
 	toolchains?: Id[];
 	type: TaskType;
+	variant?: Id | null;
 }
 
 export interface TaskFragment {
@@ -408,6 +408,14 @@ export interface TaskOptionsConfig {
 	 * @default 'normal'
 	 */
 	priority?: TaskPriority | null;
+	/**
+	 * A mapping of resource pools to the weight (number of permits) this
+	 * task acquires from each pool before running. The `cpu` pool is
+	 * reserved for the pipeline's concurrency, while other pools are
+	 * configured with `pipeline.resourcePools`.
+	 * @since 2.6.0
+	 */
+	resources?: Record<Id, number> | null;
 	/**
 	 * The number of times a failing task will be retried to succeed.
 	 *
@@ -484,6 +492,37 @@ export type TaggedTaskCheck =
 
 export type TaskCheck = string | TaggedTaskCheck;
 
+/**
+ * Readiness probes for a persistent task. Persistent tasks that depend
+ * on this task will not start until all configured probes have passed.
+ */
+export interface TaskReadinessConfig {
+	/**
+	 * A file path, relative from the task's working directory, that
+	 * must exist.
+	 */
+	file?: string | null;
+	/** A URL that must respond with a 2xx status code. */
+	http?: string | null;
+	/**
+	 * The interval in milliseconds between each probe.
+	 *
+	 * @default 250
+	 */
+	interval: number;
+	/** A regex pattern that a line of the task's output must match. */
+	log?: string | null;
+	/** A port on localhost that must accept TCP connections. */
+	port?: number | null;
+	/**
+	 * The number of seconds to wait for all probes to pass, before
+	 * failing the task.
+	 *
+	 * @default 60
+	 */
+	timeout: number;
+}
+
 /** A file path output. */
 export interface FileOutput {
 	/** The literal file path. */
@@ -514,6 +553,20 @@ export type TaskPreset = 'utility' | 'server';
 /** The type of task. */
 export type TaskType = 'build' | 'run' | 'test';
 
+/** Configures a variant of a task matrix. */
+export interface TaskMatrixVariantConfig {
+	/**
+	 * Additional arguments to pass to the command when this variant is ran.
+	 * Can be defined as a string, or a list of individual arguments.
+	 */
+	args: TaskArgs;
+	/**
+	 * A map of environment variables that will be set in the child
+	 * process when this variant is ran.
+	 */
+	env?: Record<string, string | null> | null;
+}
+
 /** Configures a task to be ran within the action pipeline. */
 export interface TaskConfig {
 	/**
@@ -570,6 +623,14 @@ export interface TaskConfig {
 	 * explicit list of inputs are considered.
 	 */
 	inputs?: Input[] | null;
+	/**
+	 * Expands the task into multiple variants, keyed by a unique identifier,
+	 * each with their own arguments and environment variables. Each variant
+	 * becomes a task with the identifier `<task>-<variant>`, while the
+	 * original task becomes an aggregate that depends on all variants.
+	 * @since 2.6.0
+	 */
+	matrix?: Record<Id, TaskMatrixVariantConfig> | null;
 	/** Options to control task inheritance, execution, and more. */
 	options: TaskOptionsConfig;
 	/**
@@ -579,6 +640,12 @@ export interface TaskConfig {
 	outputs?: Output[] | null;
 	/** The preset to apply for the task. Will inherit default options. */
 	preset?: TaskPreset | null;
+	/**
+	 * Readiness probes for persistent tasks. Persistent tasks that depend
+	 * on this task will wait for all probes to pass before starting.
+	 * @since 2.6.0
+	 */
+	readiness?: TaskReadinessConfig | null;
 	/**
 	 * A script to run within a shell. A script is anything from a single command,
 	 * to multiple commands, or shell specific syntax. Does not support
@@ -939,6 +1006,14 @@ export interface PartialTaskOptionsConfig {
 	 * @default 'normal'
 	 */
 	priority?: TaskPriority | null;
+	/**
+	 * A mapping of resource pools to the weight (number of permits) this
+	 * task acquires from each pool before running. The `cpu` pool is
+	 * reserved for the pipeline's concurrency, while other pools are
+	 * configured with `pipeline.resourcePools`.
+	 * @since 2.6.0
+	 */
+	resources?: Record<Id, number> | null;
 	/**
 	 * The number of times a failing task will be retried to succeed.
 	 *
@@ -985,6 +1060,51 @@ export interface PartialTaskOptionsConfig {
 
 export type PartialTaskArgs = null | string | string[];
 
+/**
+ * Readiness probes for a persistent task. Persistent tasks that depend
+ * on this task will not start until all configured probes have passed.
+ */
+export interface PartialTaskReadinessConfig {
+	/**
+	 * A file path, relative from the task's working directory, that
+	 * must exist.
+	 */
+	file?: string | null;
+	/** A URL that must respond with a 2xx status code. */
+	http?: string | null;
+	/**
+	 * The interval in milliseconds between each probe.
+	 *
+	 * @default 250
+	 */
+	interval?: number | null;
+	/** A regex pattern that a line of the task's output must match. */
+	log?: string | null;
+	/** A port on localhost that must accept TCP connections. */
+	port?: number | null;
+	/**
+	 * The number of seconds to wait for all probes to pass, before
+	 * failing the task.
+	 *
+	 * @default 60
+	 */
+	timeout?: number | null;
+}
+
+/** Configures a variant of a task matrix. */
+export interface PartialTaskMatrixVariantConfig {
+	/**
+	 * Additional arguments to pass to the command when this variant is ran.
+	 * Can be defined as a string, or a list of individual arguments.
+	 */
+	args?: PartialTaskArgs | null;
+	/**
+	 * A map of environment variables that will be set in the child
+	 * process when this variant is ran.
+	 */
+	env?: Record<string, string | null> | null;
+}
+
 /** Configures a task to be ran within the action pipeline. */
 export interface PartialTaskConfig {
 	/**
@@ -1041,6 +1161,14 @@ export interface PartialTaskConfig {
 	 * explicit list of inputs are considered.
 	 */
 	inputs?: Input[] | null;
+	/**
+	 * Expands the task into multiple variants, keyed by a unique identifier,
+	 * each with their own arguments and environment variables. Each variant
+	 * becomes a task with the identifier `<task>-<variant>`, while the
+	 * original task becomes an aggregate that depends on all variants.
+	 * @since 2.6.0
+	 */
+	matrix?: Record<Id, PartialTaskMatrixVariantConfig> | null;
 	/** Options to control task inheritance, execution, and more. */
 	options?: PartialTaskOptionsConfig | null;
 	/**
@@ -1050,6 +1178,12 @@ export interface PartialTaskConfig {
 	outputs?: Output[] | null;
 	/** The preset to apply for the task. Will inherit default options. */
 	preset?: TaskPreset | null;
+	/**
+	 * Readiness probes for persistent tasks. Persistent tasks that depend
+	 * on this task will wait for all probes to pass before starting.
+	 * @since 2.6.0
+	 */
+	readiness?: PartialTaskReadinessConfig | null;
 	/**
 	 * A script to run within a shell. A script is anything from a single command,
 	 * to multiple commands, or shell specific syntax. Does not support
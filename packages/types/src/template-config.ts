@@ -154,6 +154,26 @@ export type TemplateVariable =
 	| TemplateVariableObjectSetting
 	| TemplateVariableStringSetting;
 
+/** Configures a step to run after a template has been generated. */
+export interface TemplatePostGenerateConfig {
+	/** Continue running subsequent steps when this step fails. */
+	allowFailure?: boolean;
+	/**
+	 * A shell command to run in the destination directory.
+	 * Supports variable interpolation.
+	 */
+	command?: string | null;
+	/**
+	 * A target to run with `moon run` from the destination directory.
+	 * A task identifier without a project runs in the generated project.
+	 * Supports variable interpolation.
+	 */
+	target?: string | null;
+}
+
+/** A step to run after a template has been generated. */
+export type TemplatePostGenerateStep = string | TemplatePostGenerateConfig;
+
 /**
  * Configures a template and its files to be scaffolded.
  * Docs: https://moonrepo.dev/docs/config/template
@@ -169,8 +189,19 @@ export interface TemplateConfig {
 	destination?: string | null;
 	/** Extends one or many other templates. */
 	extends?: Id | Id[];
+	/**
+	 * A map of file paths or globs, relative from the template root, to a
+	 * condition that must be truthy for matching files to be generated.
+	 * Conditions are Tera expressions that can reference variables.
+	 */
+	files?: Record<string, string>;
 	/** Overrides the identifier of the template, instead of using the folder name. */
 	id?: Id | null;
+	/**
+	 * A list of shell commands or targets to run, in order, after
+	 * the template files have been written to the destination.
+	 */
+	postGenerate?: TemplatePostGenerateStep[];
 	/** A human-readable title for the template. */
 	title: string;
 	/**
@@ -302,6 +333,26 @@ export type PartialTemplateVariable =
 	| PartialTemplateVariableObjectSetting
 	| PartialTemplateVariableStringSetting;
 
+/** Configures a step to run after a template has been generated. */
+export interface PartialTemplatePostGenerateConfig {
+	/** Continue running subsequent steps when this step fails. */
+	allowFailure?: boolean | null;
+	/**
+	 * A shell command to run in the destination directory.
+	 * Supports variable interpolation.
+	 */
+	command?: string | null;
+	/**
+	 * A target to run with `moon run` from the destination directory.
+	 * A task identifier without a project runs in the generated project.
+	 * Supports variable interpolation.
+	 */
+	target?: string | null;
+}
+
+/** A step to run after a template has been generated. */
+export type PartialTemplatePostGenerateStep = string | PartialTemplatePostGenerateConfig;
+
 /**
  * Configures a template and its files to be scaffolded.
  * Docs: https://moonrepo.dev/docs/config/template
@@ -317,8 +368,19 @@ export interface PartialTemplateConfig {
 	destination?: string | null;
 	/** Extends one or many other templates. */
 	extends?: Id | Id[] | null;
+	/**
+	 * A map of file paths or globs, relative from the template root, to a
+	 * condition that must be truthy for matching files to be generated.
+	 * Conditions are Tera expressions that can reference variables.
+	 */
+	files?: Record<string, string> | null;
 	/** Overrides the identifier of the template, instead of using the folder name. */
 	id?: Id | null;
+	/**
+	 * A list of shell commands or targets to run, in order, after
+	 * the template files have been written to the destination.
+	 */
+	postGenerate?: PartialTemplatePostGenerateStep[] | null;
 	/** A human-readable title for the template. */
 	title?: string | null;
 	/**
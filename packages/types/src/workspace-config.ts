@@ -3,6 +3,7 @@
 /* eslint-disable */
 
 import type { ExtendsFrom, Id } from './common';
+import type { PluginLocator } from './toolchains-config';
 
 /** Configures aspects of the content-addressable storage (CAS) cache. */
 export interface CacheCasConfig {
@@ -320,8 +321,17 @@ export interface NotifierConfig {
 
 export type PipelineActionSwitch = null | boolean | Id[];
 
+/** The strategy for auditing the files that a task reads and writes. */
+export type PipelineAuditTaskFiles = boolean | 'snapshot' | 'trace';
+
 /** Configures aspects of the action pipeline. */
 export interface PipelineConfig {
+	/**
+	 * Audit the files that a task reads and writes while running, and
+	 * warn about files that are not declared as `inputs` or `outputs`.
+	 * @since 2.6.0
+	 */
+	auditTaskFiles?: PipelineAuditTaskFiles;
 	/**
 	 * Automatically clean the cache after every task run.
 	 * @since 1.24.0
@@ -360,6 +370,20 @@ export interface PipelineConfig {
 	killProcessThreshold?: number;
 	/** Logs the task's command and arguments when running the task. */
 	logRunningCommand?: boolean;
+	/**
+	 * A list of task targets that are quarantined. Failures of quarantined
+	 * tasks are reported, but do not fail the pipeline. Supports
+	 * fully-qualified targets, and all project scoped targets (`:task`).
+	 * @since 2.6.0
+	 */
+	quarantine?: string[];
+	/**
+	 * A mapping of named resource pools to their capacity. Tasks acquire
+	 * weighted permits from these pools (via the `resources` task option)
+	 * before running. Pools that are not configured have a capacity of 1.
+	 * @since 2.6.0
+	 */
+	resourcePools?: Record<Id, number>;
 	/**
 	 * When creating `SyncProject` actions, recursively create a `SyncProject`
 	 * action for each project dependency, and link them as a relationship.
@@ -622,6 +646,13 @@ export interface VcsConfig {
 	 * @since 1.9.0
 	 */
 	hooks?: Record<string, string[]>;
+	/**
+	 * Location of a WASM plugin that implements the VCS client,
+	 * for example, Jujutsu or Sapling. When configured, the plugin
+	 * is used instead of the built-in `client`.
+	 * @since 2.6.0
+	 */
+	plugin?: PluginLocator | null;
 	/**
 	 * The upstream version control provider, where the repository
 	 * source code is stored.
@@ -1035,6 +1066,12 @@ export type PartialPipelineActionSwitch = null | boolean | Id[];
 
 /** Configures aspects of the action pipeline. */
 export interface PartialPipelineConfig {
+	/**
+	 * Audit the files that a task reads and writes while running, and
+	 * warn about files that are not declared as `inputs` or `outputs`.
+	 * @since 2.6.0
+	 */
+	auditTaskFiles?: PipelineAuditTaskFiles | null;
 	/**
 	 * Automatically clean the cache after every task run.
 	 * @since 1.24.0
@@ -1073,6 +1110,20 @@ export interface PartialPipelineConfig {
 	killProcessThreshold?: number | null;
 	/** Logs the task's command and arguments when running the task. */
 	logRunningCommand?: boolean | null;
+	/**
+	 * A list of task targets that are quarantined. Failures of quarantined
+	 * tasks are reported, but do not fail the pipeline. Supports
+	 * fully-qualified targets, and all project scoped targets (`:task`).
+	 * @since 2.6.0
+	 */
+	quarantine?: string[] | null;
+	/**
+	 * A mapping of named resource pools to their capacity. Tasks acquire
+	 * weighted permits from these pools (via the `resources` task option)
+	 * before running. Pools that are not configured have a capacity of 1.
+	 * @since 2.6.0
+	 */
+	resourcePools?: Record<Id, number> | null;
 	/**
 	 * When creating `SyncProject` actions, recursively create a `SyncProject`
 	 * action for each project dependency, and link them as a relationship.
@@ -1303,6 +1354,13 @@ export interface PartialVcsConfig {
 	 * @since 1.9.0
 	 */
 	hooks?: Record<string, string[]> | null;
+	/**
+	 * Location of a WASM plugin that implements the VCS client,
+	 * for example, Jujutsu or Sapling. When configured, the plugin
+	 * is used instead of the built-in `client`.
+	 * @since 2.6.0
+	 */
+	plugin?: PluginLocator | null;
 	/**
 	 * The upstream version control provider, where the repository
 	 * source code is stored.
@@ -1,7 +1,13 @@
 import './app.css';
-import { useState } from 'preact/hooks';
+import { useEffect, useState } from 'preact/hooks';
 
+import { Details } from './components/Details';
 import { Graph } from './components/Graph';
+import { Sidebar } from './components/Sidebar';
+import { loadAffected, loadGraph } from './helpers/api';
+import { EMPTY_FILTERS } from './helpers/filters';
+import { isExplorerGraph } from './helpers/render';
+import type { ExplorerAffected, ExplorerGraph, ExplorerNode, GraphInfo } from './helpers/types';
 
 const SUPPORTED_LAYOUTS = ['dagre', 'klay', 'breadthfirst', 'grid'];
 
@@ -25,6 +31,23 @@ function setLayoutIntoQuery(layout: string) {
 
 export function App() {
 	const [layout, setLayout] = useState(getLayoutFromQuery());
+	const [data, setData] = useState<ExplorerGraph | GraphInfo | null>(null);
+	const [error, setError] = useState('');
+	const [filters, setFilters] = useState(EMPTY_FILTERS);
+	const [affected, setAffected] = useState<ExplorerAffected | null>(null);
+	const [selected, setSelected] = useState<ExplorerNode | null>(null);
+
+	useEffect(() => {
+		loadGraph()
+			.then(setData)
+			.catch((error_) => setError(String(error_)));
+	}, []);
+
+	async function handleAffected(base: string, head: string) {
+		setAffected(await loadAffected(base, head));
+	}
+
+	const explorer = window.GRAPH_EXPLORER && data !== null && isExplorerGraph(data) ? data : null;
 
 	function handleChange(event: Event) {
 		const target = event.target as HTMLSelectElement;
@@ -54,7 +77,40 @@ export function App() {
 
 			<h2 className="m-0 p-4 text-3xl font-extrabold sm:text-4xl">{window.PAGE_TITLE}</h2>
 
-			<Graph layout={layout} />
+			{error && <p className="p-4 text-red-400">{error}</p>}
+
+			{data && !explorer && <Graph data={data} layout={layout} />}
+
+			{explorer && (
+				<div className="flex">
+					<Sidebar
+						affected={affected}
+						filters={filters}
+						nodes={explorer.nodes}
+						onAffected={handleAffected}
+						onClearAffected={() => setAffected(null)}
+						onFilters={setFilters}
+					/>
+
+					<div className="flex-1 min-w-0">
+						<Graph
+							affected={affected ? [...affected.projects, ...affected.tasks] : null}
+							data={explorer}
+							filters={filters}
+							layout={layout}
+							onSelect={setSelected}
+						/>
+					</div>
+
+					{selected && (
+						<Details
+							node={selected}
+							onClose={() => setSelected(null)}
+							onSelect={setSelected}
+						/>
+					)}
+				</div>
+			)}
 		</main>
 	);
 }
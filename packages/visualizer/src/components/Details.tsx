@@ -0,0 +1,147 @@
+import type { Task } from '@moonrepo/types';
+import { useEffect, useState } from 'preact/hooks';
+
+import { loadProject, loadTask } from '../helpers/api';
+import type { ExplorerNode, ExplorerProject } from '../helpers/types';
+
+export interface DetailsProps {
+	node: ExplorerNode;
+	onClose: () => void;
+	onSelect: (node: ExplorerNode) => void;
+}
+
+function List({ items, title }: { items: string[]; title: string }) {
+	return (
+		<section className="mb-4">
+			<h4 className="mb-1 font-bold">{title}</h4>
+			{items.length === 0 ? (
+				<p className="text-sm text-slate-400">None</p>
+			) : (
+				<ul className="text-sm font-mono break-all">
+					{items.map((item) => (
+						<li key={item}>{item}</li>
+					))}
+				</ul>
+			)}
+		</section>
+	);
+}
+
+function TaskDetails({ task }: { task: Task }) {
+	const command = [task.command, ...(task.args ?? [])].join(' ');
+
+	return (
+		<>
+			{task.description && <p className="mb-4 text-sm">{task.description}</p>}
+			<List items={[task.script ?? command]} title={task.script ? 'Script' : 'Command'} />
+			<List items={(task.deps ?? []).map((dep) => dep.target)} title="Dependencies" />
+			<List
+				items={[
+					...Object.keys(task.inputFiles ?? {}),
+					...Object.keys(task.inputGlobs ?? {}),
+				]}
+				title="Inputs"
+			/>
+			<List
+				items={[
+					...Object.keys(task.outputFiles ?? {}),
+					...Object.keys(task.outputGlobs ?? {}),
+				]}
+				title="Outputs"
+			/>
+		</>
+	);
+}
+
+function ProjectDetails({
+	data,
+	onSelect,
+}: {
+	data: ExplorerProject;
+	onSelect: (node: ExplorerNode) => void;
+}) {
+	const { project, tasks } = data;
+
+	return (
+		<>
+			<p className="mb-4 text-sm">
+				{project.source}
+				<br />
+				{project.language} · {project.layer} · {project.stack}
+			</p>
+			<section className="mb-4">
+				<h4 className="mb-1 font-bold">Tasks</h4>
+				{tasks.length === 0 ? (
+					<p className="text-sm text-slate-400">None</p>
+				) : (
+					<ul className="text-sm font-mono">
+						{tasks.map((task) => (
+							<li key={task.id}>
+								<button
+									className="underline"
+									type="button"
+									onClick={() => onSelect(task)}
+								>
+									{task.id}
+								</button>
+							</li>
+						))}
+					</ul>
+				)}
+			</section>
+		</>
+	);
+}
+
+export function Details({ node, onClose, onSelect }: DetailsProps) {
+	const [project, setProject] = useState<ExplorerProject | null>(null);
+	const [task, setTask] = useState<Task | null>(null);
+	const [error, setError] = useState('');
+
+	useEffect(() => {
+		let active = true;
+
+		setProject(null);
+		setTask(null);
+		setError('');
+
+		const request =
+			node.kind === 'project'
+				? loadProject(node.id).then((data) => active && setProject(data))
+				: loadTask(node.id).then((data) => active && setTask(data));
+
+		request.catch((error_) => active && setError(String(error_)));
+
+		return () => {
+			active = false;
+		};
+	}, [node]);
+
+	return (
+		<aside className="w-80 shrink-0 p-4 overflow-y-auto" style={{ maxHeight: '80vh' }}>
+			<div className="flex items-center mb-3">
+				<h3 className="flex-1 text-xl font-bold break-all">{node.id}</h3>
+				<button className="text-slate-300" type="button" onClick={onClose}>
+					✕
+				</button>
+			</div>
+
+			{node.kind === 'task' && node.project && (
+				<button
+					className="mb-4 text-sm underline"
+					type="button"
+					onClick={() =>
+						onSelect({ id: node.project!, kind: 'project', tags: [], toolchains: [] })
+					}
+				>
+					← {node.project}
+				</button>
+			)}
+
+			{error && <p className="text-sm text-red-400">{error}</p>}
+			{project && <ProjectDetails data={project} onSelect={onSelect} />}
+			{task && <TaskDetails task={task} />}
+			{!error && !project && !task && <p className="text-sm text-slate-400">Loading…</p>}
+		</aside>
+	);
+}
@@ -1,20 +1,79 @@
+import type { Core } from 'cytoscape';
 import { useEffect, useRef } from 'preact/hooks';
 
+import { isNodeMatched, isNodeVisible } from '../helpers/filters';
 import { render } from '../helpers/render';
-import type { GraphInfo } from '../helpers/types';
+import type { ExplorerFilters, ExplorerGraph, ExplorerNode, GraphInfo } from '../helpers/types';
 
 export interface GraphProps {
+	affected?: string[] | null;
+	data: ExplorerGraph | GraphInfo;
+	filters?: ExplorerFilters;
 	layout: string;
+	onSelect?: (node: ExplorerNode) => void;
 }
 
-export function Graph({ layout }: GraphProps) {
+export function Graph({ affected, data, filters, layout, onSelect }: GraphProps) {
 	const graphRef = useRef<HTMLDivElement>(null);
+	const cyRef = useRef<Core | null>(null);
+	const selectRef = useRef(onSelect);
+
+	selectRef.current = onSelect;
 
 	useEffect(() => {
-		if (graphRef.current) {
-			render(graphRef.current, JSON.parse(window.GRAPH_DATA) as GraphInfo, layout);
+		if (!graphRef.current) {
+			return undefined;
 		}
-	}, [layout]);
+
+		const cy = render(graphRef.current, data, layout);
+
+		cy.on('tap', 'node', (event) => {
+			const node = event.target.data('node') as ExplorerNode | undefined;
+
+			if (node) {
+				selectRef.current?.(node);
+			}
+		});
+
+		cyRef.current = cy;
+
+		return () => {
+			cy.destroy();
+			cyRef.current = null;
+		};
+	}, [data, layout]);
+
+	useEffect(() => {
+		const cy = cyRef.current;
+
+		if (!cy || !filters) {
+			return;
+		}
+
+		const affectedIds = affected ? new Set(affected) : null;
+		const hasSearch = filters.search.trim() !== '';
+
+		cy.batch(() => {
+			cy.nodes().forEach((element) => {
+				const node = element.data('node') as ExplorerNode | undefined;
+
+				if (!node) {
+					return;
+				}
+
+				const matched = isNodeMatched(node, filters);
+				const isAffected = affectedIds ? affectedIds.has(node.id) : false;
+
+				element.toggleClass('hidden', !isNodeVisible(node, filters));
+				element.toggleClass('matched', matched);
+				element.toggleClass('affected', isAffected);
+				element.toggleClass(
+					'dimmed',
+					(hasSearch && !matched) || (affectedIds !== null && !isAffected),
+				);
+			});
+		});
+	}, [data, layout, filters, affected]);
 
 	return <div id="graph" ref={graphRef} style={{ height: '80vh', width: '100%' }} />;
 }
@@ -0,0 +1,164 @@
+import { useMemo, useState } from 'preact/hooks';
+
+import { collectFilterOptions, EMPTY_FILTERS } from '../helpers/filters';
+import type { ExplorerAffected, ExplorerFilters, ExplorerNode } from '../helpers/types';
+
+const INPUT_CLASS = 'w-full border border-slate-400 rounded bg-slate-600 text-slate-50 p-1';
+
+export interface SidebarProps {
+	affected: ExplorerAffected | null;
+	filters: ExplorerFilters;
+	nodes: ExplorerNode[];
+	onAffected: (base: string, head: string) => Promise<void>;
+	onClearAffected: () => void;
+	onFilters: (filters: ExplorerFilters) => void;
+}
+
+interface FilterSelectProps {
+	label: string;
+	onChange: (value: string) => void;
+	options: string[];
+	value: string;
+}
+
+function FilterSelect({ label, onChange, options, value }: FilterSelectProps) {
+	if (options.length === 0) {
+		return null;
+	}
+
+	return (
+		<label className="block mb-3">
+			<span className="block mb-1 text-sm text-slate-300">{label}</span>
+			<select
+				className={INPUT_CLASS}
+				value={value}
+				onChange={(event) => onChange((event.target as HTMLSelectElement).value)}
+			>
+				<option value="">All</option>
+				{options.map((option) => (
+					<option key={option} value={option}>
+						{option}
+					</option>
+				))}
+			</select>
+		</label>
+	);
+}
+
+export function Sidebar({
+	affected,
+	filters,
+	nodes,
+	onAffected,
+	onClearAffected,
+	onFilters,
+}: SidebarProps) {
+	const options = useMemo(() => collectFilterOptions(nodes), [nodes]);
+	const [base, setBase] = useState('');
+	const [head, setHead] = useState('');
+	const [error, setError] = useState('');
+	const [loading, setLoading] = useState(false);
+
+	function update(field: keyof ExplorerFilters, value: string) {
+		onFilters({ ...filters, [field]: value });
+	}
+
+	async function handleAffected(event: Event) {
+		event.preventDefault();
+		setError('');
+		setLoading(true);
+
+		try {
+			await onAffected(base, head);
+		} catch (error_) {
+			setError(String(error_));
+		} finally {
+			setLoading(false);
+		}
+	}
+
+	return (
+		<aside className="w-64 shrink-0 p-4">
+			<label className="block mb-3">
+				<span className="block mb-1 text-sm text-slate-300">Search</span>
+				<input
+					className={INPUT_CLASS}
+					placeholder="Filter by ID or target"
+					type="search"
+					value={filters.search}
+					onInput={(event) => update('search', (event.target as HTMLInputElement).value)}
+				/>
+			</label>
+
+			<FilterSelect
+				label="Layer"
+				options={options.layers}
+				value={filters.layer}
+				onChange={(value) => update('layer', value)}
+			/>
+			<FilterSelect
+				label="Stack"
+				options={options.stacks}
+				value={filters.stack}
+				onChange={(value) => update('stack', value)}
+			/>
+			<FilterSelect
+				label="Tag"
+				options={options.tags}
+				value={filters.tag}
+				onChange={(value) => update('tag', value)}
+			/>
+			<FilterSelect
+				label="Toolchain"
+				options={options.toolchains}
+				value={filters.toolchain}
+				onChange={(value) => update('toolchain', value)}
+			/>
+
+			<button
+				className="mb-6 text-sm text-slate-300 underline"
+				type="button"
+				onClick={() => onFilters(EMPTY_FILTERS)}
+			>
+				Reset filters
+			</button>
+
+			<form onSubmit={handleAffected}>
+				<h3 className="mb-2 font-bold">Affected</h3>
+
+				<input
+					className={`${INPUT_CLASS} mb-2`}
+					placeholder="Base revision"
+					value={base}
+					onInput={(event) => setBase((event.target as HTMLInputElement).value)}
+				/>
+				<input
+					className={`${INPUT_CLASS} mb-2`}
+					placeholder="Head revision"
+					value={head}
+					onInput={(event) => setHead((event.target as HTMLInputElement).value)}
+				/>
+
+				<button
+					className="w-full rounded bg-slate-600 p-1 disabled:opacity-50"
+					disabled={loading}
+					type="submit"
+				>
+					{loading ? 'Loading…' : 'Highlight affected'}
+				</button>
+			</form>
+
+			{error && <p className="mt-2 text-sm text-red-400">{error}</p>}
+
+			{affected && (
+				<p className="mt-2 text-sm text-slate-300">
+					{affected.files} changed files, {affected.projects.length} affected projects,{' '}
+					{affected.tasks.length} affected tasks.{' '}
+					<button className="underline" type="button" onClick={onClearAffected}>
+						Clear
+					</button>
+				</p>
+			)}
+		</aside>
+	);
+}
@@ -0,0 +1,53 @@
+import type { Task } from '@moonrepo/types';
+
+import type { ExplorerAffected, ExplorerGraph, ExplorerProject, GraphInfo } from './types';
+
+async function fetchJson<T>(url: string): Promise<T> {
+	const response = await fetch(url);
+	const data = (await response.json()) as T | { error: string } | null;
+
+	if (!response.ok) {
+		throw new Error(
+			data && typeof data === 'object' && 'error' in data
+				? data.error
+				: `Request to ${url} failed with status ${response.status}.`,
+		);
+	}
+
+	return data as T;
+}
+
+export async function loadGraph(): Promise<ExplorerGraph | GraphInfo> {
+	// Older moon binaries and the VS Code extension only embed the data
+	if (!window.GRAPH_URL) {
+		if (!window.GRAPH_DATA) {
+			throw new Error('No graph data or URL provided.');
+		}
+
+		return JSON.parse(window.GRAPH_DATA) as GraphInfo;
+	}
+
+	return fetchJson(window.GRAPH_URL);
+}
+
+export function loadProject(id: string): Promise<ExplorerProject> {
+	return fetchJson(`/api/project?id=${encodeURIComponent(id)}`);
+}
+
+export function loadTask(target: string): Promise<Task> {
+	return fetchJson(`/api/task?target=${encodeURIComponent(target)}`);
+}
+
+export function loadAffected(base: string, head: string): Promise<ExplorerAffected> {
+	const query = new URLSearchParams();
+
+	if (base) {
+		query.set('base', base);
+	}
+
+	if (head) {
+		query.set('head', head);
+	}
+
+	return fetchJson(`/api/affected?${query}`);
+}
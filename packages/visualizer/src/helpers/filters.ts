@@ -0,0 +1,46 @@
+import type { ExplorerFilters, ExplorerNode } from './types';
+
+export const EMPTY_FILTERS: ExplorerFilters = {
+	layer: '',
+	search: '',
+	stack: '',
+	tag: '',
+	toolchain: '',
+};
+
+export interface FilterOptions {
+	layers: string[];
+	stacks: string[];
+	tags: string[];
+	toolchains: string[];
+}
+
+function unique(values: (string | undefined)[]): string[] {
+	return [...new Set(values.filter((value): value is string => !!value))].sort();
+}
+
+export function collectFilterOptions(nodes: ExplorerNode[]): FilterOptions {
+	return {
+		layers: unique(nodes.map((node) => node.layer)),
+		stacks: unique(nodes.map((node) => node.stack)),
+		tags: unique(nodes.flatMap((node) => node.tags)),
+		toolchains: unique(nodes.flatMap((node) => node.toolchains)),
+	};
+}
+
+// Nodes that don't pass the filters are hidden,
+// while the search only highlights matching nodes
+export function isNodeVisible(node: ExplorerNode, filters: ExplorerFilters): boolean {
+	return (
+		(!filters.layer || node.layer === filters.layer) &&
+		(!filters.stack || node.stack === filters.stack) &&
+		(!filters.tag || node.tags.includes(filters.tag)) &&
+		(!filters.toolchain || node.toolchains.includes(filters.toolchain))
+	);
+}
+
+export function isNodeMatched(node: ExplorerNode, filters: ExplorerFilters): boolean {
+	const search = filters.search.trim().toLowerCase();
+
+	return search !== '' && node.id.toLowerCase().includes(search);
+}
@@ -3,7 +3,7 @@ import cytoscape from 'cytoscape';
 import dagre from 'cytoscape-dagre';
 import klay from 'cytoscape-klay';
 
-import type { GraphInfo } from './types';
+import type { ExplorerGraph, GraphInfo } from './types';
 
 cytoscape.use(dagre);
 cytoscape.use(klay);
@@ -79,7 +79,18 @@ function getShortDepLabel(label: string) {
 	return label;
 }
 
-function extractNodes(data: GraphInfo) {
+export function isExplorerGraph(data: ExplorerGraph | GraphInfo): data is ExplorerGraph {
+	return !('graph' in data) && data.nodes.every((node) => 'kind' in node);
+}
+
+function extractNodes(data: ExplorerGraph | GraphInfo) {
+	// explorer
+	if (isExplorerGraph(data)) {
+		return data.nodes.map((node) => ({
+			data: { id: node.id, label: node.id, node, type: node.kind },
+		}));
+	}
+
 	// v2
 	if ('graph' in data) {
 		return data.graph.nodes.map((nodeOrIndex, arrayIndex) => {
@@ -109,7 +120,19 @@ function extractNodes(data: GraphInfo) {
 	}));
 }
 
-function extractEdges(data: GraphInfo) {
+function extractEdges(data: ExplorerGraph | GraphInfo) {
+	// explorer
+	if (isExplorerGraph(data)) {
+		return data.edges.map((edge) => ({
+			data: {
+				id: `${edge[0]} -> ${edge[1]}`,
+				label: getShortDepLabel(edge[2]),
+				source: edge[0],
+				target: edge[1],
+			},
+		}));
+	}
+
 	// v2
 	if ('graph' in data) {
 		return data.graph.edges.map((edge) => ({
@@ -132,7 +155,7 @@ function extractEdges(data: GraphInfo) {
 	}));
 }
 
-export function render(element: HTMLElement, data: GraphInfo, layout: string) {
+export function render(element: HTMLElement, data: ExplorerGraph | GraphInfo, layout: string) {
 	const nodes = extractNodes(data);
 	const edges = extractEdges(data);
 
@@ -247,6 +270,47 @@ export function render(element: HTMLElement, data: GraphInfo, layout: string) {
 					width: 120,
 				},
 			},
+			{
+				selector: 'node[type="project"]',
+				style: {
+					// @ts-expect-error Types incorrect
+					'background-gradient-stop-colors': '#ffafff #ff79ff #cc61cc',
+				},
+			},
+			{
+				selector: 'node[type="task"]',
+				style: {
+					// @ts-expect-error Types incorrect
+					'background-gradient-stop-colors': '#6e58d1 #4a2ec6 #3b259e',
+				},
+			},
+			{
+				selector: '.hidden',
+				style: {
+					display: 'none',
+				},
+			},
+			{
+				selector: '.dimmed',
+				style: {
+					opacity: 0.25,
+				},
+			},
+			{
+				selector: 'node.affected',
+				style: {
+					'border-color': '#ff5b6b',
+					'border-width': 6,
+				},
+			},
+			{
+				selector: 'node.matched, node:selected',
+				style: {
+					'border-color': '#b7d733',
+					'border-width': 6,
+					'font-weight': 'bold',
+				},
+			},
 		],
 	});
 }
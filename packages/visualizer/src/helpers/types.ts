@@ -31,3 +31,40 @@ export interface GraphInfoV2 {
 	};
 	data: Record<number, GraphNodeV2>;
 }
+
+// explorer
+export interface ExplorerNode {
+	id: string;
+	kind: 'project' | 'task';
+	language?: string;
+	layer?: string;
+	project?: string;
+	stack?: string;
+	tags: string[];
+	toolchains: string[];
+	type?: string;
+}
+
+export interface ExplorerGraph {
+	nodes: ExplorerNode[];
+	edges: [string, string, string][];
+}
+
+export interface ExplorerProject {
+	project: Project;
+	tasks: ExplorerNode[];
+}
+
+export interface ExplorerAffected {
+	files: number;
+	projects: string[];
+	tasks: string[];
+}
+
+export interface ExplorerFilters {
+	layer: string;
+	search: string;
+	stack: string;
+	tag: string;
+	toolchain: string;
+}
@@ -5,7 +5,9 @@ import { App } from './app';
 
 declare global {
 	interface Window {
-		GRAPH_DATA: string;
+		GRAPH_DATA?: string;
+		GRAPH_EXPLORER?: boolean;
+		GRAPH_URL?: string;
 		PAGE_TITLE: string;
 	}
 }
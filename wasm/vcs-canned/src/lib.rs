@@ -0,0 +1,147 @@
+use extism_pdk::*;
+use moon_common::Id;
+use moon_pdk_api::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+// Serves canned repository state and impacts, so that hosts can be
+// tested without a real source control client. The plugin only applies
+// to workspaces that contain a `.canned` directory.
+
+#[plugin_fn]
+pub fn register_vcs(Json(input): Json<RegisterVcsInput>) -> FnResult<Json<RegisterVcsOutput>> {
+    Ok(Json(RegisterVcsOutput {
+        name: input.id.to_string(),
+        plugin_version: "0.0.1".into(),
+        protocol_version: VCS_PLUGIN_PROTOCOL_VERSION,
+        ..Default::default()
+    }))
+}
+
+#[plugin_fn]
+pub fn initialize_vcs(
+    Json(input): Json<InitializeVcsInput>,
+) -> FnResult<Json<InitializeVcsOutput>> {
+    let repository_root = input.context.workspace_root.join(".canned");
+
+    if !repository_root.exists() {
+        return Ok(Json(InitializeVcsOutput::NotDetected {
+            reason: "No .canned directory found.".into(),
+        }));
+    }
+
+    Ok(Json(InitializeVcsOutput::Initialized {
+        initialization: Box::new(VcsInitialization {
+            client: Id::raw("canned"),
+            client_version: Some("1.2.3".into()),
+            roots: VcsRoots {
+                repository_root,
+                working_root: input.context.workspace_root.clone(),
+            },
+            current: VcsState {
+                id: Some("c2".into()),
+                label: Some("feature".into()),
+            },
+            recorded: VcsState {
+                id: Some("c2".into()),
+                label: Some("feature".into()),
+            },
+            baseline: input.baseline.map(|label| VcsState {
+                id: Some("c1".into()),
+                label: Some(label),
+            }),
+            repository_slug: Some("moonrepo/canned".into()),
+            history: VcsHistoryCompleteness::Complete,
+        }),
+    }))
+}
+
+fn working_changes(changes: &mut BTreeMap<PathBuf, VcsChangeMask>) {
+    changes.insert(
+        PathBuf::from("src/working.txt"),
+        VcsChangeMask::MODIFIED | VcsChangeMask::WORKING,
+    );
+    changes.insert(
+        PathBuf::from("src/staged.txt"),
+        VcsChangeMask::ADDED | VcsChangeMask::STAGED,
+    );
+    changes.insert(
+        PathBuf::from("src/untracked.txt"),
+        VcsChangeMask::ADDED | VcsChangeMask::UNTRACKED,
+    );
+}
+
+#[plugin_fn]
+pub fn get_vcs_impacts(
+    Json(input): Json<GetVcsImpactsInput>,
+) -> FnResult<Json<GetVcsImpactsOutput>> {
+    let mut output = GetVcsImpactsOutput {
+        completeness: VcsImpactCompleteness::Exact,
+        ..Default::default()
+    };
+
+    match input.intent {
+        VcsImpactIntent::Working => {
+            working_changes(&mut output.changes);
+        }
+        VcsImpactIntent::Submission {
+            base,
+            head,
+            include_working,
+        } => {
+            if head.as_deref() == Some("unavailable") {
+                output.completeness = VcsImpactCompleteness::Unavailable;
+                output.diagnostics.push("Head is unavailable.".into());
+
+                return Ok(Json(output));
+            }
+
+            output.changes.insert(
+                PathBuf::from("src/recorded.txt"),
+                VcsChangeMask::MODIFIED | VcsChangeMask::RECORDED,
+            );
+
+            if base.is_some() {
+                output.changes.insert(
+                    PathBuf::from("src/removed.txt"),
+                    VcsChangeMask::DELETED | VcsChangeMask::RECORDED,
+                );
+            }
+
+            if include_working {
+                working_changes(&mut output.changes);
+            }
+        }
+    };
+
+    Ok(Json(output))
+}
+
+#[plugin_fn]
+pub fn setup_vcs_hook_environment(
+    Json(input): Json<SetupVcsHookEnvironmentInput>,
+) -> FnResult<Json<SetupVcsHookEnvironmentOutput>> {
+    // Write a marker so that tests can verify the hooks passed through
+    fs::write(
+        input.context.workspace_root.join(".canned/hooks"),
+        input.hooks.join(","),
+    )?;
+
+    Ok(Json(SetupVcsHookEnvironmentOutput {
+        working_dir: Some(input.context.workspace_root),
+    }))
+}
+
+#[plugin_fn]
+pub fn teardown_vcs_hook_environment(
+    Json(input): Json<TeardownVcsHookEnvironmentInput>,
+) -> FnResult<Json<TeardownVcsHookEnvironmentOutput>> {
+    let marker = input.context.workspace_root.join(".canned/hooks");
+
+    if marker.exists() {
+        fs::remove_file(marker)?;
+    }
+
+    Ok(Json(TeardownVcsHookEnvironmentOutput {}))
+}
@@ -101,6 +101,18 @@ const sidebars: SidebarsConfig = {
 				'commands/overview',
 				'commands/action-graph',
 				'commands/bin',
+				{
+					type: 'category',
+					label: 'cache',
+					items: ['commands/cache/export', 'commands/cache/import', 'commands/cache/serve'],
+					link: {
+						type: 'generated-index',
+						title: 'cache',
+						description: 'Manage and serve cached outputs.',
+						slug: '/commands/cache',
+						keywords: ['cli', 'commands', 'cache'],
+					},
+				},
 				'commands/check',
 				'commands/ci',
 				'commands/clean',
@@ -109,6 +121,7 @@ const sidebars: SidebarsConfig = {
 					type: 'category',
 					label: 'daemon',
 					items: [
+						'commands/daemon/events',
 						'commands/daemon/logs',
 						'commands/daemon/restart',
 						'commands/daemon/start',
@@ -154,7 +167,20 @@ const sidebars: SidebarsConfig = {
 						keywords: ['manage', 'extension', 'plugins'],
 					},
 				},
+				'commands/flaky',
 				'commands/generate',
+				{
+					type: 'category',
+					label: 'graph',
+					items: ['commands/graph/analyze'],
+					link: {
+						type: 'generated-index',
+						title: 'graph',
+						description: 'Analyze the project and task graphs.',
+						slug: '/commands/graph',
+						keywords: ['cli', 'commands', 'graph'],
+					},
+				},
 				'commands/hash',
 				'commands/init',
 				'commands/mcp',
@@ -217,6 +243,7 @@ const sidebars: SidebarsConfig = {
 					},
 				},
 				'commands/upgrade',
+				'commands/watch',
 			],
 			link: {
 				type: 'generated-index',